@@ -0,0 +1,105 @@
+//! Benchmarks for the mortality table lookups behind the single life functions.
+//!
+//! Run with `cargo bench --bench lookup`.
+//!
+//! The `baseline` group compares a survival probability computed with the index-based lookup
+//! against the same value read by filtering the DataFrame, as the lookups did before.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use polars::prelude::*;
+use rslife::prelude::*;
+use std::hint::black_box;
+
+fn am92() -> MortTableConfig {
+    let data = MortData::from_builtin("AM92").expect("Failed to load AM92");
+    MortTableConfig::builder().data(data).build().unwrap()
+}
+
+/// lx at `age` on the ultimate duration, read by filtering the DataFrame (former lookup path).
+fn filter_lx(mt: &MortTableConfig, age: u32) -> f64 {
+    let max_duration = mt.max_duration().unwrap();
+    mt.data
+        .dataframe
+        .clone()
+        .lazy()
+        .filter(col("age").eq(lit(age)))
+        .filter(col("duration").eq(lit(max_duration)))
+        .select([col("lx")])
+        .collect()
+        .unwrap()
+        .column("lx")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .get(0)
+        .unwrap()
+}
+
+fn bench_baseline(c: &mut Criterion) {
+    let mt = am92();
+    let mut group = c.benchmark_group("baseline");
+    group.bench_function("tpx DataFrame filter", |b| {
+        b.iter(|| filter_lx(&mt, black_box(65)) / filter_lx(&mt, black_box(40)))
+    });
+    group.bench_function("tpx index lookup", |b| {
+        b.iter(|| tpx().mt(&mt).x(black_box(40.0)).t(black_box(25.0)).call())
+    });
+    group.finish();
+}
+
+fn bench_survivals(c: &mut Criterion) {
+    let mt = am92();
+    c.bench_function("tpx whole ages", |b| {
+        b.iter(|| tpx().mt(&mt).x(black_box(40.0)).t(black_box(25.0)).call())
+    });
+    c.bench_function("tpx fractional ages", |b| {
+        b.iter(|| tpx().mt(&mt).x(black_box(40.3)).t(black_box(25.6)).call())
+    });
+    c.bench_function("tpx select", |b| {
+        b.iter(|| {
+            tpx()
+                .mt(&mt)
+                .x(black_box(40.0))
+                .t(black_box(25.0))
+                .entry_age(39)
+                .call()
+        })
+    });
+}
+
+fn bench_benefits_annuities(c: &mut Criterion) {
+    let mt = am92();
+    c.bench_function("Ax whole life", |b| {
+        b.iter(|| Ax().mt(&mt).i(0.04).x(black_box(40.0)).call())
+    });
+    c.bench_function("aaxn monthly 20 years", |b| {
+        b.iter(|| {
+            aaxn()
+                .mt(&mt)
+                .i(0.04)
+                .x(black_box(45.0))
+                .n(20.0)
+                .m(12)
+                .call()
+        })
+    });
+    c.bench_function("Axn select", |b| {
+        b.iter(|| {
+            Axn()
+                .mt(&mt)
+                .i(0.04)
+                .x(black_box(45.0))
+                .n(20.0)
+                .entry_age(45)
+                .call()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_baseline,
+    bench_survivals,
+    bench_benefits_annuities
+);
+criterion_main!(benches);
@@ -57,6 +57,24 @@ fn main() -> RSLifeResult<()> {
     println!("  15-year annuity due: {annuity_due:.6}");
     println!("  Life annuity due: {life_annuity:.6}");
 
+    // Premium calculations using the equivalence principle
+    println!("\n=== Premium Calculations (UDD assumption, age 30) ===");
+    let net_premium = 100_000.0 * Pxn().mt(&mt_config).i(0.03).x(30.0).n(15.0).call()?;
+    let gross = gross_premium()
+        .mt(&mt_config)
+        .i(0.03)
+        .x(30.0)
+        .contract(ContractEnum::Endowment(15.0))
+        .sum_assured(100_000.0)
+        .initial_expense(250.0)
+        .renewal_pct(0.03)
+        .m(12)
+        .call()?;
+
+    println!("Premiums for a 15-year endowment of 100,000:");
+    println!("  Net annual premium: {net_premium:.2}");
+    println!("  Gross annual premium (monthly): {gross:.2}");
+
     // Survival probability calculations
     println!("\n=== Survival Calculations (direct MortTableConfig calls) ===");
     let survival_10_years = tpx().mt(&mt_config).x(30.0).t(10.0).k(0.0).call()?;
@@ -0,0 +1,171 @@
+//! # Errors
+//!
+//! [`RSLifeError`] is the error type of every fallible RSLife function, returned through [`RSLifeResult`].
+//!
+//! Each variant identifies a kind of failure so that callers can match on it:
+//! - **InvalidParameters**: builder or configuration parameters rejected by validation
+//! - **AgeOutOfRange**: age outside the range of the mortality table
+//! - **UnknownTable**: table id not known to a loader (builtin, SOA custom, IFOA)
+//! - **Schema**: DataFrame not in the layout expected for a mortality or decrement table
+//! - **Parse**: XML or spreadsheet source that cannot be read as a mortality table
+//! - **Network**: failed download from SOA, IFOA or AGA (with the `blocking` or `async` feature)
+//! - **Offline**: download refused in offline mode
+//! - **Io**, **Polars**: errors passed through from the file system and Polars
+//! - **Other**: any remaining failure, described by its message
+//!
+//! ## Migration from `Box<dyn Error>`
+//! `RSLifeError` implements [`std::error::Error`] and is `Send + Sync`, so `?` still converts it into
+//! `Box<dyn Error>` in calling code. Messages are unchanged and remain available through `to_string()`.
+//!
+//! ```rust
+//! # use rslife::prelude::*;
+//! # let mort_data = MortData::from_builtin("AM92")?;
+//! # let mt = MortTableConfig::builder().data(mort_data).build()?;
+//! match tpx().mt(&mt).x(125.0).t(1.0).call() {
+//!     Err(RSLifeError::AgeOutOfRange { age, max_age, .. }) => {
+//!         println!("Age {age} is beyond the table limit {max_age}");
+//!     }
+//!     Err(err) => return Err(err),
+//!     Ok(value) => println!("Survival probability: {value:.6}"),
+//! }
+//! # RSLifeResult::Ok(())
+//! ```
+
+use polars::prelude::PolarsError;
+
+/// Result type of RSLife functions.
+pub type RSLifeResult<T> = Result<T, RSLifeError>;
+
+/// Error returned by RSLife functions.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RSLifeError {
+    /// Parameters failed validation, with one entry per offending field.
+    #[error("{0}")]
+    InvalidParameters(garde::Report),
+
+    /// Age outside the range of the mortality table.
+    #[error("age {age} must be between {min_age} and {max_age} from mortality table")]
+    AgeOutOfRange {
+        age: f64,
+        min_age: f64,
+        max_age: f64,
+    },
+
+    /// Table id not supported by the loader.
+    #[error("mortality table '{0}' is not available")]
+    UnknownTable(String),
+
+    /// DataFrame does not match the expected table schema.
+    #[error("{0}")]
+    Schema(String),
+
+    /// Source document cannot be parsed into a table.
+    #[error("{0}")]
+    Parse(String),
+
+    /// Download failed or returned an unsuccessful HTTP status.
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    #[error("network request failed: {0}")]
+    Network(#[from] reqwest::Error),
+
+    /// Download refused because the fetcher is offline.
+    #[error("offline mode: download of '{0}' is disabled")]
+    Offline(String),
+
+    /// File system error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// Error raised by Polars.
+    #[error(transparent)]
+    Polars(#[from] PolarsError),
+
+    /// Any other failure.
+    #[error("{0}")]
+    Other(String),
+}
+
+/// `InvalidParameters` error for a single parameter, as reported by the `param` validators.
+pub(crate) fn invalid_parameter(path: &str, message: impl Into<String>) -> RSLifeError {
+    let mut report = garde::Report::new();
+    report.append(garde::Path::new(path), garde::Error::new(message.into()));
+    RSLifeError::InvalidParameters(report)
+}
+
+impl From<garde::Report> for RSLifeError {
+    fn from(report: garde::Report) -> Self {
+        RSLifeError::InvalidParameters(report)
+    }
+}
+
+impl From<roxmltree::Error> for RSLifeError {
+    fn from(err: roxmltree::Error) -> Self {
+        RSLifeError::Parse(err.to_string())
+    }
+}
+
+impl From<calamine::Error> for RSLifeError {
+    fn from(err: calamine::Error) -> Self {
+        RSLifeError::Parse(err.to_string())
+    }
+}
+
+impl From<calamine::XlsError> for RSLifeError {
+    fn from(err: calamine::XlsError) -> Self {
+        RSLifeError::Parse(err.to_string())
+    }
+}
+
+impl From<calamine::XlsxError> for RSLifeError {
+    fn from(err: calamine::XlsxError) -> Self {
+        RSLifeError::Parse(err.to_string())
+    }
+}
+
+impl From<String> for RSLifeError {
+    fn from(message: String) -> Self {
+        RSLifeError::Other(message)
+    }
+}
+
+impl From<&str> for RSLifeError {
+    fn from(message: &str) -> Self {
+        RSLifeError::Other(message.to_string())
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for RSLifeError {
+    fn from(err: Box<dyn std::error::Error>) -> Self {
+        RSLifeError::Other(err.to_string())
+    }
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<RSLifeError>();
+    }
+
+    #[test]
+    fn test_error_conversions() {
+        let err: RSLifeError = "something went wrong".into();
+        assert!(matches!(err, RSLifeError::Other(_)));
+        assert_eq!(err.to_string(), "something went wrong");
+
+        let err: RSLifeError = PolarsError::ComputeError("bad column".into()).into();
+        assert!(matches!(err, RSLifeError::Polars(_)));
+
+        // Still usable where a boxed error is expected
+        let boxed: Box<dyn std::error::Error> = RSLifeError::UnknownTable("XYZ".into()).into();
+        assert_eq!(boxed.to_string(), "mortality table 'XYZ' is not available");
+    }
+}
@@ -0,0 +1,688 @@
+//! # Tests of Graduation and Actual versus Expected
+//!
+//! Compare observed deaths and exposures with the rates of a [`MortTableConfig`]: either a
+//! graduation to sign off or a standard table to analyse experience against.
+//!
+//! Exposures are initial exposed to risk Eₓ compared with qₓ, or central exposed to risk Eₓᶜ
+//! compared with the central rate mₓ (see [`ExposureEnum`]). With deviations Aₓ - Eₓ·qₓ
+//! standardised as zₓ = (Aₓ - Eₓ·qₓ) / √(Eₓ·qₓ·(1 - qₓ)), or (Aₓ - Eₓᶜ·mₓ) / √(Eₓᶜ·mₓ):
+//!
+//! ## Tests
+//! - **Chi-square**: Σ zₓ² against χ² with m - (fitted parameters) degrees of freedom
+//! - **Individual standardised deviations**: counts of zₓ in (-∞, -2], (-2, -1], ..., (2, ∞)
+//!   against the normal distribution, by chi-square with 5 degrees of freedom
+//! - **Signs**: number of positive deviations against Binomial(m, ½), two-sided
+//! - **Cumulative deviations**: Σ(Aₓ - Eₓ) / √(Σ Var) against N(0, 1), two-sided
+//! - **Grouping of signs**: number of runs of positive deviations, P(G ≤ g) given the signs
+//! - **Serial correlation**: lag-1 correlation r₁ of the zₓ, r₁·√m against N(0, 1), one-sided
+//!
+//! Zero deviations are left out of the signs and grouping of signs tests.
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! let experience = Experience::builder()
+//!     .ages((60..70).collect())
+//!     .exposures(vec![1000.0; 10])
+//!     .deaths(vec![8.0, 11.0, 10.0, 13.0, 12.0, 16.0, 15.0, 19.0, 20.0, 24.0])
+//!     .build()?;
+//! let graduation = graduate()
+//!     .experience(&experience)
+//!     .method(GraduationMethodEnum::Parametric { formula: FormulaEnum::Gompertz })
+//!     .call()?;
+//! let mt = MortTableConfig::builder().data(graduation.mort_data).build()?;
+//!
+//! let tests = graduation_tests()
+//!     .experience(&experience)
+//!     .mt(&mt)
+//!     .parameters(2)
+//!     .call()?;
+//! println!("{}", tests.summary()?);
+//! assert!(tests.chi_square.p_value > 0.05);
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::distributions::{binomial_cdf, chi_square_sf, ln_choose, normal_cdf, normal_two_sided};
+use super::{Experience, column_values};
+use crate::error::invalid_parameter;
+use crate::mt_config::lookup::MortLookup;
+use crate::mt_config::{AssumptionEnum, MortTableConfig};
+use crate::{RSLifeError, RSLifeResult};
+use bon::builder;
+use polars::prelude::*;
+use std::collections::BTreeMap;
+
+/// Interval boundaries of the individual standardised deviations test.
+const DEVIATION_BOUNDARIES: [f64; 5] = [-2.0, -1.0, 0.0, 1.0, 2.0];
+
+// ===============================================
+// EXPOSURES AND RESULTS
+// ===============================================
+
+/// Type of exposed to risk, and so the rate deaths are compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExposureEnum {
+    /// Initial exposed to risk Eₓ, expected deaths Eₓ·qₓ (binomial)
+    Initial,
+    /// Central exposed to risk Eₓᶜ, expected deaths Eₓᶜ·mₓ (Poisson), mₓ under the table's assumption
+    Central,
+}
+
+/// Statistic and p-value of one test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestStatistic {
+    /// Value of the test statistic
+    pub statistic: f64,
+    /// Probability of a result at least as extreme under the null hypothesis
+    pub p_value: f64,
+}
+
+/// Results of the statistical tests of a graduation.
+#[derive(Debug, Clone)]
+pub struct GraduationTests {
+    /// Deviations by age: `age`, `exposure`, `actual`, `expected`, `deviation`, `z`
+    pub deviations: DataFrame,
+    /// Σ zₓ², chi-square with `degrees_of_freedom`
+    pub chi_square: TestStatistic,
+    /// Degrees of freedom of the chi-square test
+    pub degrees_of_freedom: usize,
+    /// Chi-square statistic of the counts of zₓ by interval, 5 degrees of freedom
+    pub standardised_deviations: TestStatistic,
+    /// Counts of zₓ in (-∞, -2], (-2, -1], (-1, 0], (0, 1], (1, 2], (2, ∞)
+    pub deviation_counts: [usize; 6],
+    /// Number of positive deviations, two-sided binomial
+    pub signs: TestStatistic,
+    /// Standardised total deviation, two-sided normal
+    pub cumulative_deviations: TestStatistic,
+    /// Number of groups of positive deviations, P(G ≤ g)
+    pub grouping_of_signs: TestStatistic,
+    /// r₁·√m of the lag-1 correlation r₁, one-sided normal
+    pub serial_correlation: TestStatistic,
+}
+
+impl GraduationTests {
+    /// All tests as a DataFrame of `test`, `statistic` and `p_value`.
+    pub fn summary(&self) -> RSLifeResult<DataFrame> {
+        let tests = [
+            ("Chi-square", self.chi_square),
+            (
+                "Individual standardised deviations",
+                self.standardised_deviations,
+            ),
+            ("Signs", self.signs),
+            ("Cumulative deviations", self.cumulative_deviations),
+            ("Grouping of signs", self.grouping_of_signs),
+            ("Serial correlation", self.serial_correlation),
+        ];
+        let df = df! {
+            "test" => tests.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            "statistic" => tests.iter().map(|(_, t)| t.statistic).collect::<Vec<_>>(),
+            "p_value" => tests.iter().map(|(_, t)| t.p_value).collect::<Vec<_>>(),
+        }?;
+        Ok(df)
+    }
+}
+
+/// Actual versus expected deaths, by age band and by duration.
+#[derive(Debug, Clone)]
+pub struct ActualVsExpected {
+    /// `age_from`, `age_to`, `exposure`, `actual`, `expected`, `ae_ratio` by age band
+    pub by_age: DataFrame,
+    /// `duration`, `exposure`, `actual`, `expected`, `ae_ratio` by duration, when both the data and
+    /// the table are select; the last duration gathers all durations from the ultimate one up
+    pub by_duration: Option<DataFrame>,
+    /// Total actual deaths
+    pub actual: f64,
+    /// Total expected deaths
+    pub expected: f64,
+    /// Total actual over expected
+    pub ae_ratio: f64,
+}
+
+// ===============================================
+// TESTS OF GRADUATION
+// ===============================================
+
+/// Statistical tests of `experience` against the rates of `mt`.
+///
+/// `parameters` is the number of parameters fitted in the graduation, deducted from the
+/// degrees of freedom of the chi-square test (default 0 for comparison with a standard table).
+/// Select tables contribute their ultimate rates.
+///
+/// # Errors
+/// - `InvalidParameters`: no more than `parameters` ages, or fewer than 2
+/// - Experience ages outside the table, or expected deaths of zero
+#[builder]
+pub fn graduation_tests(
+    experience: &Experience,
+    mt: &MortTableConfig,
+    #[builder(default = ExposureEnum::Initial)] exposure: ExposureEnum,
+    #[builder(default = 0)] parameters: usize,
+) -> RSLifeResult<GraduationTests> {
+    let m = experience.ages.len();
+    if m < 2 || m <= parameters {
+        return Err(invalid_parameter(
+            "experience",
+            format!("Tests of graduation need more than {parameters} ages, and 2 at least"),
+        ));
+    }
+
+    // Expected deaths, variances and standardised deviations
+    let lookup = mt.lookup()?;
+    let mut expected = Vec::with_capacity(m);
+    let mut variances = Vec::with_capacity(m);
+    for (age, e) in experience.ages.iter().zip(&experience.exposures) {
+        let rate = expected_rate(mt, &lookup, *age, None, exposure)?;
+        let variance = match exposure {
+            ExposureEnum::Initial => e * rate * (1.0 - rate),
+            ExposureEnum::Central => e * rate,
+        };
+        if !(variance > 0.0 && variance.is_finite()) {
+            return Err(RSLifeError::Schema(format!(
+                "Expected deaths at age {age} must be positive and below the exposure"
+            )));
+        }
+        expected.push(e * rate);
+        variances.push(variance);
+    }
+    let deviations: Vec<f64> = experience
+        .deaths
+        .iter()
+        .zip(&expected)
+        .map(|(a, e)| a - e)
+        .collect();
+    let z: Vec<f64> = deviations
+        .iter()
+        .zip(&variances)
+        .map(|(d, v)| d / v.sqrt())
+        .collect();
+
+    // Chi-square
+    let degrees_of_freedom = m - parameters;
+    let statistic: f64 = z.iter().map(|z| z * z).sum();
+    let chi_square = TestStatistic {
+        statistic,
+        p_value: chi_square_sf(statistic, degrees_of_freedom as f64),
+    };
+
+    // Individual standardised deviations
+    let mut deviation_counts = [0; 6];
+    for value in &z {
+        let interval = DEVIATION_BOUNDARIES.iter().filter(|b| value > b).count();
+        deviation_counts[interval] += 1;
+    }
+    let mut lower = 0.0;
+    let mut statistic = 0.0;
+    for (i, observed) in deviation_counts.iter().enumerate() {
+        let upper = DEVIATION_BOUNDARIES.get(i).map_or(1.0, |b| normal_cdf(*b));
+        let expected_count = m as f64 * (upper - lower);
+        statistic += (*observed as f64 - expected_count).powi(2) / expected_count;
+        lower = upper;
+    }
+    let standardised_deviations = TestStatistic {
+        statistic,
+        p_value: chi_square_sf(statistic, (deviation_counts.len() - 1) as f64),
+    };
+
+    // Signs
+    let signs: Vec<bool> = deviations
+        .iter()
+        .filter(|d| **d != 0.0)
+        .map(|d| *d > 0.0)
+        .collect();
+    let n = signs.len() as u64;
+    let positives = signs.iter().filter(|positive| **positive).count() as u64;
+    let lower_tail = binomial_cdf(positives, n, 0.5);
+    let upper_tail = 1.0 - binomial_cdf(positives.saturating_sub(1), n, 0.5);
+    let upper_tail = if positives == 0 { 1.0 } else { upper_tail };
+    let signs_test = TestStatistic {
+        statistic: positives as f64,
+        p_value: (2.0 * lower_tail.min(upper_tail)).min(1.0),
+    };
+
+    // Cumulative deviations
+    let statistic = deviations.iter().sum::<f64>() / variances.iter().sum::<f64>().sqrt();
+    let cumulative_deviations = TestStatistic {
+        statistic,
+        p_value: normal_two_sided(statistic),
+    };
+
+    // Grouping of signs
+    let groups = signs
+        .iter()
+        .enumerate()
+        .filter(|(i, positive)| **positive && (*i == 0 || !signs[i - 1]))
+        .count() as u64;
+    let grouping_of_signs = TestStatistic {
+        statistic: groups as f64,
+        p_value: grouping_of_signs_cdf(groups, positives, n - positives),
+    };
+
+    // Serial correlation
+    let statistic = lag_one_correlation(&z) * (m as f64).sqrt();
+    let serial_correlation = TestStatistic {
+        statistic,
+        p_value: 1.0 - normal_cdf(statistic),
+    };
+
+    let deviations = df! {
+        "age" => &experience.ages,
+        "exposure" => &experience.exposures,
+        "actual" => &experience.deaths,
+        "expected" => expected,
+        "deviation" => deviations,
+        "z" => z,
+    }?;
+
+    Ok(GraduationTests {
+        deviations,
+        chi_square,
+        degrees_of_freedom,
+        standardised_deviations,
+        deviation_counts,
+        signs: signs_test,
+        cumulative_deviations,
+        grouping_of_signs,
+        serial_correlation,
+    })
+}
+
+// ===============================================
+// ACTUAL VERSUS EXPECTED
+// ===============================================
+
+/// Actual versus expected deaths of `data` against the rates of `mt`.
+///
+/// `data` has columns `age`, `exposure`, `deaths` and, for select experience, `duration`
+/// (curtate duration since selection at the attained age). Rows may repeat ages. Expected
+/// rates are read at the attained age and duration, the ultimate rates without a duration.
+/// Ages are grouped in bands of `age_band` years (default 5) starting at multiples of it.
+///
+/// # Errors
+/// - `InvalidParameters`: `age_band` of 0
+/// - Missing or null columns, negative exposures or deaths
+/// - Ages outside the table
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// use polars::prelude::*;
+///
+/// let data = df! {
+///     "age" => [60u32, 60, 61, 62],
+///     "duration" => [0u32, 2, 1, 5],
+///     "exposure" => [500.0, 800.0, 700.0, 900.0],
+///     "deaths" => [2.0, 5.0, 4.0, 7.0],
+/// }?;
+/// let mt = MortTableConfig::builder().data(MortData::from_builtin("AM92")?).build()?;
+///
+/// let ae = actual_vs_expected().data(&data).mt(&mt).call()?;
+/// println!("{}", ae.by_age);
+/// println!("{}", ae.by_duration.unwrap());
+/// println!("Overall A/E: {:.3}", ae.ae_ratio);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn actual_vs_expected(
+    data: &DataFrame,
+    mt: &MortTableConfig,
+    #[builder(default = ExposureEnum::Initial)] exposure: ExposureEnum,
+    #[builder(default = 5)] age_band: u32,
+) -> RSLifeResult<ActualVsExpected> {
+    if age_band == 0 {
+        return Err(invalid_parameter(
+            "age_band",
+            "Age band must be at least 1 year",
+        ));
+    }
+    let ages = column_values(data, "age", &DataType::UInt32, |c| {
+        Ok(c.u32()?.iter().collect())
+    })?;
+    let exposures = column_values(data, "exposure", &DataType::Float64, |c| {
+        Ok(c.f64()?.iter().collect())
+    })?;
+    let deaths = column_values(data, "deaths", &DataType::Float64, |c| {
+        Ok(c.f64()?.iter().collect())
+    })?;
+    let has_duration = data
+        .get_column_names()
+        .iter()
+        .any(|n| n.as_str() == "duration");
+    let durations: Option<Vec<u32>> = if has_duration {
+        Some(column_values(data, "duration", &DataType::UInt32, |c| {
+            Ok(c.u32()?.iter().collect())
+        })?)
+    } else {
+        None
+    };
+    if exposures
+        .iter()
+        .chain(&deaths)
+        .any(|v| v.is_nan() || *v < 0.0)
+    {
+        return Err(RSLifeError::Schema(
+            "Exposures and deaths must be non-negative".into(),
+        ));
+    }
+
+    let lookup = mt.lookup()?;
+    let max_duration = lookup.durations().map(|(_, max_dur)| max_dur);
+
+    // (exposure, actual, expected) by age band and by duration
+    let mut by_age: BTreeMap<u32, [f64; 3]> = BTreeMap::new();
+    let mut by_duration: BTreeMap<u32, [f64; 3]> = BTreeMap::new();
+    for (row, age) in ages.iter().enumerate() {
+        let duration = durations.as_ref().map(|d| d[row]);
+        let rate = expected_rate(mt, &lookup, *age, duration, exposure)?;
+        let cell = [exposures[row], deaths[row], exposures[row] * rate];
+
+        let band = by_age.entry(age - age % age_band).or_default();
+        band.iter_mut().zip(cell).for_each(|(sum, v)| *sum += v);
+        if let (Some(duration), Some(max_dur)) = (duration, max_duration) {
+            let group = by_duration.entry(duration.min(max_dur)).or_default();
+            group.iter_mut().zip(cell).for_each(|(sum, v)| *sum += v);
+        }
+    }
+
+    let actual: f64 = deaths.iter().sum();
+    let expected: f64 = by_age.values().map(|cell| cell[2]).sum();
+    let by_age_df = ae_frame(&by_age, |band| {
+        vec![
+            Series::new("age_from".into(), band.to_vec()).into_column(),
+            Series::new(
+                "age_to".into(),
+                band.iter()
+                    .map(|from| from + age_band - 1)
+                    .collect::<Vec<u32>>(),
+            )
+            .into_column(),
+        ]
+    })?;
+    let by_duration_df = if has_duration && max_duration.is_some() {
+        Some(ae_frame(&by_duration, |durations| {
+            vec![Series::new("duration".into(), durations.to_vec()).into_column()]
+        })?)
+    } else {
+        None
+    };
+
+    Ok(ActualVsExpected {
+        by_age: by_age_df,
+        by_duration: by_duration_df,
+        actual,
+        expected,
+        ae_ratio: actual / expected,
+    })
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// qₓ (initial exposures) or mₓ (central exposures) of `mt` at `age` and `duration`.
+///
+/// Durations are capped to those of a select table and ignored by an ultimate table.
+fn expected_rate(
+    mt: &MortTableConfig,
+    lookup: &MortLookup,
+    age: u32,
+    duration: Option<u32>,
+    exposure: ExposureEnum,
+) -> RSLifeResult<f64> {
+    let duration = match (duration, lookup.durations()) {
+        (Some(d), Some((min_dur, max_dur))) => Some(d.clamp(min_dur, max_dur)),
+        _ => None,
+    };
+    let qx = lookup.qx(age, duration).ok_or(RSLifeError::AgeOutOfRange {
+        age: age as f64,
+        min_age: lookup.min_age() as f64,
+        max_age: lookup.max_age() as f64,
+    })?;
+    Ok(match exposure {
+        ExposureEnum::Initial => qx,
+        ExposureEnum::Central => central_rate(qx, mt.assumption),
+    })
+}
+
+/// Central rate mₓ = dₓ / Lₓ from qₓ under the fractional age `assumption`.
+fn central_rate(qx: f64, assumption: AssumptionEnum) -> f64 {
+    if qx <= 0.0 {
+        return 0.0;
+    }
+    if qx >= 1.0 {
+        return f64::INFINITY;
+    }
+    match assumption {
+        // Lₓ = lₓ·(1 - qₓ/2)
+        AssumptionEnum::UDD => qx / (1.0 - qx / 2.0),
+        // mₓ = μ = -ln pₓ
+        AssumptionEnum::CFM => -(1.0 - qx).ln(),
+        // Lₓ = -lₓ·pₓ·ln pₓ / qₓ
+        AssumptionEnum::HPB => -qx * qx / ((1.0 - qx) * (1.0 - qx).ln()),
+    }
+}
+
+/// P(G ≤ groups) for the number G of groups of `positives` positive among `negatives` negative signs.
+///
+/// P(G = t) = C(n₁ - 1, t - 1)·C(n₂ + 1, t) / C(n₁ + n₂, n₁).
+fn grouping_of_signs_cdf(groups: u64, positives: u64, negatives: u64) -> f64 {
+    if positives == 0 || negatives == 0 {
+        return 1.0;
+    }
+    let total = ln_choose(positives + negatives, positives);
+    (1..=groups.min(positives).min(negatives + 1))
+        .map(|t| (ln_choose(positives - 1, t - 1) + ln_choose(negatives + 1, t) - total).exp())
+        .sum::<f64>()
+        .min(1.0)
+}
+
+/// Lag-1 correlation of `z`, 0 if either series is constant.
+fn lag_one_correlation(z: &[f64]) -> f64 {
+    let (first, second) = (&z[..z.len() - 1], &z[1..]);
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+    let (mean_first, mean_second) = (mean(first), mean(second));
+    let mut covariance = 0.0;
+    let mut variance_first = 0.0;
+    let mut variance_second = 0.0;
+    for (a, b) in first.iter().zip(second) {
+        covariance += (a - mean_first) * (b - mean_second);
+        variance_first += (a - mean_first).powi(2);
+        variance_second += (b - mean_second).powi(2);
+    }
+    let denominator = (variance_first * variance_second).sqrt();
+    if denominator > 0.0 {
+        covariance / denominator
+    } else {
+        0.0
+    }
+}
+
+/// Frame of key columns followed by `exposure`, `actual`, `expected` and `ae_ratio`.
+fn ae_frame(
+    groups: &BTreeMap<u32, [f64; 3]>,
+    keys: impl Fn(&[u32]) -> Vec<Column>,
+) -> RSLifeResult<DataFrame> {
+    let group_keys: Vec<u32> = groups.keys().copied().collect();
+    let value = |i: usize| -> Vec<f64> { groups.values().map(|cell| cell[i]).collect() };
+    let ratios: Vec<f64> = groups.values().map(|cell| cell[1] / cell[2]).collect();
+    let mut columns = keys(&group_keys);
+    columns.extend([
+        Series::new("exposure".into(), value(0)).into_column(),
+        Series::new("actual".into(), value(1)).into_column(),
+        Series::new("expected".into(), value(2)).into_column(),
+        Series::new("ae_ratio".into(), ratios).into_column(),
+    ]);
+    Ok(DataFrame::new(group_keys.len(), columns)?)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+
+    /// Table with qₓ = 0.01 at ages 60 to 69.
+    fn flat_table() -> MortTableConfig {
+        let df = df! {
+            "age" => (60u32..70).collect::<Vec<_>>(),
+            "qx" => vec![0.01; 10],
+        }
+        .unwrap();
+        let data = MortData::new("Test".to_string(), "Flat".to_string(), df).unwrap();
+        MortTableConfig::builder().data(data).build().unwrap()
+    }
+
+    /// Experience on the flat table with standardised deviations `z`.
+    fn experience_with_deviations(z: &[f64]) -> Experience {
+        let sd = (10_000.0f64 * 0.01 * 0.99).sqrt();
+        Experience::builder()
+            .ages((60..70).collect())
+            .exposures(vec![10_000.0; 10])
+            .deaths(z.iter().map(|z| 100.0 + z * sd).collect())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fn_graduation_tests() {
+        let mt = flat_table();
+        let z = [1.1, -0.5, 0.5, 2.5, -1.5, 0.2, -0.2, 1.2, -2.2, 0.7];
+        let tests = graduation_tests()
+            .experience(&experience_with_deviations(&z))
+            .mt(&mt)
+            .call()
+            .unwrap();
+
+        assert!((tests.chi_square.statistic - 17.06).abs() < 1e-10);
+        assert!((tests.chi_square.p_value - 0.073_046_831_170_276_12).abs() < 1e-12);
+        assert_eq!(tests.degrees_of_freedom, 10);
+
+        assert_eq!(tests.deviation_counts, [1, 1, 2, 3, 2, 1]);
+        assert!((tests.standardised_deviations.statistic - 6.278_661_418_344_034).abs() < 1e-9);
+
+        // 6 positive deviations out of 10: 2·P(X ≥ 6) = 2·386/1024
+        assert_eq!(tests.signs.statistic, 6.0);
+        assert!((tests.signs.p_value - 772.0 / 1024.0).abs() < 1e-12);
+
+        assert!((tests.cumulative_deviations.statistic - 0.569_209_978_830_308_1).abs() < 1e-10);
+        assert!((tests.cumulative_deviations.p_value - 0.569_213_649_473_711_1).abs() < 1e-10);
+
+        // + - + + - + - + - +: five groups, the most possible
+        assert_eq!(tests.grouping_of_signs.statistic, 5.0);
+        assert!((tests.grouping_of_signs.p_value - 1.0).abs() < 1e-12);
+
+        assert!((tests.serial_correlation.statistic + 1.598_678_237_494_54).abs() < 1e-10);
+        assert!((tests.serial_correlation.p_value - 0.945_053_942_205_563_9).abs() < 1e-10);
+        assert_eq!(tests.summary().unwrap().height(), 6);
+    }
+
+    #[test]
+    fn test_fn_graduation_tests_clumped_signs() {
+        let mt = flat_table();
+        let z = [1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, -1.0];
+        let tests = graduation_tests()
+            .experience(&experience_with_deviations(&z))
+            .mt(&mt)
+            .parameters(2)
+            .call()
+            .unwrap();
+
+        assert_eq!(tests.degrees_of_freedom, 8);
+        assert!(tests.cumulative_deviations.statistic.abs() < 1e-12);
+        // A single group: C(4, 0)·C(6, 1) / C(10, 5)
+        assert_eq!(tests.grouping_of_signs.statistic, 1.0);
+        assert!((tests.grouping_of_signs.p_value - 6.0 / 252.0).abs() < 1e-12);
+        // r₁ = 0.8
+        assert!((tests.serial_correlation.statistic - 0.8 * 10f64.sqrt()).abs() < 1e-12);
+        assert!((tests.serial_correlation.p_value - 0.005_706_018_193_000_824).abs() < 1e-12);
+
+        // Central exposures against mₓ, ages outside the table
+        let tests = graduation_tests()
+            .experience(&experience_with_deviations(&z))
+            .mt(&mt)
+            .exposure(ExposureEnum::Central)
+            .call()
+            .unwrap();
+        let expected = tests.deviations.column("expected").unwrap().f64().unwrap();
+        assert!((expected.get(0).unwrap() - 10_000.0 * 0.01 / 0.995).abs() < 1e-10);
+        let outside = Experience::builder()
+            .ages(vec![69, 70])
+            .exposures(vec![100.0; 2])
+            .deaths(vec![1.0; 2])
+            .build()
+            .unwrap();
+        assert!(
+            graduation_tests()
+                .experience(&outside)
+                .mt(&mt)
+                .call()
+                .is_err()
+        );
+
+        // No more ages than fitted parameters
+        assert!(matches!(
+            graduation_tests()
+                .experience(&outside)
+                .mt(&mt)
+                .parameters(2)
+                .call(),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_actual_vs_expected() {
+        let mt = MortTableConfig::builder()
+            .data(MortData::from_builtin("AM92").unwrap())
+            .build()
+            .unwrap();
+        let data = df! {
+            "age" => [60u32, 60, 61, 64, 65],
+            "duration" => [0u32, 2, 1, 7, 2],
+            "exposure" => [1000.0, 2000.0, 1000.0, 1000.0, 1000.0],
+            "deaths" => [3.0, 12.0, 5.0, 9.0, 10.0],
+        }
+        .unwrap();
+        let ae = actual_vs_expected().data(&data).mt(&mt).call().unwrap();
+
+        let lookup = mt.lookup().unwrap();
+        let q = |age, duration| lookup.qx(age, Some(duration)).unwrap();
+        let expected_60_64 =
+            1000.0 * q(60, 0) + 2000.0 * q(60, 2) + 1000.0 * q(61, 1) + 1000.0 * q(64, 2);
+
+        assert_eq!(ae.by_age.height(), 2);
+        let expected = ae.by_age.column("expected").unwrap().f64().unwrap();
+        assert!((expected.get(0).unwrap() - expected_60_64).abs() < 1e-12);
+        let age_to = ae.by_age.column("age_to").unwrap().u32().unwrap();
+        assert_eq!(age_to.get(1), Some(69));
+
+        // Durations 0, 1 and the ultimate duration 2 (from duration 2 up)
+        let by_duration = ae.by_duration.unwrap();
+        let durations = by_duration.column("duration").unwrap().u32().unwrap();
+        assert_eq!(durations.into_no_null_iter().collect::<Vec<_>>(), [0, 1, 2]);
+        let actual = by_duration.column("actual").unwrap().f64().unwrap();
+        assert_eq!(actual.get(2), Some(31.0));
+
+        assert_eq!(ae.actual, 39.0);
+        assert!((ae.ae_ratio - 39.0 / ae.expected).abs() < 1e-15);
+
+        // Ultimate experience has no duration analysis
+        let ultimate = data.drop("duration").unwrap();
+        let ae = actual_vs_expected()
+            .data(&ultimate)
+            .mt(&mt)
+            .age_band(1)
+            .call()
+            .unwrap();
+        assert!(ae.by_duration.is_none());
+        assert_eq!(ae.by_age.height(), 4);
+
+        assert!(matches!(
+            actual_vs_expected().data(&data).mt(&mt).age_band(0).call(),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+    }
+}
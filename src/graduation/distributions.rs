@@ -0,0 +1,140 @@
+//! Distribution functions for the statistical tests of graduation.
+//!
+//! The normal and chi-square distributions both come from the regularised incomplete gamma
+//! function, evaluated by its series or continued fraction.
+
+use std::f64::consts::PI;
+
+/// Lanczos coefficients (g = 7, n = 9).
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// ln Γ(x) for x > 0.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection: Γ(x)·Γ(1 - x) = π / sin(πx)
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// ln C(n, k).
+pub(crate) fn ln_choose(n: u64, k: u64) -> f64 {
+    ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+}
+
+/// Upper regularised incomplete gamma Q(a, x) = Γ(a, x) / Γ(a).
+pub(crate) fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let log_prefactor = a * x.ln() - x - ln_gamma(a);
+    if x < a + 1.0 {
+        // Series for P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..500 {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * 1e-16 {
+                break;
+            }
+        }
+        1.0 - sum * log_prefactor.exp()
+    } else {
+        // Continued fraction for Q(a, x) by the modified Lentz method
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..500 {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-16 {
+                break;
+            }
+        }
+        h * log_prefactor.exp()
+    }
+}
+
+/// Standard normal distribution function Φ(x).
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    // erfc(z) = Q(½, z²) for z ≥ 0
+    let tail = 0.5 * gamma_q(0.5, x * x / 2.0);
+    if x >= 0.0 { 1.0 - tail } else { tail }
+}
+
+/// Upper tail P(X > x) of the chi-square distribution with `df` degrees of freedom.
+pub(crate) fn chi_square_sf(x: f64, df: f64) -> f64 {
+    gamma_q(df / 2.0, x / 2.0)
+}
+
+/// P(X ≤ k) for X ~ Binomial(n, p).
+pub(crate) fn binomial_cdf(k: u64, n: u64, p: f64) -> f64 {
+    (0..=k.min(n))
+        .map(|i| (ln_choose(n, i) + i as f64 * p.ln() + (n - i) as f64 * (1.0 - p).ln()).exp())
+        .sum::<f64>()
+        .min(1.0)
+}
+
+/// Two-sided p-value 2·P(Z > |z|) of a standard normal statistic.
+pub(crate) fn normal_two_sided(z: f64) -> f64 {
+    2.0 * normal_cdf(-z.abs())
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::SQRT_2;
+
+    #[test]
+    fn test_fn_distributions() {
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-13);
+        assert!((ln_gamma(0.5) - PI.sqrt().ln()).abs() < 1e-13);
+        assert!((ln_choose(10, 3) - 120f64.ln()).abs() < 1e-12);
+
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-15);
+        assert!((normal_cdf(1.959_963_984_540_054) - 0.975).abs() < 1e-13);
+        assert!((normal_cdf(-SQRT_2) - 0.078_649_603_525_142_57).abs() < 1e-13);
+        assert!((normal_two_sided(-1.959_963_984_540_054) - 0.05).abs() < 1e-13);
+
+        assert!((chi_square_sf(3.841_458_820_694_124, 1.0) - 0.05).abs() < 1e-13);
+        assert!((chi_square_sf(18.307_038_053_275_146, 10.0) - 0.05).abs() < 1e-13);
+        assert_eq!(chi_square_sf(0.0, 4.0), 1.0);
+
+        assert!((binomial_cdf(2, 10, 0.5) - 56.0 / 1024.0).abs() < 1e-14);
+        assert!((binomial_cdf(10, 10, 0.5) - 1.0).abs() < 1e-14);
+    }
+}
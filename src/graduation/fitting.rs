@@ -0,0 +1,648 @@
+//! # Fitting of Parametric Mortality Laws
+//!
+//! Estimate the parameters of the Gompertz, Makeham and Weibull laws from experience data,
+//! the inverse of [`MortData::from_Gompertz_law`] and friends.
+//!
+//! The force of mortality μₜ of the law gives qₓ = 1 - exp(-∫ₓˣ⁺¹ μₜ dt), and the
+//! parameters are chosen by one of:
+//! - **Poisson**: maximum likelihood with dₓ ~ Poisson(Eₓ·qₓ), the Poisson approximation to
+//!   the binomial on the initial exposures Eₓ (not a Poisson model of central exposure and μₓ)
+//! - **Binomial**: maximum likelihood with dₓ ~ Binomial(Eₓ, qₓ)
+//! - **Weighted least squares**: minimise Σ Eₓ (q̂ₓ - qₓ)²
+//!
+//! All three are solved by Fisher scoring (Gauss–Newton for least squares) with step halving,
+//! on log B and log C (Gompertz, Makeham) or log k and n (Weibull). Standard errors come from
+//! the inverse Fisher information, by the delta method for the log parameters.
+//!
+//! To fit a published table, build the experience with [`Experience::from_mort_data`].
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! let elt15 = MortData::from_builtin("ELT15_M")?;
+//! let experience = Experience::from_mort_data()
+//!     .data(&elt15)
+//!     .exposure(10_000.0)
+//!     .min_age(40)
+//!     .max_age(90)
+//!     .call()?;
+//!
+//! let fit = fit_law()
+//!     .experience(&experience)
+//!     .law(LawFamilyEnum::Makeham)
+//!     .method(FitMethodEnum::Binomial)
+//!     .call()?;
+//!
+//! println!("Parameters: {:?}", fit.parameters);
+//! println!("Standard errors: {:?}", fit.standard_errors);
+//! let config = MortTableConfig::builder().data(fit.mort_data).build()?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::Experience;
+use crate::error::invalid_parameter;
+use crate::linalg::{invert, solve};
+use crate::mt_config::mt_data::{MortData, MortalityLawEnum};
+use crate::quadrature::gauss_legendre;
+use crate::{RSLifeError, RSLifeResult};
+use bon::builder;
+use polars::prelude::*;
+
+// ===============================================
+// LAWS AND METHODS
+// ===============================================
+
+/// Parametric law to fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LawFamilyEnum {
+    /// μₓ = B·Cˣ (parameters `B`, `C`)
+    Gompertz,
+    /// μₓ = A + B·Cˣ (parameters `A`, `B`, `C`)
+    Makeham,
+    /// μₓ = k·xⁿ (parameters `k`, `n`)
+    Weibull,
+}
+
+/// Criterion the parameters are chosen by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitMethodEnum {
+    /// Maximum likelihood with dₓ ~ Poisson(Eₓ·qₓ) on the initial exposures Eₓ, the Poisson
+    /// approximation to `Binomial`
+    Poisson,
+    /// Maximum likelihood with dₓ ~ Binomial(Eₓ, qₓ)
+    Binomial,
+    /// Minimise Σ Eₓ (q̂ₓ - qₓ)², standard errors scaled by the residual variance
+    WeightedLeastSquares,
+}
+
+impl LawFamilyEnum {
+    /// Parameter names, in the order of the working parameters.
+    fn names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Gompertz => &["B", "C"],
+            Self::Makeham => &["A", "B", "C"],
+            Self::Weibull => &["k", "n"],
+        }
+    }
+
+    /// Whether each working parameter is the log of the law parameter.
+    fn is_log(&self) -> &'static [bool] {
+        match self {
+            Self::Gompertz => &[true, true],
+            Self::Makeham => &[false, true, true],
+            Self::Weibull => &[true, false],
+        }
+    }
+
+    /// Force of mortality at age `t` and its gradient in the working parameters `theta`.
+    fn mu_and_gradient(&self, theta: &[f64], t: f64) -> (f64, Vec<f64>) {
+        match self {
+            Self::Gompertz => {
+                let mu = (theta[0] + theta[1] * t).exp();
+                (mu, vec![mu, t * mu])
+            }
+            Self::Makeham => {
+                let gompertz = (theta[1] + theta[2] * t).exp();
+                (theta[0] + gompertz, vec![1.0, gompertz, t * gompertz])
+            }
+            Self::Weibull => {
+                let mu = (theta[0] + theta[1] * t.ln()).exp();
+                (mu, vec![mu, t.ln() * mu])
+            }
+        }
+    }
+
+    /// qₓ and its gradient in the working parameters.
+    fn qx_and_gradient(&self, theta: &[f64], x: f64) -> (f64, Vec<f64>) {
+        let mut hazard = 0.0;
+        let mut gradient = vec![0.0; theta.len()];
+        // Integral of μ over the year of age
+        for (age, weight) in gauss_legendre(x, x + 1.0) {
+            let (mu, mu_gradient) = self.mu_and_gradient(theta, age);
+            hazard += weight * mu;
+            for (g, dmu) in gradient.iter_mut().zip(mu_gradient) {
+                *g += weight * dmu;
+            }
+        }
+        let survival = (-hazard).exp();
+        gradient.iter_mut().for_each(|g| *g *= survival);
+        (1.0 - survival, gradient)
+    }
+
+    /// Law parameters from the working parameters.
+    fn law(&self, parameters: &[f64]) -> MortalityLawEnum {
+        match self {
+            Self::Gompertz => MortalityLawEnum::Gompertz {
+                B: parameters[0],
+                C: parameters[1],
+            },
+            Self::Makeham => MortalityLawEnum::Makeham {
+                A: parameters[0],
+                B: parameters[1],
+                C: parameters[2],
+            },
+            Self::Weibull => MortalityLawEnum::Weibull {
+                k: parameters[0],
+                n: parameters[1],
+            },
+        }
+    }
+}
+
+// ===============================================
+// FIT
+// ===============================================
+
+/// Result of fitting a parametric law.
+#[derive(Debug, Clone)]
+pub struct LawFit {
+    /// Fitted law with its parameters
+    pub law: MortalityLawEnum,
+    /// Criterion used
+    pub method: FitMethodEnum,
+    /// Fitted parameters by name
+    pub parameters: Vec<(String, f64)>,
+    /// Asymptotic standard errors of the fitted parameters, by name
+    pub standard_errors: Vec<(String, f64)>,
+    /// Fitted values: `age`, `exposure`, `deaths`, `crude_qx`, `qx`, `expected_deaths`
+    pub fitted: DataFrame,
+    /// Table generated from the fitted law, as by `MortData::from_Gompertz_law` and friends
+    pub mort_data: MortData,
+}
+
+impl LawFit {
+    /// Fitted parameter `name`, if the law has one.
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameters
+            .iter()
+            .find(|(parameter, _)| parameter == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Fit `law` to `experience` by `method` (default Poisson).
+///
+/// The table in `mort_data` runs from `start_age` to `omega` (default 150). By default
+/// `start_age` is the youngest experience age, or for a fitted Makeham law with μₓ < 0 there
+/// (A < 0 and small B), the first age from which μₓ ≥ 0.
+///
+/// # Errors
+/// - `InvalidParameters`: no more ages than parameters
+/// - Deaths at fewer than two ages
+/// - Fit not converging or singular
+/// - Fitted parameters outside the range of the law over `start_age..=omega`
+///   (e.g. Weibull n ≤ 1, Makeham μ < 0 at `start_age`)
+#[builder]
+pub fn fit_law(
+    experience: &Experience,
+    law: LawFamilyEnum,
+    #[builder(default = FitMethodEnum::Poisson)] method: FitMethodEnum,
+    start_age: Option<u32>,
+    #[builder(default = 150)] omega: u32,
+) -> RSLifeResult<LawFit> {
+    let n_params = law.names().len();
+    if experience.ages.len() <= n_params {
+        return Err(invalid_parameter(
+            "experience",
+            format!("Fitting the {law:?} law needs more than {n_params} ages"),
+        ));
+    }
+
+    let ages: Vec<f64> = experience.ages.iter().map(|age| *age as f64).collect();
+    let crude = experience.crude_rates();
+    let objective = |theta: &[f64]| objective(law, method, theta, &ages, experience);
+
+    let mut theta = starting_values(law, &ages, experience)?;
+    let mut current = objective(&theta);
+    let mut converged = false;
+    for _ in 0..200 {
+        let (information, score) = scoring(law, method, &theta, &ages, experience);
+        let step = solve(information, score).ok_or("Law fitting information is singular")?;
+
+        // Halve the step until the objective does not get worse
+        let mut scale = 1.0;
+        let mut next: Vec<f64> = theta.iter().zip(&step).map(|(t, s)| t + s).collect();
+        let mut value = objective(&next);
+        while value < current && scale > 1e-10 {
+            scale /= 2.0;
+            next = theta
+                .iter()
+                .zip(&step)
+                .map(|(t, s)| t + scale * s)
+                .collect();
+            value = objective(&next);
+        }
+        if !value.is_finite() {
+            return Err("Law fitting left the range of the law".into());
+        }
+
+        converged = step
+            .iter()
+            .zip(&next)
+            .all(|(s, t)| (scale * s).abs() <= 1e-10 * (1.0 + t.abs()));
+        theta = next;
+        current = value;
+        if converged {
+            break;
+        }
+    }
+    if !converged {
+        return Err("Law fitting did not converge within 200 iterations".into());
+    }
+
+    // Covariance from the information at the optimum
+    let (information, _) = scoring(law, method, &theta, &ages, experience);
+    let mut covariance = invert(&information).ok_or("Law fitting information is singular")?;
+    if method == FitMethodEnum::WeightedLeastSquares {
+        let variance = -current / (ages.len() - n_params) as f64;
+        covariance
+            .iter_mut()
+            .flatten()
+            .for_each(|value| *value *= variance);
+    }
+
+    // Law parameters, standard errors by the delta method for log parameters
+    let (values, errors): (Vec<f64>, Vec<f64>) = theta
+        .iter()
+        .zip(law.is_log())
+        .enumerate()
+        .map(|(i, (t, is_log))| {
+            let se = covariance[i][i].sqrt();
+            if *is_log {
+                (t.exp(), t.exp() * se)
+            } else {
+                (*t, se)
+            }
+        })
+        .unzip();
+    let names = law.names().iter().map(|name| name.to_string());
+    let parameters = names.clone().zip(values.clone()).collect();
+    let standard_errors = names.zip(errors).collect();
+    let mortality_law = law.law(&values);
+
+    // A fitted Makeham μ can be negative at the youngest ages; by default start where it is not
+    let start_age = start_age.unwrap_or_else(|| {
+        let youngest = experience.ages[0];
+        match mortality_law {
+            MortalityLawEnum::Makeham { A, B, C } => (youngest..omega)
+                .find(|x| A >= -B * C.powf(f64::from(*x)))
+                .unwrap_or(omega),
+            _ => youngest,
+        }
+    });
+
+    let graduated: Vec<f64> = ages
+        .iter()
+        .map(|x| law.qx_and_gradient(&theta, *x).0)
+        .collect();
+    let expected: Vec<f64> = graduated
+        .iter()
+        .zip(&experience.exposures)
+        .map(|(q, e)| q * e)
+        .collect();
+    let fitted = df! {
+        "age" => &experience.ages,
+        "exposure" => &experience.exposures,
+        "deaths" => &experience.deaths,
+        "crude_qx" => &crude,
+        "qx" => &graduated,
+        "expected_deaths" => expected,
+    }?;
+
+    let table = match mortality_law {
+        MortalityLawEnum::Gompertz { B, C } => MortData::from_Gompertz_law()
+            .B(B)
+            .C(C)
+            .start_age(start_age)
+            .omega(omega)
+            .call(),
+        MortalityLawEnum::Makeham { A, B, C } => MortData::from_Makeham_law()
+            .A(A)
+            .B(B)
+            .C(C)
+            .start_age(start_age)
+            .omega(omega)
+            .call(),
+        MortalityLawEnum::Weibull { k, n } => MortData::from_Weibull_law()
+            .k(k)
+            .n(n)
+            .start_age(start_age)
+            .omega(omega)
+            .call(),
+    };
+    let mut mort_data = table.map_err(|e| {
+        RSLifeError::from(format!(
+            "Fitted {mortality_law:?} is outside the range of the law ({e}); try other ages or another law"
+        ))
+    })?;
+    mort_data.description = format!("{law:?} law fitted by {method:?}");
+
+    Ok(LawFit {
+        law: mortality_law,
+        method,
+        parameters,
+        standard_errors,
+        fitted,
+        mort_data,
+    })
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// Log-likelihood (Poisson, binomial, constants dropped) or minus the weighted sum of squares.
+///
+/// Parameters giving rates outside (0, 1) score minus infinity.
+fn objective(
+    law: LawFamilyEnum,
+    method: FitMethodEnum,
+    theta: &[f64],
+    ages: &[f64],
+    experience: &Experience,
+) -> f64 {
+    let mut total = 0.0;
+    for ((x, e), d) in ages
+        .iter()
+        .zip(&experience.exposures)
+        .zip(&experience.deaths)
+    {
+        let (q, _) = law.qx_and_gradient(theta, *x);
+        if !(q > 0.0 && q < 1.0) {
+            return f64::NEG_INFINITY;
+        }
+        total += match method {
+            FitMethodEnum::Poisson => d * q.ln() - e * q,
+            FitMethodEnum::Binomial => d * q.ln() + (e - d) * (1.0 - q).ln(),
+            FitMethodEnum::WeightedLeastSquares => -e * (d / e - q).powi(2),
+        };
+    }
+    total
+}
+
+/// Fisher information and score in the working parameters.
+///
+/// Each criterion weights ∂qₓ/∂θ by vₓ: Eₓ/qₓ (Poisson), Eₓ/(qₓ(1 - qₓ)) (binomial) or
+/// Eₓ (least squares), so that the step solves (Σ vₓ ∂q ∂qᵀ) δ = Σ vₓ (q̂ₓ - qₓ) ∂q.
+fn scoring(
+    law: LawFamilyEnum,
+    method: FitMethodEnum,
+    theta: &[f64],
+    ages: &[f64],
+    experience: &Experience,
+) -> (Vec<Vec<f64>>, Vec<f64>) {
+    let n = theta.len();
+    let mut information = vec![vec![0.0; n]; n];
+    let mut score = vec![0.0; n];
+    for ((x, e), d) in ages
+        .iter()
+        .zip(&experience.exposures)
+        .zip(&experience.deaths)
+    {
+        let (q, gradient) = law.qx_and_gradient(theta, *x);
+        let weight = match method {
+            FitMethodEnum::Poisson => e / q,
+            FitMethodEnum::Binomial => e / (q * (1.0 - q)),
+            FitMethodEnum::WeightedLeastSquares => *e,
+        };
+        for j in 0..n {
+            score[j] += weight * (d / e - q) * gradient[j];
+            for k in 0..n {
+                information[j][k] += weight * gradient[j] * gradient[k];
+            }
+        }
+    }
+    (information, score)
+}
+
+/// Starting values from a regression of log μ̂ on age (log age for Weibull), weighted by deaths.
+///
+/// μ̂ = -log(1 - q̂ₓ) estimates μ at age x + ½. Makeham starts from A = 0.
+fn starting_values(
+    law: LawFamilyEnum,
+    ages: &[f64],
+    experience: &Experience,
+) -> RSLifeResult<Vec<f64>> {
+    let mut normal = vec![vec![0.0; 2]; 2];
+    let mut rhs = vec![0.0; 2];
+    let mut count = 0;
+    for ((x, e), d) in ages
+        .iter()
+        .zip(&experience.exposures)
+        .zip(&experience.deaths)
+    {
+        if *d <= 0.0 || d >= e {
+            continue;
+        }
+        let mid = x + 0.5;
+        let covariate = if law == LawFamilyEnum::Weibull {
+            mid.ln()
+        } else {
+            mid
+        };
+        let log_mu = (-(1.0 - d / e).ln()).ln();
+        let row = [1.0, covariate];
+        for j in 0..2 {
+            rhs[j] += d * row[j] * log_mu;
+            for k in 0..2 {
+                normal[j][k] += d * row[j] * row[k];
+            }
+        }
+        count += 1;
+    }
+    if count < 2 {
+        return Err("Law fitting needs deaths at two ages at least".into());
+    }
+    let start = solve(normal, rhs).ok_or("Law fitting needs deaths at two ages at least")?;
+
+    Ok(match law {
+        LawFamilyEnum::Makeham => vec![0.0, start[0], start[1]],
+        _ => start,
+    })
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Experience with expected deaths from `law` at ages 30 to 90.
+    fn law_experience(law: &MortData) -> Experience {
+        Experience::from_mort_data()
+            .data(law)
+            .exposure(100_000.0)
+            .min_age(30)
+            .max_age(90)
+            .call()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fn_fit_law_recovers_parameters() {
+        let makeham = MortData::from_Makeham_law()
+            .A(0.00022)
+            .B(2.7e-6)
+            .C(1.124)
+            .call()
+            .unwrap();
+        let experience = law_experience(&makeham);
+
+        for method in [
+            FitMethodEnum::Poisson,
+            FitMethodEnum::Binomial,
+            FitMethodEnum::WeightedLeastSquares,
+        ] {
+            let fit = fit_law()
+                .experience(&experience)
+                .law(LawFamilyEnum::Makeham)
+                .method(method)
+                .call()
+                .unwrap();
+            assert!((fit.parameter("A").unwrap() / 0.00022 - 1.0).abs() < 1e-6);
+            assert!((fit.parameter("B").unwrap() / 2.7e-6 - 1.0).abs() < 1e-6);
+            assert!((fit.parameter("C").unwrap() - 1.124).abs() < 1e-8);
+            assert!(matches!(fit.law, MortalityLawEnum::Makeham { .. }));
+        }
+
+        let weibull = MortData::from_Weibull_law().k(1e-9).n(4.5).call().unwrap();
+        let fit = fit_law()
+            .experience(&law_experience(&weibull))
+            .law(LawFamilyEnum::Weibull)
+            .call()
+            .unwrap();
+        assert!((fit.parameter("k").unwrap() / 1e-9 - 1.0).abs() < 1e-5);
+        assert!((fit.parameter("n").unwrap() - 4.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fn_fit_law_to_table() {
+        let elt15 = MortData::from_builtin("ELT15_M").unwrap();
+        let experience = Experience::from_mort_data()
+            .data(&elt15)
+            .exposure(10_000.0)
+            .min_age(40)
+            .max_age(90)
+            .call()
+            .unwrap();
+
+        let fit = fit_law()
+            .experience(&experience)
+            .law(LawFamilyEnum::Gompertz)
+            .start_age(20)
+            .omega(110)
+            .call()
+            .unwrap();
+        let c = fit.parameter("C").unwrap();
+        assert!(c > 1.05 && c < 1.15);
+        assert!(fit.standard_errors.iter().all(|(_, se)| *se > 0.0));
+        assert_eq!(fit.fitted.height(), 51);
+
+        // The table follows the fitted law
+        let ages = fit.mort_data.dataframe.column("age").unwrap();
+        assert_eq!(ages.u32().unwrap().get(0), Some(20));
+        assert_eq!(fit.mort_data.metadata.law, Some(fit.law));
+
+        // Larger exposure, smaller standard errors
+        let larger = Experience::from_mort_data()
+            .data(&elt15)
+            .exposure(40_000.0)
+            .min_age(40)
+            .max_age(90)
+            .call()
+            .unwrap();
+        let larger_fit = fit_law()
+            .experience(&larger)
+            .law(LawFamilyEnum::Gompertz)
+            .call()
+            .unwrap();
+        let ratio = fit.standard_errors[1].1 / larger_fit.standard_errors[1].1;
+        assert!((ratio - 2.0).abs() < 1e-6);
+
+        // Makeham by every method, the table starting at the youngest age of the experience
+        for (min_age, max_age) in [(20, 100), (40, 90)] {
+            let experience = Experience::from_mort_data()
+                .data(&elt15)
+                .exposure(10_000.0)
+                .min_age(min_age)
+                .max_age(max_age)
+                .call()
+                .unwrap();
+            for method in [
+                FitMethodEnum::Poisson,
+                FitMethodEnum::Binomial,
+                FitMethodEnum::WeightedLeastSquares,
+            ] {
+                let fit = fit_law()
+                    .experience(&experience)
+                    .law(LawFamilyEnum::Makeham)
+                    .method(method)
+                    .call()
+                    .unwrap();
+                assert_eq!(fit.standard_errors.len(), 3);
+                let MortalityLawEnum::Makeham { A, B, C } = fit.law else {
+                    panic!("Makeham law expected");
+                };
+                let table = &fit.mort_data.dataframe;
+                let start_age = table.column("age").unwrap().u32().unwrap().get(0).unwrap();
+                assert!(start_age >= min_age && start_age < min_age + 5);
+                assert!(A + B * C.powf(f64::from(start_age)) >= 0.0);
+                let table_qx = table.column("qx").unwrap().f64().unwrap();
+                let fitted_qx = fit.fitted.column("qx").unwrap().f64().unwrap();
+                let offset = (start_age - min_age) as usize;
+                for row in 0..table_qx.len().min(fitted_qx.len() - offset) {
+                    let q = table_qx.get(row).unwrap();
+                    assert!((q / fitted_qx.get(row + offset).unwrap() - 1.0).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fn_fit_law_errors() {
+        let experience = Experience::builder()
+            .ages(vec![60, 61, 62])
+            .exposures(vec![100.0; 3])
+            .deaths(vec![0.0, 0.0, 1.0])
+            .build()
+            .unwrap();
+        // Too few ages for Makeham, deaths at a single age for Gompertz
+        assert!(matches!(
+            fit_law()
+                .experience(&experience)
+                .law(LawFamilyEnum::Makeham)
+                .call(),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+        assert!(
+            fit_law()
+                .experience(&experience)
+                .law(LawFamilyEnum::Gompertz)
+                .call()
+                .is_err()
+        );
+
+        // Makeham law below the ages where the fitted μ is non-negative
+        let elt15 = MortData::from_builtin("ELT15_M").unwrap();
+        let experience = Experience::from_mort_data()
+            .data(&elt15)
+            .exposure(10_000.0)
+            .min_age(40)
+            .max_age(90)
+            .call()
+            .unwrap();
+        let result = fit_law()
+            .experience(&experience)
+            .law(LawFamilyEnum::Makeham)
+            .start_age(0)
+            .call();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("outside the range")
+        );
+    }
+}
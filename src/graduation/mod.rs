@@ -0,0 +1,720 @@
+//! # Graduation
+//!
+//! Smooth crude mortality rates from experience data (deaths and exposures by age) into
+//! graduated rates q̊ₓ, returned as a [`MortData`] ready for [`MortTableConfig`](crate::mt_config::MortTableConfig).
+//!
+//! Exposures are initial exposed to risk Eₓ, so that the crude rates are q̂ₓ = dₓ / Eₓ.
+//!
+//! ## Methods
+//! - **Whittaker–Henderson**: minimise Σ wₓ(q̊ₓ - q̂ₓ)² + h Σ (Δᶻ q̊ₓ)², weights proportional to Eₓ
+//! - **Standard table**: g(q̊ₓ) = a + b·g(qₓˢ) against any table, with a linear or logit link g
+//! - **Parametric formula**: Gompertz q̊ₓ = B·Cˣ, or a polynomial in x for logit q̊ₓ
+//!
+//! The standard table and parametric methods are fitted by binomial maximum likelihood.
+//! To fit the Gompertz, Makeham or Weibull law itself, see [`fitting`]; to test a graduation
+//! and analyse actual versus expected deaths, see [`analysis`].
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! let experience = Experience::builder()
+//!     .ages((60..70).collect())
+//!     .exposures(vec![1000.0; 10])
+//!     .deaths(vec![8.0, 11.0, 10.0, 13.0, 12.0, 16.0, 15.0, 19.0, 20.0, 24.0])
+//!     .build()?;
+//!
+//! let graduation = graduate()
+//!     .experience(&experience)
+//!     .method(GraduationMethodEnum::StandardTable {
+//!         standard: MortData::from_builtin("AM92")?,
+//!         link: LinkEnum::Logit,
+//!     })
+//!     .call()?;
+//!
+//! println!("Parameters: {:?}", graduation.parameters);
+//! println!("Fitted values:\n{}", graduation.fitted);
+//! let config = MortTableConfig::builder().data(graduation.mort_data).build()?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+pub mod analysis;
+pub(crate) mod distributions;
+pub mod fitting;
+pub(crate) mod glm;
+pub(crate) mod whittaker;
+
+use self::glm::{Link, fit_binomial};
+use self::whittaker::whittaker_henderson;
+use crate::error::invalid_parameter;
+use crate::mt_config::mt_data::MortData;
+use crate::{RSLifeError, RSLifeResult};
+use bon::{bon, builder};
+use polars::prelude::*;
+
+// ===============================================
+// EXPERIENCE DATA
+// ===============================================
+
+/// Observed deaths and initial exposures at consecutive ages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Experience {
+    /// Consecutive ages in ascending order
+    pub ages: Vec<u32>,
+    /// Initial exposed to risk Eₓ at each age
+    pub exposures: Vec<f64>,
+    /// Observed deaths dₓ at each age
+    pub deaths: Vec<f64>,
+}
+
+#[bon]
+impl Experience {
+    /// Create experience data from ages, exposures and deaths.
+    ///
+    /// # Errors
+    /// - `InvalidParameters`: empty data or vectors of different lengths
+    /// - Ages not consecutive and ascending
+    /// - Exposure not positive, or deaths negative or above the exposure
+    #[builder]
+    pub fn new(ages: Vec<u32>, exposures: Vec<f64>, deaths: Vec<f64>) -> RSLifeResult<Self> {
+        if ages.is_empty() {
+            return Err(invalid_parameter("ages", "Ages must not be empty"));
+        }
+        for (name, len) in [("exposures", exposures.len()), ("deaths", deaths.len())] {
+            if len != ages.len() {
+                return Err(invalid_parameter(
+                    name,
+                    format!("{name} has {len} values but ages has {}", ages.len()),
+                ));
+            }
+        }
+        if ages.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+            return Err(RSLifeError::Schema(
+                "Ages must be consecutive and ascending".into(),
+            ));
+        }
+        for ((age, exposure), death) in ages.iter().zip(&exposures).zip(&deaths) {
+            if !(exposure.is_finite() && *exposure > 0.0) {
+                return Err(RSLifeError::Schema(format!(
+                    "Exposure at age {age} must be positive"
+                )));
+            }
+            if !(death.is_finite() && *death >= 0.0 && death <= exposure) {
+                return Err(RSLifeError::Schema(format!(
+                    "Deaths at age {age} must be between 0 and the exposure"
+                )));
+            }
+        }
+        Ok(Self {
+            ages,
+            exposures,
+            deaths,
+        })
+    }
+
+    /// Pseudo-experience from the rates of a table, with `exposure` at every age and expected deaths.
+    ///
+    /// Use it to fit a formula to a published table such as `ELT15_M`. The exposure sets the
+    /// weight given to the table, and so the size of the standard errors. Select tables
+    /// contribute their ultimate rates; rates of 0 or 1 are left out.
+    ///
+    /// # Errors
+    /// - Table without qx
+    /// - Exposure not positive
+    /// - Ages with rates strictly between 0 and 1 not consecutive within `min_age` to `max_age`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use rslife::prelude::*;
+    /// let elt15 = MortData::from_builtin("ELT15_M")?;
+    /// let experience = Experience::from_mort_data()
+    ///     .data(&elt15)
+    ///     .exposure(10_000.0)
+    ///     .min_age(30)
+    ///     .max_age(90)
+    ///     .call()?;
+    /// assert_eq!(experience.ages.len(), 61);
+    /// # RSLifeResult::Ok(())
+    /// ```
+    #[builder]
+    pub fn from_mort_data(
+        data: &MortData,
+        exposure: f64,
+        min_age: Option<u32>,
+        max_age: Option<u32>,
+    ) -> RSLifeResult<Self> {
+        let (ages, rates) = ultimate_rates(data)?;
+        let (ages, deaths): (Vec<u32>, Vec<f64>) = ages
+            .into_iter()
+            .zip(rates)
+            .filter(|(age, q)| {
+                *q > 0.0
+                    && *q < 1.0
+                    && min_age.is_none_or(|min| *age >= min)
+                    && max_age.is_none_or(|max| *age <= max)
+            })
+            .map(|(age, q)| (age, q * exposure))
+            .unzip();
+        let exposures = vec![exposure; ages.len()];
+        Self::builder()
+            .ages(ages)
+            .exposures(exposures)
+            .deaths(deaths)
+            .build()
+    }
+}
+
+impl Experience {
+    /// Experience data from a DataFrame with columns "age", "exposure" and "deaths".
+    ///
+    /// # Errors
+    /// - Missing columns, invalid data types or null values
+    /// - All errors from `new()`
+    pub fn from_df(df: &DataFrame) -> RSLifeResult<Self> {
+        let ages = column_values(df, "age", &DataType::UInt32, |c| {
+            Ok(c.u32()?.iter().collect())
+        })?;
+        let exposures = column_values(df, "exposure", &DataType::Float64, |c| {
+            Ok(c.f64()?.iter().collect())
+        })?;
+        let deaths = column_values(df, "deaths", &DataType::Float64, |c| {
+            Ok(c.f64()?.iter().collect())
+        })?;
+        Self::builder()
+            .ages(ages)
+            .exposures(exposures)
+            .deaths(deaths)
+            .build()
+    }
+
+    /// Crude rates q̂ₓ = dₓ / Eₓ.
+    pub fn crude_rates(&self) -> Vec<f64> {
+        self.deaths
+            .iter()
+            .zip(&self.exposures)
+            .map(|(d, e)| d / e)
+            .collect()
+    }
+}
+
+// ===============================================
+// GRADUATION METHODS
+// ===============================================
+
+/// Link between graduated and standard rates for graduation by reference to a standard table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkEnum {
+    /// q̊ₓ = a + b·qₓˢ
+    Linear,
+    /// logit q̊ₓ = a + b·logit qₓˢ
+    Logit,
+}
+
+/// Parametric formula in age for graduation by formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaEnum {
+    /// q̊ₓ = B·Cˣ (parameters `B`, `C`)
+    Gompertz,
+    /// logit q̊ₓ = a₀ + a₁t + ... + aₖtᵏ in the standardised age t = (x - x̄) / s
+    /// (parameters `a0` to `ak`), where x̄ and s are the mean and standard deviation of the
+    /// experience ages
+    LogitPolynomial { degree: usize },
+}
+
+/// Graduation method and its settings.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum GraduationMethodEnum {
+    /// Whittaker–Henderson with smoothness `h` (≥ 0) and difference `order` (≥ 1, usually 2 or 3).
+    /// Weights are the exposures scaled to average 1.
+    WhittakerHenderson { h: f64, order: usize },
+    /// Graduation by reference to `standard` (ultimate rates of select tables) through `link`.
+    StandardTable { standard: MortData, link: LinkEnum },
+    /// Graduation by parametric formula.
+    Parametric { formula: FormulaEnum },
+}
+
+/// Result of a graduation.
+#[derive(Debug, Clone)]
+pub struct Graduation {
+    /// Method and settings used
+    pub method: GraduationMethodEnum,
+    /// Fitted parameters by name, in the order of the formula
+    pub parameters: Vec<(String, f64)>,
+    /// Asymptotic standard errors of the fitted parameters, by name (none for Whittaker–Henderson)
+    pub standard_errors: Vec<(String, f64)>,
+    /// Fitted values: `age`, `exposure`, `deaths`, `crude_qx`, `qx`, `expected_deaths`
+    pub fitted: DataFrame,
+    /// Graduated table of `age` and `qx`
+    pub mort_data: MortData,
+}
+
+impl Graduation {
+    /// Fitted parameter `name`, if the method has one.
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameters
+            .iter()
+            .find(|(parameter, _)| parameter == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Graduate crude rates of `experience` with `method`.
+///
+/// # Errors
+/// - `InvalidParameters`: invalid method settings (negative h, order 0, degree 0)
+/// - Experience ages outside the standard table, or standard rates not in (0, 1) for the logit link
+/// - Fit not converging or singular (e.g. too few ages for the formula)
+/// - Graduated rates failing `MortData` validation
+#[builder]
+pub fn graduate(experience: &Experience, method: GraduationMethodEnum) -> RSLifeResult<Graduation> {
+    let crude = experience.crude_rates();
+    let ages: Vec<f64> = experience.ages.iter().map(|age| *age as f64).collect();
+
+    let (graduated, parameters, standard_errors, description) = match &method {
+        GraduationMethodEnum::WhittakerHenderson { h, order } => {
+            if !(h.is_finite() && *h >= 0.0) {
+                return Err(invalid_parameter("h", "Whittaker-Henderson needs h >= 0"));
+            }
+            if *order == 0 {
+                return Err(invalid_parameter(
+                    "order",
+                    "Whittaker-Henderson needs order >= 1",
+                ));
+            }
+            let mean = experience.exposures.iter().sum::<f64>() / ages.len() as f64;
+            let weights: Vec<f64> = experience.exposures.iter().map(|e| e / mean).collect();
+            let graduated = whittaker_henderson(&crude, &weights, *h, *order)?
+                .into_iter()
+                .map(|q| q.clamp(0.0, 1.0))
+                .collect();
+            let parameters = vec![("h".to_string(), *h), ("order".to_string(), *order as f64)];
+            let description = format!("Whittaker-Henderson graduation (h = {h}, order {order})");
+            (graduated, parameters, Vec::new(), description)
+        }
+        GraduationMethodEnum::StandardTable { standard, link } => {
+            let standard_rates = standard_rates(standard, &experience.ages)?;
+            let link = match link {
+                LinkEnum::Linear => Link::Identity,
+                LinkEnum::Logit => {
+                    if standard_rates.iter().any(|q| *q <= 0.0 || *q >= 1.0) {
+                        return Err(RSLifeError::Schema(
+                            "Standard rates must be between 0 and 1 for the logit link".into(),
+                        ));
+                    }
+                    Link::Logit
+                }
+            };
+            let design: Vec<Vec<f64>> = standard_rates
+                .iter()
+                .map(|q| vec![1.0, link.link(*q)])
+                .collect();
+            let fit = fit_binomial(&design, &experience.deaths, &experience.exposures, link)?;
+            let names = ["a", "b"].map(str::to_string);
+            let parameters = names
+                .iter()
+                .cloned()
+                .zip(fit.coefficients.clone())
+                .collect();
+            let standard_errors = names.into_iter().zip(fit.standard_errors()).collect();
+            let description = format!(
+                "Graduation by reference to {} ({link:?} link)",
+                standard.description
+            );
+            (fit.fitted, parameters, standard_errors, description)
+        }
+        GraduationMethodEnum::Parametric { formula } => {
+            let (degree, link) = match formula {
+                FormulaEnum::Gompertz => (1, Link::Log),
+                FormulaEnum::LogitPolynomial { degree } if *degree >= 1 => (*degree, Link::Logit),
+                FormulaEnum::LogitPolynomial { .. } => {
+                    return Err(invalid_parameter(
+                        "degree",
+                        "Logit polynomial needs degree >= 1",
+                    ));
+                }
+            };
+            // Raw powers of age are nearly collinear, so polynomials use the standardised age
+            let ages = match formula {
+                FormulaEnum::Gompertz => ages,
+                FormulaEnum::LogitPolynomial { .. } => standardise(&ages),
+            };
+            let design: Vec<Vec<f64>> = ages
+                .iter()
+                .map(|x| (0..=degree).map(|power| x.powi(power as i32)).collect())
+                .collect();
+            let fit = fit_binomial(&design, &experience.deaths, &experience.exposures, link)?;
+            let errors = fit.standard_errors();
+            let (parameters, standard_errors) = match formula {
+                // B = eᵃ and C = eᵇ, standard errors by the delta method
+                FormulaEnum::Gompertz => {
+                    let (b, c) = (fit.coefficients[0].exp(), fit.coefficients[1].exp());
+                    (
+                        vec![("B".to_string(), b), ("C".to_string(), c)],
+                        vec![
+                            ("B".to_string(), b * errors[0]),
+                            ("C".to_string(), c * errors[1]),
+                        ],
+                    )
+                }
+                FormulaEnum::LogitPolynomial { .. } => {
+                    let names: Vec<String> =
+                        (0..=degree).map(|power| format!("a{power}")).collect();
+                    (
+                        names
+                            .iter()
+                            .cloned()
+                            .zip(fit.coefficients.clone())
+                            .collect(),
+                        names.into_iter().zip(errors).collect(),
+                    )
+                }
+            };
+            let description = format!("Graduation by formula ({formula:?})");
+            (fit.fitted, parameters, standard_errors, description)
+        }
+    };
+
+    let expected: Vec<f64> = graduated
+        .iter()
+        .zip(&experience.exposures)
+        .map(|(q, e)| q * e)
+        .collect();
+    let fitted = df! {
+        "age" => &experience.ages,
+        "exposure" => &experience.exposures,
+        "deaths" => &experience.deaths,
+        "crude_qx" => &crude,
+        "qx" => &graduated,
+        "expected_deaths" => expected,
+    }?;
+
+    let data = fitted.select(["age", "qx"])?;
+    let mort_data = MortData::new("Graduated Mortality Data".to_string(), description, data)?;
+
+    Ok(Graduation {
+        method,
+        parameters,
+        standard_errors,
+        fitted,
+        mort_data,
+    })
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// Values of column `name` cast to `dtype`, rejecting nulls.
+pub(crate) fn column_values<T>(
+    df: &DataFrame,
+    name: &str,
+    dtype: &DataType,
+    values: impl Fn(&Column) -> PolarsResult<Vec<Option<T>>>,
+) -> RSLifeResult<Vec<T>> {
+    let column = df.column(name)?.cast(dtype)?;
+    values(&column)?
+        .into_iter()
+        .collect::<Option<Vec<T>>>()
+        .ok_or_else(|| RSLifeError::Schema(format!("Column '{name}' must not contain nulls")))
+}
+
+/// Values less their mean, divided by their standard deviation (1 when all values are equal).
+fn standardise(values: &[f64]) -> Vec<f64> {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let sd = if variance > 0.0 { variance.sqrt() } else { 1.0 };
+    values.iter().map(|x| (x - mean) / sd).collect()
+}
+
+/// Ages and rates of `data`, using the ultimate rates of select tables and skipping missing rates.
+fn ultimate_rates(data: &MortData) -> RSLifeResult<(Vec<u32>, Vec<f64>)> {
+    let mut df = data.dataframe.clone();
+    if df
+        .get_column_names()
+        .iter()
+        .any(|n| n.as_str() == "duration")
+    {
+        df = df
+            .lazy()
+            .filter(col("duration").eq(col("duration").max()))
+            .collect()?;
+    }
+    if !df.get_column_names().iter().any(|n| n.as_str() == "qx") {
+        return Err(RSLifeError::Schema("Table must contain qx".into()));
+    }
+
+    let ages = df.column("age")?.cast(&DataType::UInt32)?;
+    let qx = df.column("qx")?.cast(&DataType::Float64)?;
+    let rates = ages
+        .u32()?
+        .iter()
+        .zip(qx.f64()?.iter())
+        .filter_map(|(age, q)| Some((age?, q?)))
+        .filter(|(_, q)| !q.is_nan())
+        .unzip();
+    Ok(rates)
+}
+
+/// Rates of `standard` at `ages`, using the ultimate rates of select tables.
+fn standard_rates(standard: &MortData, ages: &[u32]) -> RSLifeResult<Vec<f64>> {
+    let (table_ages, qx) = ultimate_rates(standard)?;
+    let min_age = table_ages.iter().min().copied().unwrap_or(0);
+    let max_age = table_ages.iter().max().copied().unwrap_or(0);
+
+    ages.iter()
+        .map(|age| {
+            table_ages
+                .iter()
+                .position(|table_age| table_age == age)
+                .map(|row| qx[row])
+                .ok_or(RSLifeError::AgeOutOfRange {
+                    age: *age as f64,
+                    min_age: min_age as f64,
+                    max_age: max_age as f64,
+                })
+        })
+        .collect()
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected deaths from AM92 ultimate rates scaled by 90%, rounded to whole deaths.
+    fn am92_experience() -> Experience {
+        let standard = MortData::from_builtin("AM92").unwrap();
+        let ages: Vec<u32> = (40..80).collect();
+        let rates = standard_rates(&standard, &ages).unwrap();
+        let exposures = vec![5000.0; ages.len()];
+        let deaths = rates.iter().map(|q| (0.9 * q * 5000.0).round()).collect();
+        Experience::builder()
+            .ages(ages)
+            .exposures(exposures)
+            .deaths(deaths)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fn_experience_validation() {
+        let build = |ages: Vec<u32>, deaths: Vec<f64>| {
+            Experience::builder()
+                .ages(ages)
+                .exposures(vec![100.0, 100.0])
+                .deaths(deaths)
+                .build()
+        };
+        assert!(build(vec![60, 61], vec![1.0, 2.0]).is_ok());
+        assert!(build(vec![60, 62], vec![1.0, 2.0]).is_err());
+        assert!(build(vec![60, 61], vec![1.0, 200.0]).is_err());
+        assert!(matches!(
+            build(vec![60], vec![1.0, 2.0]),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+
+        let df = df! {
+            "age" => [60u32, 61],
+            "exposure" => [100.0, 100.0],
+            "deaths" => [1.0, 2.0],
+        }
+        .unwrap();
+        assert_eq!(
+            Experience::from_df(&df).unwrap().crude_rates(),
+            [0.01, 0.02]
+        );
+
+        let df = df! {
+            "age" => [60u32, 61],
+            "exposure" => [Some(100.0), None],
+            "deaths" => [1.0, 2.0],
+        }
+        .unwrap();
+        assert!(matches!(
+            Experience::from_df(&df),
+            Err(RSLifeError::Schema(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_graduate_whittaker_henderson() {
+        let experience = am92_experience();
+        let graduation = graduate()
+            .experience(&experience)
+            .method(GraduationMethodEnum::WhittakerHenderson { h: 10.0, order: 3 })
+            .call()
+            .unwrap();
+
+        assert_eq!(graduation.parameter("h"), Some(10.0));
+        assert_eq!(graduation.fitted.height(), 40);
+        assert_eq!(graduation.mort_data.dataframe.width(), 2);
+
+        // Smoother than the rounded crude rates
+        let roughness = |name: &str| -> f64 {
+            let values: Vec<f64> = graduation
+                .fitted
+                .column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect();
+            values
+                .windows(4)
+                .map(|w| (w[3] - 3.0 * w[2] + 3.0 * w[1] - w[0]).powi(2))
+                .sum()
+        };
+        assert!(roughness("qx") < roughness("crude_qx"));
+
+        // Invalid method settings
+        for method in [
+            GraduationMethodEnum::WhittakerHenderson { h: -1.0, order: 3 },
+            GraduationMethodEnum::WhittakerHenderson { h: 10.0, order: 0 },
+            GraduationMethodEnum::Parametric {
+                formula: FormulaEnum::LogitPolynomial { degree: 0 },
+            },
+        ] {
+            assert!(matches!(
+                graduate().experience(&experience).method(method).call(),
+                Err(RSLifeError::InvalidParameters(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_fn_graduate_standard_table() {
+        let experience = am92_experience();
+        let standard = MortData::from_builtin("AM92").unwrap();
+
+        let graduation = graduate()
+            .experience(&experience)
+            .method(GraduationMethodEnum::StandardTable {
+                standard: standard.clone(),
+                link: LinkEnum::Linear,
+            })
+            .call()
+            .unwrap();
+        // Deaths are 90% of the standard
+        assert!(graduation.parameter("a").unwrap().abs() < 1e-4);
+        assert!(graduation.standard_errors[1].1 > 0.0);
+        assert!((graduation.parameter("b").unwrap() - 0.9).abs() < 0.01);
+
+        let graduation = graduate()
+            .experience(&experience)
+            .method(GraduationMethodEnum::StandardTable {
+                standard,
+                link: LinkEnum::Logit,
+            })
+            .call()
+            .unwrap();
+        assert!((graduation.parameter("b").unwrap() - 1.0).abs() < 0.05);
+
+        // Experience outside the standard table
+        let young = Experience::builder()
+            .ages(vec![5, 6])
+            .exposures(vec![100.0, 100.0])
+            .deaths(vec![0.0, 0.0])
+            .build()
+            .unwrap();
+        assert!(
+            graduate()
+                .experience(&young)
+                .method(GraduationMethodEnum::StandardTable {
+                    standard: MortData::from_builtin("AM92").unwrap(),
+                    link: LinkEnum::Linear,
+                })
+                .call()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_fn_graduate_parametric() {
+        // Deaths exactly from q = B·Cˣ
+        let ages: Vec<u32> = (50..90).collect();
+        let exposures = vec![10_000.0; ages.len()];
+        let deaths = ages
+            .iter()
+            .map(|x| 10_000.0 * 0.00005 * 1.1f64.powi(*x as i32))
+            .collect();
+        let experience = Experience::builder()
+            .ages(ages)
+            .exposures(exposures)
+            .deaths(deaths)
+            .build()
+            .unwrap();
+
+        let graduation = graduate()
+            .experience(&experience)
+            .method(GraduationMethodEnum::Parametric {
+                formula: FormulaEnum::Gompertz,
+            })
+            .call()
+            .unwrap();
+        assert!((graduation.parameter("B").unwrap() / 0.00005 - 1.0).abs() < 1e-8);
+        assert!((graduation.parameter("C").unwrap() - 1.1).abs() < 1e-10);
+
+        let graduation = graduate()
+            .experience(&experience)
+            .method(GraduationMethodEnum::Parametric {
+                formula: FormulaEnum::LogitPolynomial { degree: 2 },
+            })
+            .call()
+            .unwrap();
+        assert_eq!(graduation.parameters.len(), 3);
+        assert_eq!(graduation.standard_errors.len(), 3);
+
+        // Higher degrees converge on the standardised age
+        let doc_experience = Experience::builder()
+            .ages((60..70).collect())
+            .exposures(vec![1000.0; 10])
+            .deaths(vec![
+                8.0, 11.0, 10.0, 13.0, 12.0, 16.0, 15.0, 19.0, 20.0, 24.0,
+            ])
+            .build()
+            .unwrap();
+        let am92 = Experience::from_mort_data()
+            .data(&MortData::from_builtin("AM92").unwrap())
+            .exposure(10_000.0)
+            .min_age(40)
+            .max_age(90)
+            .call()
+            .unwrap();
+        for experience in [&doc_experience, &am92] {
+            for degree in [3, 4] {
+                let graduation = graduate()
+                    .experience(experience)
+                    .method(GraduationMethodEnum::Parametric {
+                        formula: FormulaEnum::LogitPolynomial { degree },
+                    })
+                    .call()
+                    .unwrap();
+                assert_eq!(graduation.parameters.len(), degree + 1);
+                assert!(
+                    graduation
+                        .standard_errors
+                        .iter()
+                        .all(|(_, se)| se.is_finite())
+                );
+            }
+        }
+
+        // A quartic follows the AM92 rates closely
+        let quartic = graduate()
+            .experience(&am92)
+            .method(GraduationMethodEnum::Parametric {
+                formula: FormulaEnum::LogitPolynomial { degree: 4 },
+            })
+            .call()
+            .unwrap();
+        let fitted = quartic.fitted.column("qx").unwrap().f64().unwrap();
+        let crude = quartic.fitted.column("crude_qx").unwrap().f64().unwrap();
+        for (q, crude) in fitted.into_no_null_iter().zip(crude.into_no_null_iter()) {
+            assert!((q / crude - 1.0).abs() < 0.1);
+        }
+    }
+}
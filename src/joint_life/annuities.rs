@@ -0,0 +1,386 @@
+#![allow(clippy::too_many_arguments)]
+
+use super::helpers::{Status, period_starts, prepare_tables, status_max_term, status_tpx};
+use crate::RSLifeResult;
+use crate::mt_config::MortTableConfig;
+use crate::param::JointLifeParams;
+use crate::single_life::helpers::annuity_summation;
+use crate::yield_curve::InterestEnum;
+use bon::builder;
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+//-----------------Joint life------------------
+
+/// Joint-life temporary annuity-due payable m times per year: äₓᵧ:ₙ̅⁽ᵐ⁾
+///
+/// Present value of 1/m paid m times per year for up to n years, starting after t years,
+/// provided both lives are alive at each payment time.
+///
+/// # Formula
+/// ```text
+/// ₜ|äₓᵧ:ₙ̅⁽ᵐ⁾ = Σₖ₌₀^(nm-1) [1/m · v^(t+k/m) · ₜ₊ₖ/ₘpₓᵧ]
+/// ```
+/// where:
+/// - `v = 1/(1+i)` is the discount factor
+/// - `ₜpₓᵧ = ₜpₓ · ₜpᵧ` is the probability that both lives survive t years
+/// - `t` is the deferral period (default 0)
+/// - `m` is the number of payments per year (default 1)
+/// - `moment` is the moment to calculate (default 1, i.e., mean)
+/// - `entry_age_x`, `entry_age_y` are the entry ages for select tables (default None, uses ultimate tables)
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // 10-year joint-life annuity-due paid monthly
+/// let annuity = aaxyn().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).n(10.0).m(12).call()?;
+/// println!("Joint-life temporary annuity-due: {:.6}", annuity);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn aaxyn(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    n: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_annuity_procedure(&params, validate, Status::Joint)
+}
+
+/// Joint-life annuity-due payable m times per year: äₓᵧ⁽ᵐ⁾
+///
+/// Present value of 1/m paid m times per year while both lives are alive.
+///
+/// # Formula
+/// ```text
+/// ₜ|äₓᵧ⁽ᵐ⁾ = Σₖ₌₀^∞ [1/m · v^(t+k/m) · ₜ₊ₖ/ₘpₓᵧ]
+/// ```
+///
+/// The summation runs until the shorter of the two remaining lifetimes from the tables.
+/// Refer to `aaxyn` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let annuity = aaxy().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+/// println!("Joint-life annuity-due: {:.6}", annuity);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn aaxy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = status_max_term(mt_x, mt_y, x, y, Status::Joint)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_annuity_procedure(&params, validate, Status::Joint)
+}
+
+//-----------------Last survivor------------------
+
+/// Last-survivor annuity-due payable m times per year: äₓᵧ⁽ᵐ⁾ (bar)
+///
+/// Present value of 1/m paid m times per year while at least one life is alive.
+///
+/// # Formula
+/// ```text
+/// ₜ|äₓᵧ⁽ᵐ⁾(bar) = Σₖ₌₀^∞ [1/m · v^(t+k/m) · ₜ₊ₖ/ₘpₓᵧ(bar)]
+///               = ₜ|äₓ⁽ᵐ⁾ + ₜ|äᵧ⁽ᵐ⁾ - ₜ|äₓᵧ⁽ᵐ⁾
+/// ```
+///
+/// The summation runs until the longer of the two remaining lifetimes from the tables.
+/// Refer to `aaxyn` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let annuity = aaxybar().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).m(12).call()?;
+/// println!("Last-survivor annuity-due: {:.6}", annuity);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn aaxybar(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = status_max_term(mt_x, mt_y, x, y, Status::LastSurvivor)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_annuity_procedure(&params, validate, Status::LastSurvivor)
+}
+
+//-----------------Reversionary------------------
+
+/// Reversionary annuity-due payable m times per year: äᵧ|ₓ⁽ᵐ⁾
+///
+/// Present value of 1/m paid m times per year to x, starting once y has died, for as long as x is alive.
+/// Typical use is a spouse pension where x is the surviving spouse and y the member.
+///
+/// # Formula
+/// ```text
+/// ₜ|äᵧ|ₓ⁽ᵐ⁾ = Σₖ₌₀^∞ [1/m · v^(t+k/m) · ₜ₊ₖ/ₘpₓ · (1 - ₜ₊ₖ/ₘpᵧ)]
+///           = ₜ|äₓ⁽ᵐ⁾ - ₜ|äₓᵧ⁽ᵐ⁾
+/// ```
+///
+/// The summation runs until the end of the table of x.
+/// Refer to `aaxyn` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_member = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_spouse = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // Monthly pension to a wife (62) after the death of her husband (65)
+/// let spouse_pension = aayx()
+///     .mt_x(&mt_spouse)
+///     .mt_y(&mt_member)
+///     .i(0.04)
+///     .x(62.0)
+///     .y(65.0)
+///     .m(12)
+///     .call()?;
+/// println!("Reversionary annuity-due: {:.6}", spouse_pension);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn aayx(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = status_max_term(mt_x, mt_y, x, y, Status::Reversionary)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_annuity_procedure(&params, validate, Status::Reversionary)
+}
+
+// =======================================
+// PRIVATE FUNCTIONS
+// =======================================
+
+fn status_annuity_procedure(
+    params: &JointLifeParams,
+    validate: bool,
+    status: Status,
+) -> RSLifeResult<f64> {
+    let (mt_x, mt_y) = prepare_tables(params, validate)?;
+    let JointLifeParams {
+        i, x, y, n, t, m, ..
+    } = *params;
+
+    // Payment times [0, 1/m, 2/m, ...] strictly before n
+    let k_arr = period_starts(n, m);
+
+    // The deferral is discounted together with the payment time, as the
+    // last-survivor status cannot be restarted from ages (x+t, y+t)
+    let probability = |k: f64| status_tpx(&mt_x, &mt_y, x, y, t + k, status);
+
+    // Calculate the summation, each payment is 1/m
+    let m_f = f64::from(m);
+    let summation = annuity_summation(
+        &InterestEnum::Flat(i),
+        t,
+        params.moment,
+        &k_arr,
+        probability,
+        |_| 1.0 / m_f,
+    )?;
+
+    Ok(summation)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+    use crate::single_life::annuities::aax;
+    use approx::assert_abs_diff_eq;
+
+    fn configs() -> (MortTableConfig, MortTableConfig) {
+        let pma92c20 = MortData::from_builtin("PMA92C20").unwrap();
+        let pfa92c20 = MortData::from_builtin("PFA92C20").unwrap();
+        let mt_x = MortTableConfig::builder().data(pma92c20).build().unwrap();
+        let mt_y = MortTableConfig::builder().data(pfa92c20).build().unwrap();
+        (mt_x, mt_y)
+    }
+
+    #[test]
+    fn test_fn_aaxybar_identity() {
+        // äₓᵧ(bar) = äₓ + äᵧ - äₓᵧ
+        let (mt_x, mt_y) = configs();
+        let aa_x = aax().mt(&mt_x).i(0.04).x(65.0).call().unwrap();
+        let aa_y = aax().mt(&mt_y).i(0.04).x(62.0).call().unwrap();
+        let aa_xy = aaxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .call()
+            .unwrap();
+        let aa_xy_bar = aaxybar()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(aa_xy_bar, aa_x + aa_y - aa_xy, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_fn_aaxy_deferred_split() {
+        // äₓᵧ = äₓᵧ:ₙ̅ + ₙ|äₓᵧ
+        let (mt_x, mt_y) = configs();
+        let whole = aaxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .m(12)
+            .call()
+            .unwrap();
+        let temporary = aaxyn()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(10.0)
+            .m(12)
+            .call()
+            .unwrap();
+        let deferred = aaxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .t(10.0)
+            .m(12)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(whole, temporary + deferred, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_fn_aayx_reversionary_identity() {
+        // äᵧ|ₓ = äₓ - äₓᵧ, with x the wife and y the husband
+        let (mt_member, mt_spouse) = configs();
+        let aa_x = aax().mt(&mt_spouse).i(0.04).x(62.0).m(12).call().unwrap();
+        let aa_xy = aaxy()
+            .mt_x(&mt_spouse)
+            .mt_y(&mt_member)
+            .i(0.04)
+            .x(62.0)
+            .y(65.0)
+            .m(12)
+            .call()
+            .unwrap();
+        let aa_yx = aayx()
+            .mt_x(&mt_spouse)
+            .mt_y(&mt_member)
+            .i(0.04)
+            .x(62.0)
+            .y(65.0)
+            .m(12)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(aa_yx, aa_x - aa_xy, epsilon = 1e-6);
+    }
+}
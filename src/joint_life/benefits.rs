@@ -0,0 +1,571 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use super::helpers::{Status, life_tpx, periods, prepare_tables, status_max_term, status_tpx};
+use crate::RSLifeResult;
+use crate::mt_config::MortTableConfig;
+use crate::param::JointLifeParams;
+use crate::single_life::helpers::benefit_summation;
+use crate::yield_curve::InterestEnum;
+use bon::builder;
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+//-----------------Joint life------------------
+
+/// Joint-life term insurance: Aₓᵧ:ₙ̅¹
+///
+/// Present value of $1 paid at the end of the 1/m-th period of the first death, if it occurs within n years after time t.
+///
+/// # Formula
+/// ```text
+/// ₜ|Aₓᵧ:ₙ̅¹⁽ᵐ⁾ = Σₖ₌₀^(nm-1) [v^(t+(k+1)/m) · (ₜ₊ₖ/ₘpₓᵧ - ₜ₊₍ₖ₊₁₎/ₘpₓᵧ)]
+/// ```
+/// When n is not a multiple of 1/m, the last period ends, and its claim is paid, at n.
+///
+/// where:
+/// - `v = 1/(1+i)` is the discount factor
+/// - `ₜpₓᵧ = ₜpₓ · ₜpᵧ` is the probability that both lives survive t years
+/// - `t` is the deferral period (default 0)
+/// - `m` is the number of payment periods per year (default 1)
+/// - `moment` is the moment to calculate (default 1, i.e., mean)
+/// - `entry_age_x`, `entry_age_y` are the entry ages for select tables (default None, uses ultimate tables)
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // 10-year joint-life term insurance for a husband (65) and wife (62)
+/// let term = Axy1n().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).n(10.0).call()?;
+/// println!("Joint-life term insurance: {:.6}", term);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Axy1n(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    n: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_benefit_procedure(&params, validate, Status::Joint)
+}
+
+/// Joint-life whole life insurance: Aₓᵧ
+///
+/// Present value of $1 paid at the end of the 1/m-th period of the first death.
+///
+/// # Formula
+/// ```text
+/// ₜ|Aₓᵧ⁽ᵐ⁾ = Σₖ₌₀^∞ [v^(t+(k+1)/m) · (ₜ₊ₖ/ₘpₓᵧ - ₜ₊₍ₖ₊₁₎/ₘpₓᵧ)]
+/// ```
+///
+/// The summation runs until the shorter of the two remaining lifetimes from the tables.
+/// Refer to `Axy1n` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let joint = Axy().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+/// println!("Joint-life whole life insurance: {:.6}", joint);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Axy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = status_max_term(mt_x, mt_y, x, y, Status::Joint)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_benefit_procedure(&params, validate, Status::Joint)
+}
+
+//-----------------Last survivor------------------
+
+/// Last-survivor whole life insurance: Aₓᵧ (bar)
+///
+/// Present value of $1 paid at the end of the 1/m-th period of the second death.
+///
+/// # Formula
+/// ```text
+/// ₜ|Aₓᵧ⁽ᵐ⁾(bar) = Σₖ₌₀^∞ [v^(t+(k+1)/m) · (ₜ₊ₖ/ₘpₓᵧ(bar) - ₜ₊₍ₖ₊₁₎/ₘpₓᵧ(bar))]
+///               = ₜ|Aₓ⁽ᵐ⁾ + ₜ|Aᵧ⁽ᵐ⁾ - ₜ|Aₓᵧ⁽ᵐ⁾
+/// ```
+///
+/// The summation runs until the longer of the two remaining lifetimes from the tables.
+/// Refer to `Axy1n` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let last = Axybar().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+/// println!("Last-survivor whole life insurance: {:.6}", last);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Axybar(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = status_max_term(mt_x, mt_y, x, y, Status::LastSurvivor)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    status_benefit_procedure(&params, validate, Status::LastSurvivor)
+}
+
+//-----------------Contingent------------------
+
+/// Contingent assurance on the first death: A¹ₓᵧ
+///
+/// Present value of $1 paid at the end of the 1/m-th period of the death of x, provided x dies before y.
+///
+/// # Formula
+/// ```text
+/// ₜ|A¹ₓᵧ⁽ᵐ⁾ = Σₖ₌₀^∞ [v^(t+(k+1)/m) · (ₜ₊ₖ/ₘpₓ - ₜ₊₍ₖ₊₁₎/ₘpₓ) · ½(ₜ₊ₖ/ₘpᵧ + ₜ₊₍ₖ₊₁₎/ₘpᵧ)]
+/// ```
+/// Within each 1/m period the death of x is assumed to occur half way through for the survival of y.
+///
+/// Useful identities:
+/// ```text
+/// A¹ₓᵧ + A¹ᵧₓ = Aₓᵧ
+/// A¹ₓᵧ + A²ₓᵧ = Aₓ
+/// ```
+///
+/// Refer to `Axy1n` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // Benefit on the husband's death, only if the wife is still alive
+/// let contingent = A1xy().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+/// println!("Contingent assurance A¹ₓᵧ: {:.6}", contingent);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn A1xy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    // No benefit is possible once the joint status has failed
+    let n = status_max_term(mt_x, mt_y, x, y, Status::Joint)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    contingent_benefit_procedure(&params, validate, DeathOrder::First)
+}
+
+/// Contingent assurance on the second death: A²ₓᵧ
+///
+/// Present value of $1 paid at the end of the 1/m-th period of the death of x, provided y has already died.
+///
+/// # Formula
+/// ```text
+/// ₜ|A²ₓᵧ⁽ᵐ⁾ = Σₖ₌₀^∞ [v^(t+(k+1)/m) · (ₜ₊ₖ/ₘpₓ - ₜ₊₍ₖ₊₁₎/ₘpₓ) · (1 - ½(ₜ₊ₖ/ₘpᵧ + ₜ₊₍ₖ₊₁₎/ₘpᵧ))]
+///           = ₜ|Aₓ⁽ᵐ⁾ - ₜ|A¹ₓᵧ⁽ᵐ⁾
+/// ```
+///
+/// Refer to `A1xy` for the death timing assumption and `Axy1n` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // Benefit on the husband's death, only if the wife has died before him
+/// let contingent = A2xy().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+/// println!("Contingent assurance A²ₓᵧ: {:.6}", contingent);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn A2xy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    i: f64,
+    x: f64,
+    y: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    // The benefit is paid on the death of x, so only x's lifetime limits the term
+    let n = status_max_term(mt_x, mt_y, x, y, Status::Reversionary)? - t;
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i,
+        x,
+        y,
+        n,
+        t,
+        m,
+        moment,
+        entry_age_x,
+        entry_age_y,
+    };
+    contingent_benefit_procedure(&params, validate, DeathOrder::Second)
+}
+
+// =======================================
+// PRIVATE FUNCTIONS
+// =======================================
+
+/// Order of the death of x relative to y for contingent assurances.
+#[derive(Debug, Clone, Copy)]
+enum DeathOrder {
+    First,
+    Second,
+}
+
+fn status_benefit_procedure(
+    params: &JointLifeParams,
+    validate: bool,
+    status: Status,
+) -> RSLifeResult<f64> {
+    let (mt_x, mt_y) = prepare_tables(params, validate)?;
+    let JointLifeParams { x, y, t, .. } = *params;
+
+    // ₜ₊ₖ/ₘpₓᵧ - ₜ₊₍ₖ₊₁₎/ₘpₓᵧ
+    status_benefit_summation(params, |start, end| {
+        Ok(status_tpx(&mt_x, &mt_y, x, y, t + start, status)?
+            - status_tpx(&mt_x, &mt_y, x, y, t + end, status)?)
+    })
+}
+
+fn contingent_benefit_procedure(
+    params: &JointLifeParams,
+    validate: bool,
+    order: DeathOrder,
+) -> RSLifeResult<f64> {
+    let (mt_x, mt_y) = prepare_tables(params, validate)?;
+    let JointLifeParams { x, y, t, .. } = *params;
+
+    // Death of x in the period, weighted by the state of y at mid-period
+    status_benefit_summation(params, |start, end| {
+        let death_x = life_tpx(&mt_x, x, t + start)? - life_tpx(&mt_x, x, t + end)?;
+        let alive_y = 0.5 * (life_tpx(&mt_y, y, t + start)? + life_tpx(&mt_y, y, t + end)?);
+        let result = match order {
+            DeathOrder::First => death_x * alive_y,
+            DeathOrder::Second => death_x * (1.0 - alive_y),
+        };
+        Ok(result)
+    })
+}
+
+/// Σₖ v^(t+end) · P(start, end) over the 1/m periods before n, paid at the end of the period of death.
+///
+/// `probability(start, end)` is the probability of a claim between the times `start` and `end`
+/// after the deferral; the last period ends at n.
+fn status_benefit_summation(
+    params: &JointLifeParams,
+    probability: impl Fn(f64, f64) -> RSLifeResult<f64>,
+) -> RSLifeResult<f64> {
+    let JointLifeParams {
+        i, n, t, m, moment, ..
+    } = *params;
+
+    // The deferral is discounted together with the payment time, as the
+    // last-survivor status cannot be restarted from ages (x+t, y+t)
+    benefit_summation(
+        &InterestEnum::Flat(i),
+        t,
+        moment,
+        &periods(n, m),
+        probability,
+        |_| 1.0,
+    )
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joint_life::annuities::{aaxy, aaxyn};
+    use crate::joint_life::survivals::tpxy;
+    use crate::mt_config::mt_data::MortData;
+    use crate::single_life::benefits::Ax;
+    use approx::assert_abs_diff_eq;
+
+    fn configs() -> (MortTableConfig, MortTableConfig) {
+        let pma92c20 = MortData::from_builtin("PMA92C20").unwrap();
+        let pfa92c20 = MortData::from_builtin("PFA92C20").unwrap();
+        let mt_x = MortTableConfig::builder().data(pma92c20).build().unwrap();
+        let mt_y = MortTableConfig::builder().data(pfa92c20).build().unwrap();
+        (mt_x, mt_y)
+    }
+
+    #[test]
+    fn test_fn_Axy1n_partial_last_period() {
+        // n = 10.5 annually: the last claim is paid at n = 10.5, not at 11
+        let (mt_x, mt_y) = configs();
+        let term = Axy1n()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(10.5)
+            .call()
+            .unwrap();
+        let whole_years = Axy1n()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(10.0)
+            .call()
+            .unwrap();
+        let p_10 = tpxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .x(65.0)
+            .y(62.0)
+            .t(10.0)
+            .call()
+            .unwrap();
+        let p_105 = tpxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .x(65.0)
+            .y(62.0)
+            .t(10.5)
+            .call()
+            .unwrap();
+        let expected = whole_years + 1.04f64.powf(-10.5) * (p_10 - p_105);
+        assert_abs_diff_eq!(term, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fn_Axybar_identity() {
+        // Aₓᵧ(bar) = Aₓ + Aᵧ - Aₓᵧ
+        let (mt_x, mt_y) = configs();
+        let a_x = Ax().mt(&mt_x).i(0.04).x(65.0).call().unwrap();
+        let a_y = Ax().mt(&mt_y).i(0.04).x(62.0).call().unwrap();
+        let a_xy = Axy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .call()
+            .unwrap();
+        let a_xy_bar = Axybar()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(a_xy_bar, a_x + a_y - a_xy, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_fn_Axy_premium_conversion() {
+        // Aₓᵧ = 1 - d · äₓᵧ, also at fractional ages where the term is not whole periods
+        let (mt_x, mt_y) = configs();
+        let i = 0.04;
+        let d = i / (1.0 + i);
+        for x in [65.0, 65.5] {
+            let a_xy = Axy()
+                .mt_x(&mt_x)
+                .mt_y(&mt_y)
+                .i(i)
+                .x(x)
+                .y(62.0)
+                .call()
+                .unwrap();
+            let aa_xy = aaxy()
+                .mt_x(&mt_x)
+                .mt_y(&mt_y)
+                .i(i)
+                .x(x)
+                .y(62.0)
+                .call()
+                .unwrap();
+            assert_abs_diff_eq!(a_xy, 1.0 - d * aa_xy, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_fn_Axy1n_endowment_identity() {
+        // Aₓᵧ:ₙ̅¹ + vⁿ · ₙpₓᵧ = 1 - d · äₓᵧ:ₙ̅ with a select life x
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let i = 0.04;
+        let d = i / (1.0 + i);
+        let term = Axy1n()
+            .mt_x(&mt)
+            .mt_y(&mt)
+            .i(i)
+            .x(40.0)
+            .y(45.0)
+            .n(20.0)
+            .entry_age_x(39)
+            .call()
+            .unwrap();
+        let npxy = tpxy()
+            .mt_x(&mt)
+            .mt_y(&mt)
+            .x(40.0)
+            .y(45.0)
+            .t(20.0)
+            .entry_age_x(39)
+            .call()
+            .unwrap();
+        let aa_xyn = aaxyn()
+            .mt_x(&mt)
+            .mt_y(&mt)
+            .i(i)
+            .x(40.0)
+            .y(45.0)
+            .n(20.0)
+            .entry_age_x(39)
+            .call()
+            .unwrap();
+        let endowment = term + (1.0 + i).powf(-20.0) * npxy;
+        assert_abs_diff_eq!(endowment, 1.0 - d * aa_xyn, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_fn_A1xy_contingent_identities() {
+        let (mt_x, mt_y) = configs();
+        let a1_xy = A1xy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .m(4)
+            .call()
+            .unwrap();
+        let a1_yx = A1xy()
+            .mt_x(&mt_y)
+            .mt_y(&mt_x)
+            .i(0.04)
+            .x(62.0)
+            .y(65.0)
+            .m(4)
+            .call()
+            .unwrap();
+        let a2_xy = A2xy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .m(4)
+            .call()
+            .unwrap();
+        let a_xy = Axy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .m(4)
+            .call()
+            .unwrap();
+        let a_x = Ax().mt(&mt_x).i(0.04).x(65.0).m(4).call().unwrap();
+
+        // A¹ₓᵧ + A¹ᵧₓ = Aₓᵧ
+        assert_abs_diff_eq!(a1_xy + a1_yx, a_xy, epsilon = 1e-9);
+        // A¹ₓᵧ + A²ₓᵧ = Aₓ
+        assert_abs_diff_eq!(a1_xy + a2_xy, a_x, epsilon = 1e-6);
+    }
+}
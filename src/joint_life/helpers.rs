@@ -0,0 +1,116 @@
+//! Shared helpers for joint_life submodules.
+//!
+//! Provides the status definition and survival helpers used by `survivals`,
+//! `annuities`, and `benefits`.
+
+use crate::RSLifeResult;
+use crate::mt_config::MortTableConfig;
+use crate::param::JointLifeParams;
+use crate::single_life::helpers::get_new_config_with_selected_table;
+use crate::single_life::survivals::tpx;
+
+// ================================================
+// PUBLIC (within joint_life) TYPES AND FUNCTIONS
+// ================================================
+
+/// Status of a pair of lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Status {
+    /// Joint-life status: fails on the first death.
+    Joint,
+
+    /// Last-survivor status: fails on the second death.
+    LastSurvivor,
+
+    /// Reversionary status y|x: life x is alive after life y has died.
+    Reversionary,
+}
+
+/// Validate `params` if requested, then get the selected or ultimate tables of both lives.
+///
+/// Shared set-up of the survival, annuity and benefit procedures.
+pub(super) fn prepare_tables(
+    params: &JointLifeParams,
+    validate: bool,
+) -> RSLifeResult<(MortTableConfig, MortTableConfig)> {
+    if validate {
+        params.validate_all()?;
+    }
+
+    // Decide if selected tables are used
+    let mt_x = get_new_config_with_selected_table(&params.mt_x, params.entry_age_x)?;
+    let mt_y = get_new_config_with_selected_table(&params.mt_y, params.entry_age_y)?;
+    Ok((mt_x, mt_y))
+}
+
+/// Start times [0, 1/m, 2/m, ...] of the 1/m periods beginning strictly before n.
+///
+/// Annuity payments fall at these times; benefit periods start at them and end at n at the latest.
+pub(super) fn period_starts(n: f64, m: u32) -> Vec<f64> {
+    let m = f64::from(m);
+    let total_periods = (n * m).ceil().max(0.0) as u32;
+    (0..total_periods).map(|k| f64::from(k) / m).collect()
+}
+
+/// Benefit periods `(start, end)` of length 1/m, the last one ending at n.
+pub(super) fn periods(n: f64, m: u32) -> Vec<(f64, f64)> {
+    let length = 1.0 / f64::from(m);
+    period_starts(n, m)
+        .into_iter()
+        .map(|start| (start, f64::min(start + length, n)))
+        .collect()
+}
+
+/// Survival probability of the status over t years from ages (x, y).
+///
+/// Assumes the tables have already been selected and the lives are independent:
+/// ```text
+/// ₜpₓᵧ = ₜpₓ · ₜpᵧ
+/// ₜpₓᵧ(bar) = ₜpₓ + ₜpᵧ - ₜpₓ · ₜpᵧ
+/// ₜpᵧ|ₓ = ₜpₓ · (1 - ₜpᵧ)
+/// ```
+/// A survival time beyond the end of a table counts as death of that life.
+pub(super) fn status_tpx(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    t: f64,
+    status: Status,
+) -> RSLifeResult<f64> {
+    let tpx_x = life_tpx(mt_x, x, t)?;
+    let tpx_y = life_tpx(mt_y, y, t)?;
+
+    let result = match status {
+        Status::Joint => tpx_x * tpx_y,
+        Status::LastSurvivor => tpx_x + tpx_y - tpx_x * tpx_y,
+        Status::Reversionary => tpx_x * (1.0 - tpx_y),
+    };
+    Ok(result)
+}
+
+/// Remaining term until the status has certainly failed, measured from ages (x, y).
+pub(super) fn status_max_term(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    status: Status,
+) -> RSLifeResult<f64> {
+    let term_x = mt_x.max_age()? as f64 - x;
+    let term_y = mt_y.max_age()? as f64 - y;
+    let result = match status {
+        Status::Joint => f64::min(term_x, term_y),
+        Status::LastSurvivor => f64::max(term_x, term_y),
+        Status::Reversionary => term_x,
+    };
+    Ok(result)
+}
+
+/// ₜpₓ for a single life, 0 once x + t runs beyond the end of its table.
+pub(super) fn life_tpx(mt: &MortTableConfig, x: f64, t: f64) -> RSLifeResult<f64> {
+    if x + t > mt.max_age()? as f64 {
+        return Ok(0.0);
+    }
+    tpx().mt(mt).x(x).t(t).validate(false).call()
+}
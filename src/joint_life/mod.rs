@@ -0,0 +1,45 @@
+//! # Joint Life Actuarial Functions
+//!
+//! This module provides actuarial calculations for contracts written on two lives, including:
+//!
+//! - Joint-life status (x y), which fails on the first death
+//! - Last-survivor status (x y with a bar), which fails on the second death
+//! - Present values of assurances and annuities on both statuses
+//! - Contingent assurances (order of death) and reversionary annuities
+//!
+//! Each life has its own mortality table configuration, age and optional entry age,
+//! so male/female tables (e.g. PMA92C20 and PFA92C20) and select tables can be mixed.
+//! The two lives are assumed to be independent.
+//!
+//! ## Submodules
+//! - [`annuities`] — Present value of joint-life, last-survivor and reversionary annuities
+//! - [`benefits`] — Present value of joint-life, last-survivor and contingent assurances
+//! - [`survivals`] — Survival probabilities of joint-life and last-survivor statuses
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! let mt_x = MortTableConfig::builder()
+//!     .data(MortData::from_builtin("PMA92C20")?)
+//!     .build()?;
+//! let mt_y = MortTableConfig::builder()
+//!     .data(MortData::from_builtin("PFA92C20")?)
+//!     .build()?;
+//!
+//! // Joint-life annuity-due äₓᵧ for a husband aged 65 and a wife aged 62
+//! let aa_xy = aaxy().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+//!
+//! // Last-survivor annuity-due äₓᵧ (bar)
+//! let aa_xy_bar = aaxybar().mt_x(&mt_x).mt_y(&mt_y).i(0.04).x(65.0).y(62.0).call()?;
+//!
+//! // Probability both lives survive 10 years
+//! let p_xy = tpxy().mt_x(&mt_x).mt_y(&mt_y).x(65.0).y(62.0).t(10.0).call()?;
+//!
+//! # RSLifeResult::Ok(())
+//! ```
+
+// Module structure
+pub mod annuities;
+pub mod benefits;
+mod helpers;
+pub mod survivals;
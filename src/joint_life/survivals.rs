@@ -0,0 +1,337 @@
+use super::helpers::{Status, prepare_tables, status_tpx};
+use crate::RSLifeResult;
+use crate::mt_config::MortTableConfig;
+use crate::param::JointLifeParams;
+use bon::builder;
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+/// Joint-life survival probability: ₜpₓᵧ (probability that both lives survive t years)
+///
+/// Computes the probability that a life aged `x` and a life aged `y` are both alive after `t` years.
+/// Each life uses its own mortality table and optional entry age. The lives are assumed independent.
+///
+/// # Formula
+/// ```text
+/// ₜpₓᵧ = ₜpₓ · ₜpᵧ
+/// ₖ|ₜpₓᵧ = ₖ₊ₜpₓᵧ
+/// ```
+///
+/// Refer to `tpx` for fractional age details as this function is based on it.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // Probability that husband (65) and wife (62) both survive 10 years
+/// let prob = tpxy().mt_x(&mt_x).mt_y(&mt_y).x(65.0).y(62.0).t(10.0).call()?;
+/// println!("10-year joint survival probability: {:.6}", prob);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn tpxy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    #[builder(default = 1.0)] t: f64,
+    #[builder(default = 0.0)] k: f64,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        t,
+        k,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::Joint,
+    )
+}
+
+/// Joint-life mortality probability: ₜqₓᵧ (probability that the first death occurs within t years)
+///
+/// # Formula
+/// ```text
+/// ₜqₓᵧ = 1 - ₜpₓᵧ
+/// ₖ|ₜqₓᵧ = ₖpₓᵧ - ₖ₊ₜpₓᵧ
+/// ```
+///
+/// Refer to `tpxy` for more details as this function is based on it.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// // Probability that the first death occurs between years 5 and 10
+/// let prob = tqxy().mt_x(&mt_x).mt_y(&mt_y).x(65.0).y(62.0).t(5.0).k(5.0).call()?;
+/// println!("Deferred joint mortality probability: {:.6}", prob);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn tqxy(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    #[builder(default = 1.0)] t: f64,
+    #[builder(default = 0.0)] k: f64,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let kpxy = status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        k,
+        0.0,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::Joint,
+    )?;
+
+    let ktpxy = status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        t,
+        k,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::Joint,
+    )?;
+
+    // ✅ ₖ|ₜqₓᵧ = ₖpₓᵧ - ₖ|ₜpₓᵧ
+    Ok(kpxy - ktpxy)
+}
+
+/// Last-survivor survival probability: ₜpₓᵧ (bar) (probability that at least one life survives t years)
+///
+/// # Formula
+/// ```text
+/// ₜpₓᵧ(bar) = ₜpₓ + ₜpᵧ - ₜpₓᵧ
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let prob = tpxybar().mt_x(&mt_x).mt_y(&mt_y).x(65.0).y(62.0).t(20.0).call()?;
+/// println!("20-year last-survivor probability: {:.6}", prob);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn tpxybar(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    #[builder(default = 1.0)] t: f64,
+    #[builder(default = 0.0)] k: f64,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        t,
+        k,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::LastSurvivor,
+    )
+}
+
+/// Last-survivor mortality probability: ₜqₓᵧ (bar) (probability that both lives die within t years)
+///
+/// # Formula
+/// ```text
+/// ₜqₓᵧ(bar) = ₜqₓ · ₜqᵧ
+/// ₖ|ₜqₓᵧ(bar) = ₖpₓᵧ(bar) - ₖ₊ₜpₓᵧ(bar)
+/// ```
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mt_x = MortTableConfig::builder().data(MortData::from_builtin("PMA92C20")?).build()?;
+/// # let mt_y = MortTableConfig::builder().data(MortData::from_builtin("PFA92C20")?).build()?;
+/// let prob = tqxybar().mt_x(&mt_x).mt_y(&mt_y).x(65.0).y(62.0).t(20.0).call()?;
+/// println!("Probability both die within 20 years: {:.6}", prob);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn tqxybar(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    #[builder(default = 1.0)] t: f64,
+    #[builder(default = 0.0)] k: f64,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let kpxy = status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        k,
+        0.0,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::LastSurvivor,
+    )?;
+
+    let ktpxy = status_survival_procedure(
+        mt_x,
+        mt_y,
+        x,
+        y,
+        t,
+        k,
+        entry_age_x,
+        entry_age_y,
+        validate,
+        Status::LastSurvivor,
+    )?;
+
+    Ok(kpxy - ktpxy)
+}
+
+// =======================================
+// PRIVATE FUNCTIONS
+// =======================================
+
+#[allow(clippy::too_many_arguments)]
+fn status_survival_procedure(
+    mt_x: &MortTableConfig,
+    mt_y: &MortTableConfig,
+    x: f64,
+    y: f64,
+    t: f64,
+    k: f64,
+    entry_age_x: Option<u32>,
+    entry_age_y: Option<u32>,
+    validate: bool,
+    status: Status,
+) -> RSLifeResult<f64> {
+    let params = JointLifeParams {
+        mt_x: mt_x.clone(),
+        mt_y: mt_y.clone(),
+        i: 0.0, // Not used
+        x,
+        y,
+        n: t,
+        t: k,
+        m: 1,      // Not used
+        moment: 1, // Not used
+        entry_age_x,
+        entry_age_y,
+    };
+
+    let (mt_x, mt_y) = prepare_tables(&params, validate)?;
+
+    // ₖ|ₜpₓᵧ = ₖ₊ₜpₓᵧ
+    status_tpx(&mt_x, &mt_y, x, y, t + k, status)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+    use crate::single_life::survivals::{tpx, tqx};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_tpxy_product_of_single_lives() {
+        let mt_x = MortTableConfig::builder()
+            .data(MortData::from_builtin("PMA92C20").unwrap())
+            .build()
+            .unwrap();
+        let mt_y = MortTableConfig::builder()
+            .data(MortData::from_builtin("PFA92C20").unwrap())
+            .build()
+            .unwrap();
+
+        let p_x = tpx().mt(&mt_x).x(65.5).t(10.0).call().unwrap();
+        let p_y = tpx().mt(&mt_y).x(62.0).t(10.0).call().unwrap();
+
+        let joint = tpxy()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .x(65.5)
+            .y(62.0)
+            .t(10.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(joint, p_x * p_y, epsilon = 1e-12);
+
+        let last = tpxybar()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .x(65.5)
+            .y(62.0)
+            .t(10.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(last, p_x + p_y - p_x * p_y, epsilon = 1e-12);
+
+        // ₜqₓᵧ(bar) = ₜqₓ · ₜqᵧ
+        let both_dead = tqxybar()
+            .mt_x(&mt_x)
+            .mt_y(&mt_y)
+            .x(65.5)
+            .y(62.0)
+            .t(10.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(both_dead, (1.0 - p_x) * (1.0 - p_y), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_tqxy_select_entry_ages() {
+        // Each life keeps its own select period
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+
+        let q_x = tqx().mt(&mt).x(41.0).t(3.0).entry_age(40).call().unwrap();
+        let q_y = tqx().mt(&mt).x(45.0).t(3.0).call().unwrap();
+
+        let ans = tqxy()
+            .mt_x(&mt)
+            .mt_y(&mt)
+            .x(41.0)
+            .y(45.0)
+            .t(3.0)
+            .entry_age_x(40)
+            .call()
+            .unwrap();
+        let expected = 1.0 - (1.0 - q_x) * (1.0 - q_y);
+        assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+    }
+}
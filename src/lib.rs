@@ -4,12 +4,21 @@
 //!
 //! ## Features
 //! - **Fast & Safe**: Optimized for speed and reliability
-//! - **Flexible Data**: Load directly from SOA or IFOA mortality and morbidity database, Speadsheets (ODS/XLSX), or DataFrames
+//! - **Flexible Data**: Load directly from SOA or IFOA mortality and morbidity database, Speadsheets (ODS/XLSX), CSV/Parquet files, or DataFrames
+//! - **Export**: Write tables back out as SOA XTbML, ODS/XLSX, CSV or Parquet
 //! - **Comprehensive Coverage**: Life insurance, annuities, survival functions,commutatons and more
 //! - **Fractional Ages**: Supports both integer and fractional ages/durations
 //! - **Multiple Assumptions**: Uniform Distribution of  Death (UDD), Constant Force of Mortality (CFM), Hyperbolic (HPB) for fractional age calculations
 //! - **Builder Pattern**: All functions use builder pattern with automatic parameter validation
 //!
+//! ## Cargo Features
+//! - `blocking` (default): blocking HTTP downloads for the SOA, IFOA and AGA URL loaders
+//! - `async`: non-blocking `*_async` loaders (`MortData::from_soa_url_async`, ...) for tokio applications
+//! - `rayon`: parallel batch evaluation
+//!
+//! Disable default features to drop the HTTP client entirely; builtin tables, local files and
+//! mirrored downloads keep working.
+//!
 //! ## Quick Start
 //!
 //! ```rust
@@ -52,9 +61,21 @@
 //! - **Life Insurance**: `Ax`, `Ax1n`, `Axn`, `Exn`
 //! - **Increasing/Decreasing/Geometric Insurance**: `IAx`, `IAx1n`, `IAxn`, `DAx1n`, `DAxn`, `gAx`, `gAx1n`, `gExn`, `gAxn`
 //! - **Annuities**: `aax`, `aaxn`, `Iaax`, `Iaaxn`, `Daaxn`, `gaax`, `gaaxn`
-//! - **Survival Functions**: `tpx`, `tqx` (fractional ages supported)
+//! - **Continuous Insurance & Annuities**: `Abarx`, `Abarx1n`, `abarx`, `abarxn` (integration or claims acceleration)
+//! - **Premiums**: `Px`, `Px1n`, `Pxn`, `gross_premium`
+//! - **Batch Evaluation**: `batch`, `batch_df` over slices or a DataFrame of policies (parallel with the `rayon` feature)
+//! - **Policy Values**: `tVx` (prospective, retrospective, recursive), `DSAR`, `EDS`, `ADS`
+//! - **Survival Functions**: `tpx`, `tqx`, `mux` (fractional ages supported, exact μₓ for parametric laws)
+//! - **Graduation**: `graduate` crude experience rates by Whittaker–Henderson, reference to a standard table or parametric formula
+//! - **Law Fitting**: `fit_law` estimates Gompertz, Makeham and Weibull parameters by Poisson or binomial maximum likelihood or weighted least squares
+//! - **Tests of Graduation**: `graduation_tests` (chi-square, standardised deviations, signs, cumulative deviations, grouping of signs, serial correlation) and `actual_vs_expected` by age band and duration
+//! - **Multiple Decrement Tables**: `MultiDecrementTable` with `al`, `ad`, `aq` and UDD/CFM rate conversions
+//! - **Expectation of Life**: `ex`, `exn` (curtate), `ebarx`, `ebarxn` (complete)
 //! - **Commutation Functions**: `Cx`,`Dx`,`Mx`,`Nx`,`Sx`,`Rx`
+//! - **Joint Life**: `tpxy`, `tqxy`, `tpxybar`, `tqxybar`, `Axy`, `Axy1n`, `Axybar`, `aaxy`, `aaxyn`, `aaxybar`
+//! - **Contingent & Reversionary**: `A1xy`, `A2xy`, `aayx`
 //! - **Annuities Certain**: `an`, `aan`
+//! - **Yield Curves**: `YieldCurve` (linear spot, flat-forward, Smith-Wilson) passed to `.i()` instead of a flat rate
 //! - **Interest Rate Conversions**: between nominial/effective interest rates and discount factors
 //!
 //!
@@ -64,11 +85,18 @@
 //! - Survival functions accept `f64` for age and time parameters to fractional value calculation
 //! - All calculations include automatic parameter validation
 
-pub type RSLifeResult<T> = Result<T, Box<dyn std::error::Error>>;
 pub mod annuities_certain;
+pub mod error;
+pub mod graduation;
 pub mod int_rate_convert;
+pub mod joint_life;
+pub(crate) mod linalg;
 pub mod macros;
 pub mod mt_config;
 pub mod param;
 pub mod prelude;
+pub(crate) mod quadrature;
 pub mod single_life;
+pub mod yield_curve;
+
+pub use error::{RSLifeError, RSLifeResult};
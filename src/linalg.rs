@@ -0,0 +1,75 @@
+//! Dense linear algebra for the small systems of graduation, law fitting and Smith-Wilson curves.
+
+/// Solve `a · x = b` by Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if `a` is singular.
+pub(crate) fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        // Pivot on the largest remaining entry of the column
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            if factor == 0.0 {
+                continue;
+            }
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+            b[col + 1 + offset] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Inverse of the square matrix `a`, or `None` if singular.
+pub(crate) fn invert(a: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = a.len();
+    let mut columns = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut unit = vec![0.0; n];
+        unit[i] = 1.0;
+        columns.push(solve(a.to_vec(), unit)?);
+    }
+    // Transpose the solved columns into rows
+    let result = (0..n)
+        .map(|row| columns.iter().map(|column| column[row]).collect())
+        .collect();
+    Some(result)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_solve_and_invert() {
+        let a = vec![vec![0.0, 2.0], vec![3.0, 1.0]];
+        let x = solve(a.clone(), vec![4.0, 5.0]).unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-15 && (x[1] - 2.0).abs() < 1e-15);
+
+        let inverse = invert(&a).unwrap();
+        assert!((inverse[0][0] + 1.0 / 6.0).abs() < 1e-15);
+        assert!((inverse[0][1] - 1.0 / 3.0).abs() < 1e-15);
+
+        assert!(solve(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0]).is_none());
+    }
+}
@@ -1,10 +1,16 @@
-use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+#[cfg(feature = "async")]
+use crate::mt_config::fetch::AsyncFetcher;
+use crate::mt_config::fetch::Fetcher;
 use crate::mt_config::spreadsheet_helpers::{parse_excel_data, parse_excel_headers};
+use crate::{RSLifeError, RSLifeResult};
 use calamine::{Data, Reader, Xlsx};
 use polars::prelude::*;
-use reqwest::blocking::get;
 use std::io::Cursor;
 
+/// Workbook of historical mortality rates published by the Australian Government Actuary.
+pub(crate) const AGA_URL: &str = "https://aga.gov.au/sites/aga.gov.au/files/2024-12/historical-mortality-rates-life-expectancies_0.xlsx";
+
 pub struct AusGovActMortXLS {
     pub description: String,
     pub dataframe: DataFrame,
@@ -16,31 +22,49 @@ impl AusGovActMortXLS {
     /// This method downloads the XLS file from the given URL, extracts the sheet name from the URL, and parses the data.
     ///
     /// # Parameters
-    /// - `url`: Direct URL to the XLS file on the IFOA website.
+    /// - `gender`: Gender of the table ("M"/"Male" or "F"/"Female").
+    /// - `period`: Period column of the workbook (e.g., "2015-17").
+    /// - `fetcher`: Source of the downloaded workbook.
     ///
     /// # Errors
     /// - Network errors or invalid URL
     /// - Sheet not found in workbook
     /// - Invalid data or unsupported structure
-    pub fn from_url(gender: &str, period: &str) -> RSLifeResult<Self> {
-        let response = get(
-            "https://aga.gov.au/sites/aga.gov.au/files/2024-12/historical-mortality-rates-life-expectancies_0.xlsx",
-        )?;
+    pub fn from_url(gender: &str, period: &str, fetcher: &dyn Fetcher) -> RSLifeResult<Self> {
+        let sheet_name = sheet_name_from_gender(gender)?;
+        let bytes = fetcher.fetch(AGA_URL)?;
+        Self::from_xlsx_bytes(bytes, sheet_name, gender, period)
+    }
 
-        let bytes = response.bytes()?;
-        let mut workbook = Xlsx::new(Cursor::new(bytes))?;
+    /// Load an Australian mortality table without blocking, downloading through `fetcher`.
+    ///
+    /// Refer to `from_url()` for details.
+    #[cfg(feature = "async")]
+    pub async fn from_url_async(
+        gender: &str,
+        period: &str,
+        fetcher: &dyn AsyncFetcher,
+    ) -> RSLifeResult<Self> {
+        let sheet_name = sheet_name_from_gender(gender)?;
+        let bytes = fetcher.fetch_async(AGA_URL).await?;
+        Self::from_xlsx_bytes(bytes, sheet_name, gender, period)
+    }
 
-        // Depend on gender input to determine which sheet to parse
-        let sheet_name = match gender {
-            "M" | "m" | "Male" | "male" => "Historical Male qx",
-            "F" | "f" | "Female" | "female" => "Historical Female qx",
-            _ => return Err(format!("Unknown gender: {}", gender).into()),
-        };
+    /// Parse the downloaded AGA workbook, shared by the blocking and async loaders.
+    fn from_xlsx_bytes(
+        bytes: Vec<u8>,
+        sheet_name: &str,
+        gender: &str,
+        period: &str,
+    ) -> RSLifeResult<Self> {
+        let mut workbook = Xlsx::new(Cursor::new(bytes))?;
 
         // Check if the expected sheet is present
         let sheet_names = workbook.sheet_names().to_owned();
         if !sheet_names.iter().any(|n| n == sheet_name) {
-            return Err(format!("Sheet '{sheet_name}' not found in workbook").into());
+            return Err(RSLifeError::Parse(format!(
+                "Sheet '{sheet_name}' not found in workbook"
+            )));
         }
 
         // Obtain the sheet range
@@ -79,6 +103,18 @@ impl AusGovActMortXLS {
 // PRIVATE FUNCTIONS
 // ================================================
 
+/// Sheet of the AGA workbook holding the rates of `gender`, checked before downloading.
+fn sheet_name_from_gender(gender: &str) -> RSLifeResult<&'static str> {
+    match gender {
+        "M" | "m" | "Male" | "male" => Ok("Historical Male qx"),
+        "F" | "f" | "Female" | "female" => Ok("Historical Female qx"),
+        _ => Err(invalid_parameter(
+            "gender",
+            format!("Unknown gender: {gender}"),
+        )),
+    }
+}
+
 fn parse_data(range: &calamine::Range<Data>, period: &str) -> RSLifeResult<Vec<Vec<f64>>> {
     let headers = parse_excel_headers(range, 1)?; // Header row is row 2 (0-based index 1)
 
@@ -86,7 +122,7 @@ fn parse_data(range: &calamine::Range<Data>, period: &str) -> RSLifeResult<Vec<V
     let period_col_index = headers
         .iter()
         .position(|h| h.trim() == period)
-        .ok_or_else(|| format!("Period '{period}' not found in headers"))?;
+        .ok_or_else(|| RSLifeError::Parse(format!("Period '{period}' not found in headers")))?;
 
     // This will contain data age column to column of interest
     let data = parse_excel_data(range, 2, period_col_index + 1)?; // Age from row 3 to 121 (0-based index 2 to 120), column 0
@@ -109,15 +145,16 @@ fn parse_data(range: &calamine::Range<Data>, period: &str) -> RSLifeResult<Vec<V
 // UNIT TESTS
 // ================================================
 
-#[cfg(test)]
+#[cfg(all(test, feature = "blocking"))]
 mod tests {
     use super::*;
+    use crate::mt_config::fetch::default_fetcher;
 
     #[test]
     fn test_from_url_male_with_available_period() {
         // Load the file to get an available period first
-        let response = get("https://aga.gov.au/sites/aga.gov.au/files/2024-12/historical-mortality-rates-life-expectancies_0.xlsx").unwrap();
-        let bytes = response.bytes().unwrap();
+        let fetcher = default_fetcher();
+        let bytes = fetcher.fetch(AGA_URL).unwrap();
         let mut workbook = Xlsx::new(Cursor::new(bytes)).unwrap();
 
         let range = workbook.worksheet_range("Historical Male qx").unwrap();
@@ -127,7 +164,7 @@ mod tests {
         let test_period = &headers[1]; // Skip age column (index 0)
 
         // Test the from_url method with Male gender and an available period
-        let result = AusGovActMortXLS::from_url("Male", test_period);
+        let result = AusGovActMortXLS::from_url("Male", test_period, fetcher.as_ref());
 
         assert!(result.is_ok(), "Loading AGA mortality data should succeed");
 
@@ -0,0 +1,486 @@
+//! # Table Export
+//!
+//! Write a [`MortData`] table back out as SOA XTbML, XLSX, ODS, CSV or Parquet, so that
+//! adjusted or fitted tables can be shared and read back by the matching loaders.
+//!
+//! Ultimate tables are written as `age` and `qx` (or `lx`) columns. Select tables are
+//! written to files in the pivoted layout of the IFOA workbooks, one column per duration
+//! with the ultimate rates last, and to XTbML as a select table and an ultimate table.
+//!
+//! ```rust, ignore
+//! # use rslife::prelude::*;
+//! let mort_data = MortData::from_builtin("AM92")?;
+//!
+//! mort_data.to_xlsx("am92.xlsx", "AM92")?;
+//! let read_back = MortData::from_xlsx("am92.xlsx", "AM92")?;
+//!
+//! std::fs::write("am92.xml", mort_data.to_soa_xml()?)?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::layout::pivot;
+use super::mt_data::MortData;
+use crate::{RSLifeError, RSLifeResult};
+use polars::prelude::*;
+use rust_xlsxwriter::Workbook;
+use spreadsheet_ods::{Sheet, WorkBook, write_ods};
+use std::fmt::Write;
+use std::fs::File;
+
+/// Layout keywords recognised by the SOA loaders, replaced by the layout of the written table.
+const LAYOUT_KEY_WORDS: [&str; 4] = ["Aggregate", "Ultimate", "Select", "Select & Ultimate"];
+
+impl MortData {
+    /// Table as written to files.
+    ///
+    /// `age` and `qx` (or `lx`) for ultimate tables. Select tables are pivoted to `age`
+    /// followed by one `qx` column per duration ("Duration 0", ..., "Durations n+").
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    pub fn to_export_frame(&self) -> RSLifeResult<DataFrame> {
+        let df = &self.dataframe;
+        if has_column(df, "duration") {
+            if !has_column(df, "qx") {
+                return Err(RSLifeError::Schema(
+                    "Only select tables of qx can be exported".into(),
+                ));
+            }
+            return pivot(df);
+        }
+
+        let value_column = value_column(df);
+        let result = df.select(["age", value_column])?;
+        Ok(result)
+    }
+
+    /// Serialise the table as a SOA XTbML document, readable by `from_soa_xml_string()`.
+    ///
+    /// Ultimate tables are written as one aggregate table. Select tables are written as a
+    /// select table by issue age and duration (1-based) followed by the ultimate table.
+    /// `lx` tables are converted to `qx`.
+    ///
+    /// Classification is taken from `metadata`: the table identity when numeric, provider,
+    /// nation, content type (default "Insured Lives Mortality") and keywords.
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    /// - Invalid data types in the table
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// let xml = MortData::from_builtin("ELT15_F")?.to_soa_xml()?;
+    /// let read_back = MortData::from_soa_xml_string(&xml)?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn to_soa_xml(&self) -> RSLifeResult<String> {
+        let df = &self.dataframe;
+        let is_select = has_column(df, "duration")
+            && df.column("duration")?.n_unique()? > 1
+            && has_column(df, "qx");
+        if has_column(df, "duration") && !has_column(df, "qx") {
+            return Err(RSLifeError::Schema(
+                "Only select tables of qx can be exported".into(),
+            ));
+        }
+
+        let ages = column_u32(df, "age")?;
+        let rates = rates(df)?;
+        let metadata = &self.metadata;
+
+        // Content classification
+        let layout = if is_select {
+            "Select & Ultimate"
+        } else {
+            "Aggregate"
+        };
+        let identity = metadata
+            .table_identity
+            .as_deref()
+            .and_then(|id| id.trim().parse::<i32>().ok())
+            .unwrap_or(0);
+        let content_type = metadata
+            .content_type
+            .as_deref()
+            .unwrap_or("Insured Lives Mortality");
+        let provider = metadata.provider.as_deref().unwrap_or("");
+        let nation = metadata.nation.as_deref().unwrap_or("");
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<XTbML>\n");
+        xml.push_str("  <ContentClassification>\n");
+        push_element(&mut xml, 4, "TableIdentity", &identity.to_string());
+        push_element(&mut xml, 4, "ProviderName", provider);
+        if let Some(location) = &metadata.location {
+            push_element(&mut xml, 4, "TableReference", location);
+        }
+        push_element(&mut xml, 4, "ContentType", content_type);
+        push_element(&mut xml, 4, "TableName", &self.description);
+        push_element(&mut xml, 4, "TableDescription", &self.description);
+        push_element(&mut xml, 4, "KeyWord", layout);
+        for key_word in &metadata.key_words {
+            if !LAYOUT_KEY_WORDS.contains(&key_word.as_str()) {
+                push_element(&mut xml, 4, "KeyWord", key_word);
+            }
+        }
+        xml.push_str("  </ContentClassification>\n");
+
+        if is_select {
+            let durations = column_u32(df, "duration")?;
+            let ultimate = durations.iter().copied().max().unwrap_or(0);
+
+            // Select rates by issue age, with durations counted from 1
+            let mut select: Vec<(u32, u32, f64)> = Vec::new();
+            let mut ultimate_rates: Vec<(u32, f64)> = Vec::new();
+            for ((&age, &duration), &rate) in ages.iter().zip(&durations).zip(&rates) {
+                if rate.is_nan() {
+                    continue;
+                }
+                if duration == ultimate {
+                    ultimate_rates.push((age, rate));
+                } else if age >= duration {
+                    select.push((age - duration, duration + 1, rate));
+                }
+            }
+            select.sort_by_key(|(issue_age, duration, _)| (*issue_age, *duration));
+            ultimate_rates.sort_by_key(|(age, _)| *age);
+
+            push_select_table(&mut xml, &select, nation);
+            push_ultimate_table(&mut xml, &ultimate_rates, nation, "Ultimate rates");
+        } else {
+            let mut ultimate_rates: Vec<(u32, f64)> = ages
+                .iter()
+                .copied()
+                .zip(rates)
+                .filter(|(_, rate)| !rate.is_nan())
+                .collect();
+            ultimate_rates.sort_by_key(|(age, _)| *age);
+            push_ultimate_table(&mut xml, &ultimate_rates, nation, &self.description);
+        }
+
+        xml.push_str("</XTbML>\n");
+        Ok(xml)
+    }
+
+    /// Write the table to sheet `sheet_name` of a new XLSX workbook, readable by `from_xlsx()`.
+    ///
+    /// Refer to `to_export_frame()` for the layout. Missing select rates are left empty.
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    /// - Invalid sheet name
+    /// - File not writable
+    pub fn to_xlsx(&self, xlsx_file_path_str: &str, sheet_name: &str) -> RSLifeResult<()> {
+        let (headers, rows) = export_cells(&self.to_export_frame()?)?;
+        let xlsx_error = |e: rust_xlsxwriter::XlsxError| {
+            RSLifeError::Other(format!(
+                "Failed to write XLSX file '{xlsx_file_path_str}': {e}"
+            ))
+        };
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(sheet_name).map_err(xlsx_error)?;
+        for (col, header) in headers.iter().enumerate() {
+            worksheet
+                .write_string(0, col as u16, header.as_str())
+                .map_err(xlsx_error)?;
+        }
+        for (row, values) in rows.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                if let Some(value) = value {
+                    worksheet
+                        .write_number(row as u32 + 1, col as u16, *value)
+                        .map_err(xlsx_error)?;
+                }
+            }
+        }
+        workbook.save(xlsx_file_path_str).map_err(xlsx_error)?;
+        Ok(())
+    }
+
+    /// Write the table to sheet `sheet_name` of a new ODS workbook, readable by `from_ods()`.
+    ///
+    /// Refer to `to_export_frame()` for the layout. Missing select rates are left empty.
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    /// - File not writable
+    pub fn to_ods(&self, ods_file_path_str: &str, sheet_name: &str) -> RSLifeResult<()> {
+        let (headers, rows) = export_cells(&self.to_export_frame()?)?;
+
+        let mut sheet = Sheet::new(sheet_name);
+        for (col, header) in headers.iter().enumerate() {
+            sheet.set_value(0, col as u32, header.as_str());
+        }
+        for (row, values) in rows.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                if let Some(value) = value {
+                    sheet.set_value(row as u32 + 1, col as u32, *value);
+                }
+            }
+        }
+
+        let mut workbook = WorkBook::default();
+        workbook.push_sheet(sheet);
+        write_ods(&mut workbook, ods_file_path_str).map_err(|e| {
+            RSLifeError::Other(format!(
+                "Failed to write ODS file '{ods_file_path_str}': {e}"
+            ))
+        })?;
+        Ok(())
+    }
+
+    /// Write the table to a CSV file with a header row.
+    ///
+    /// Refer to `to_export_frame()` for the layout. Missing select rates are left empty.
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    /// - File not writable
+    pub fn to_csv(&self, csv_file_path_str: &str) -> RSLifeResult<()> {
+        let mut df = self.to_export_frame()?;
+        let mut file = File::create(csv_file_path_str)?;
+        CsvWriter::new(&mut file)
+            .include_header(true)
+            .finish(&mut df)?;
+        Ok(())
+    }
+
+    /// Write the table to a Parquet file.
+    ///
+    /// Refer to `to_export_frame()` for the layout. Missing select rates are null.
+    ///
+    /// # Errors
+    /// - Select table without `qx` column
+    /// - File not writable
+    pub fn to_parquet(&self, parquet_file_path_str: &str) -> RSLifeResult<()> {
+        let mut df = self.to_export_frame()?;
+        let file = File::create(parquet_file_path_str)?;
+        ParquetWriter::new(file).finish(&mut df)?;
+        Ok(())
+    }
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+fn has_column(df: &DataFrame, name: &str) -> bool {
+    df.get_column_names().iter().any(|n| n.as_str() == name)
+}
+
+/// Rate column of a table: `qx`, or `lx` for life tables.
+fn value_column(df: &DataFrame) -> &'static str {
+    if has_column(df, "qx") { "qx" } else { "lx" }
+}
+
+fn column_u32(df: &DataFrame, name: &str) -> RSLifeResult<Vec<u32>> {
+    let column = df.column(name)?.cast(&DataType::UInt32)?;
+    let result = column.u32()?.into_no_null_iter().collect();
+    Ok(result)
+}
+
+/// Mortality rates of a table, converting `lx` to `qx = 1 - lx+1 / lx` (1 at the last age).
+fn rates(df: &DataFrame) -> RSLifeResult<Vec<f64>> {
+    let column = df.column(value_column(df))?.cast(&DataType::Float64)?;
+    let values: Vec<f64> = column
+        .f64()?
+        .iter()
+        .map(|value| value.unwrap_or(f64::NAN))
+        .collect();
+    if value_column(df) == "qx" {
+        return Ok(values);
+    }
+
+    let result = values
+        .iter()
+        .enumerate()
+        .map(|(i, lx)| match values.get(i + 1) {
+            Some(lx_next) if *lx > 0.0 => 1.0 - lx_next / lx,
+            _ => 1.0,
+        })
+        .collect();
+    Ok(result)
+}
+
+/// Header row and data rows of an export frame, missing cells as `None`.
+#[allow(clippy::type_complexity)]
+fn export_cells(df: &DataFrame) -> RSLifeResult<(Vec<String>, Vec<Vec<Option<f64>>>)> {
+    let headers = df
+        .get_column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    let columns = df
+        .columns()
+        .iter()
+        .map(|column| Ok(column.cast(&DataType::Float64)?))
+        .collect::<RSLifeResult<Vec<_>>>()?;
+    let mut rows = vec![Vec::with_capacity(columns.len()); df.height()];
+    for column in &columns {
+        for (row, value) in rows.iter_mut().zip(column.f64()?.iter()) {
+            row.push(value.filter(|value| !value.is_nan()));
+        }
+    }
+
+    Ok((headers, rows))
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn push_element(xml: &mut String, indent: usize, tag: &str, text: &str) {
+    let _ = writeln!(xml, "{:indent$}<{tag}>{}</{tag}>", "", escape_xml(text));
+}
+
+fn push_axis_def(xml: &mut String, scale_type: &str, axis_name: &str, values: &[u32]) {
+    let min = values.iter().min().copied().unwrap_or(0);
+    let max = values.iter().max().copied().unwrap_or(0);
+    let _ = writeln!(
+        xml,
+        "      <AxisDef><ScaleType>{scale_type}</ScaleType><AxisName>{axis_name}</AxisName>\
+         <MinScaleValue>{min}</MinScaleValue><MaxScaleValue>{max}</MaxScaleValue>\
+         <Increment>1</Increment></AxisDef>"
+    );
+}
+
+fn push_meta_data(xml: &mut String, nation: &str, description: &str) {
+    xml.push_str("  <Table>\n    <MetaData>\n");
+    push_element(xml, 6, "ScalingFactor", "0");
+    push_element(xml, 6, "DataType", "Floating Point");
+    if !nation.is_empty() {
+        push_element(xml, 6, "Nation", nation);
+    }
+    push_element(xml, 6, "TableDescription", description);
+}
+
+/// One-dimensional table of `(age, qx)`.
+fn push_ultimate_table(xml: &mut String, rates: &[(u32, f64)], nation: &str, description: &str) {
+    let ages: Vec<u32> = rates.iter().map(|(age, _)| *age).collect();
+    push_meta_data(xml, nation, description);
+    push_axis_def(xml, "Age", "Age", &ages);
+    xml.push_str("    </MetaData>\n    <Values>\n      <Axis>\n");
+    for (age, rate) in rates {
+        let _ = writeln!(xml, "        <Y t=\"{age}\">{rate}</Y>");
+    }
+    xml.push_str("      </Axis>\n    </Values>\n  </Table>\n");
+}
+
+/// Two-dimensional table of `(issue age, duration, qx)`, sorted by issue age.
+fn push_select_table(xml: &mut String, rates: &[(u32, u32, f64)], nation: &str) {
+    let issue_ages: Vec<u32> = rates.iter().map(|(issue_age, _, _)| *issue_age).collect();
+    let durations: Vec<u32> = rates.iter().map(|(_, duration, _)| *duration).collect();
+    push_meta_data(xml, nation, "Select rates");
+    push_axis_def(xml, "Age", "Issue Age", &issue_ages);
+    push_axis_def(xml, "Duration", "Duration", &durations);
+    xml.push_str("    </MetaData>\n    <Values>\n");
+    for (i, (issue_age, duration, rate)) in rates.iter().enumerate() {
+        if i == 0 || rates[i - 1].0 != *issue_age {
+            let _ = write!(xml, "      <Axis t=\"{issue_age}\"><Axis>");
+        }
+        let _ = write!(xml, "<Y t=\"{duration}\">{rate}</Y>");
+        if rates.get(i + 1).is_none_or(|next| next.0 != *issue_age) {
+            xml.push_str("</Axis></Axis>\n");
+        }
+    }
+    xml.push_str("    </Values>\n  </Table>\n");
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rows with a defined rate, sorted by age (and duration).
+    fn sorted(df: &DataFrame) -> DataFrame {
+        let value = value_column(df);
+        let mut by = vec!["age"];
+        if has_column(df, "duration") {
+            by.push("duration");
+        }
+        df.clone()
+            .lazy()
+            .filter(col(value).is_not_nan())
+            .sort(by, SortMultipleOptions::default())
+            .collect()
+            .unwrap()
+    }
+
+    /// Same rates, ignoring the placeholder rows of missing select rates.
+    fn assert_same_table(left: &MortData, right: &MortData) {
+        assert_eq!(sorted(&left.dataframe), sorted(&right.dataframe));
+    }
+
+    fn temp_path(name: &str) -> String {
+        let dir = std::env::temp_dir().join(format!("rslife_export_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name).to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_fn_soa_xml_round_trip() {
+        for id in ["ELT15_F", "AM92"] {
+            let mort_data = MortData::from_builtin(id).unwrap();
+            let xml = mort_data.to_soa_xml().unwrap();
+            let read_back = MortData::from_soa_xml_string(&xml).unwrap();
+            assert_same_table(&mort_data, &read_back);
+        }
+
+        let xml = MortData::from_builtin("ELT15_F")
+            .unwrap()
+            .to_soa_xml()
+            .unwrap();
+        let read_back = MortData::from_soa_xml_string(&xml).unwrap();
+        assert_eq!(read_back.metadata.table_identity.as_deref(), Some("1704"));
+    }
+
+    #[test]
+    fn test_fn_spreadsheet_round_trip() {
+        for id in ["ELT15_F", "AM92"] {
+            let mort_data = MortData::from_builtin(id).unwrap();
+
+            let xlsx_path = temp_path(&format!("{id}.xlsx"));
+            mort_data.to_xlsx(&xlsx_path, id).unwrap();
+            assert_same_table(&mort_data, &MortData::from_xlsx(&xlsx_path, id).unwrap());
+
+            let ods_path = temp_path(&format!("{id}.ods"));
+            mort_data.to_ods(&ods_path, id).unwrap();
+            assert_same_table(&mort_data, &MortData::from_ods(&ods_path, id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_fn_csv_and_parquet_round_trip() {
+        for id in ["ELT15_F", "AM92"] {
+            let mort_data = MortData::from_builtin(id).unwrap();
+
+            let csv_path = temp_path(&format!("{id}.csv"));
+            mort_data.to_csv(&csv_path).unwrap();
+            assert_same_table(&mort_data, &MortData::from_csv(&csv_path).unwrap());
+
+            let parquet_path = temp_path(&format!("{id}.parquet"));
+            mort_data.to_parquet(&parquet_path).unwrap();
+            assert_same_table(&mort_data, &MortData::from_parquet(&parquet_path).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_fn_export_frame_pivoted() {
+        let df = MortData::from_builtin("AM92")
+            .unwrap()
+            .to_export_frame()
+            .unwrap();
+        let names: Vec<&str> = df.get_column_names().iter().map(|n| n.as_str()).collect();
+        assert_eq!(names, ["age", "Duration 0", "Duration 1", "Durations 2+"]);
+    }
+}
@@ -0,0 +1,648 @@
+//! # Table Downloads
+//!
+//! Pluggable retrieval of the files behind the SOA, IFOA and AGA URL loaders.
+//!
+//! Every URL loader reads its bytes through a [`Fetcher`]:
+//! - [`HttpFetcher`]: HTTP GET with timeout and retries (default, `blocking` feature)
+//! - [`MirrorFetcher`]: files of a local mirror directory, named after the last URL segment
+//! - [`CachedFetcher`]: on-disk content cache keyed by URL in front of another fetcher
+//! - [`OfflineFetcher`]: fails fast with [`RSLifeError::Offline`]
+//!
+//! The default fetcher is configured once from the environment:
+//! - `RSLIFE_OFFLINE=1` disables downloads
+//! - `RSLIFE_MIRROR_DIR=<dir>` serves files from a local mirror instead of the network
+//! - `RSLIFE_CACHE_DIR=<dir>` caches downloaded files (cache hits are still served offline)
+//!
+//! Without the `blocking` feature there is no HTTP client and the default fetcher is offline
+//! unless a mirror directory is configured.
+//!
+//! With the `async` feature, the `*_async` loaders of [`MortData`](crate::mt_config::mt_data::MortData)
+//! read through an [`AsyncFetcher`] instead. The default async fetcher follows the same environment
+//! variables, with [`AsyncHttpFetcher`] in place of [`HttpFetcher`] and [`AsyncCachedFetcher`] for the
+//! cache, and can be replaced with [`set_default_async_fetcher`] or bypassed with the
+//! `*_async_with_fetcher` loaders.
+//!
+//! Each loader records [`Fetcher::location`] of its URL as the table location: the URL itself for
+//! downloads, the file path for mirrored or cached files.
+//!
+//! It can be replaced at runtime with [`set_default_fetcher`], or bypassed per call with the
+//! `*_with_fetcher` loaders of [`MortData`](crate::mt_config::mt_data::MortData).
+//!
+//! ```rust
+//! # use rslife::prelude::*;
+//! use rslife::mt_config::fetch::{Fetcher, OfflineFetcher};
+//!
+//! let result = MortData::from_soa_url_with_fetcher("https://mort.soa.org/data/t1704.xml", &OfflineFetcher);
+//! assert!(matches!(result, Err(RSLifeError::Offline(_))));
+//! # RSLifeResult::Ok(())
+//! ```
+
+use crate::{RSLifeError, RSLifeResult};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use bon::bon;
+use once_cell::sync::Lazy;
+use std::fs;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use std::time::Duration;
+
+// ================================================
+// FETCHER TRAIT
+// ================================================
+
+/// Source of the raw bytes behind a table URL.
+pub trait Fetcher: Send + Sync {
+    /// Fetch the content at `url`.
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>>;
+
+    /// Where the content at `url` is read from, recorded as the table location.
+    ///
+    /// Defaults to `url`; local sources return the file path instead.
+    fn location(&self, url: &str) -> String {
+        url.to_string()
+    }
+}
+
+impl<F: Fetcher + ?Sized> Fetcher for Arc<F> {
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        self.as_ref().fetch(url)
+    }
+
+    fn location(&self, url: &str) -> String {
+        self.as_ref().location(url)
+    }
+}
+
+/// Future returned by [`AsyncFetcher::fetch_async`].
+#[cfg(feature = "async")]
+pub type FetchFuture<'a> = Pin<Box<dyn Future<Output = RSLifeResult<Vec<u8>>> + Send + 'a>>;
+
+/// Source of the raw bytes behind a table URL, read without blocking the calling task.
+///
+/// Local sources ([`MirrorFetcher`], [`OfflineFetcher`]) implement both traits.
+#[cfg(feature = "async")]
+pub trait AsyncFetcher: Send + Sync {
+    /// Fetch the content at `url`.
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a>;
+
+    /// Where the content at `url` is read from, recorded as the table location.
+    ///
+    /// Defaults to `url`; local sources return the file path instead.
+    fn location(&self, url: &str) -> String {
+        url.to_string()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<F: AsyncFetcher + ?Sized> AsyncFetcher for Arc<F> {
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        self.as_ref().fetch_async(url)
+    }
+
+    fn location(&self, url: &str) -> String {
+        self.as_ref().location(url)
+    }
+}
+
+// ================================================
+// FETCHERS
+// ================================================
+
+/// Blocking HTTP download with a timeout and retries on connection failures.
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    timeout: Duration,
+    retries: u32,
+}
+
+#[cfg(feature = "blocking")]
+#[bon]
+impl HttpFetcher {
+    /// HTTP fetcher with `timeout` per request (default 30 s) and `retries` after the first attempt (default 2).
+    ///
+    /// HTTP error statuses (4xx, 5xx) are returned without retrying.
+    #[builder]
+    pub fn new(
+        #[builder(default = Duration::from_secs(30))] timeout: Duration,
+        #[builder(default = 2)] retries: u32,
+    ) -> Self {
+        Self { timeout, retries }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()?;
+
+        let mut attempt = 0;
+        loop {
+            let result = client
+                .get(url)
+                .send()
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.bytes());
+            match result {
+                Ok(bytes) => return Ok(bytes.to_vec()),
+                Err(err) if err.is_status() || attempt >= self.retries => return Err(err.into()),
+                Err(_) => attempt += 1,
+            }
+        }
+    }
+}
+
+/// Local mirror directory holding the files under the last segment of their URL.
+///
+/// For example `https://mort.soa.org/data/t1704.xml` is read from `<dir>/t1704.xml`.
+#[derive(Debug, Clone)]
+pub struct MirrorFetcher {
+    dir: PathBuf,
+}
+
+impl MirrorFetcher {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Path of the mirrored file for `url`.
+    fn path(&self, url: &str) -> RSLifeResult<PathBuf> {
+        let file_name = url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| RSLifeError::Other(format!("No file name in URL '{url}'")))?;
+        Ok(self.dir.join(file_name))
+    }
+}
+
+impl Fetcher for MirrorFetcher {
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        Ok(fs::read(self.path(url)?)?)
+    }
+
+    fn location(&self, url: &str) -> String {
+        match self.path(url) {
+            Ok(path) => path.display().to_string(),
+            Err(_) => url.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncFetcher for MirrorFetcher {
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(async move { self.fetch(url) })
+    }
+
+    fn location(&self, url: &str) -> String {
+        Fetcher::location(self, url)
+    }
+}
+
+/// On-disk content cache keyed by URL in front of another fetcher.
+///
+/// Cached files are served without calling the inner fetcher, so a cache in front of
+/// [`OfflineFetcher`] serves previously downloaded tables and fails fast on the rest.
+pub struct CachedFetcher {
+    inner: Arc<dyn Fetcher>,
+    dir: PathBuf,
+}
+
+impl CachedFetcher {
+    pub fn new(inner: impl Fetcher + 'static, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            dir: dir.into(),
+        }
+    }
+
+    /// Path of the cache file for `url`.
+    pub fn cache_path(&self, url: &str) -> PathBuf {
+        self.dir.join(cache_key(url))
+    }
+}
+
+impl Fetcher for CachedFetcher {
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        let path = self.cache_path(url);
+        if path.is_file() {
+            return Ok(fs::read(path)?);
+        }
+
+        let bytes = self.inner.fetch(url)?;
+        write_atomic(&path, &bytes)?;
+        Ok(bytes)
+    }
+
+    /// The cache file once `url` is cached, otherwise the location of the inner fetcher.
+    fn location(&self, url: &str) -> String {
+        let path = self.cache_path(url);
+        if path.is_file() {
+            path.display().to_string()
+        } else {
+            self.inner.location(url)
+        }
+    }
+}
+
+/// Fetcher refusing every download.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OfflineFetcher;
+
+impl Fetcher for OfflineFetcher {
+    fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        Err(RSLifeError::Offline(url.to_string()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncFetcher for OfflineFetcher {
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(async move { self.fetch(url) })
+    }
+}
+
+/// Non-blocking HTTP download with a timeout and retries on connection failures.
+///
+/// Default source of the `*_async` loaders. Requires a running tokio runtime.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct AsyncHttpFetcher {
+    timeout: Duration,
+    retries: u32,
+}
+
+#[cfg(feature = "async")]
+#[bon]
+impl AsyncHttpFetcher {
+    /// Async HTTP fetcher with `timeout` per request (default 30 s) and `retries` after the first attempt (default 2).
+    ///
+    /// HTTP error statuses (4xx, 5xx) are returned without retrying.
+    #[builder]
+    pub fn new(
+        #[builder(default = Duration::from_secs(30))] timeout: Duration,
+        #[builder(default = 2)] retries: u32,
+    ) -> Self {
+        Self { timeout, retries }
+    }
+
+    /// Fetch the content at `url`.
+    pub async fn fetch(&self, url: &str) -> RSLifeResult<Vec<u8>> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build()?;
+
+        let mut attempt = 0;
+        loop {
+            let result = async {
+                let response = client.get(url).send().await?.error_for_status()?;
+                response.bytes().await
+            }
+            .await;
+            match result {
+                Ok(bytes) => return Ok(bytes.to_vec()),
+                Err(err) if err.is_status() || attempt >= self.retries => return Err(err.into()),
+                Err(_) => attempt += 1,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for AsyncHttpFetcher {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncFetcher for AsyncHttpFetcher {
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(self.fetch(url))
+    }
+}
+
+/// On-disk content cache keyed by URL in front of another async fetcher.
+///
+/// Shares the cache layout of [`CachedFetcher`], so both serve each other's files.
+#[cfg(feature = "async")]
+pub struct AsyncCachedFetcher {
+    inner: Arc<dyn AsyncFetcher>,
+    dir: PathBuf,
+}
+
+#[cfg(feature = "async")]
+impl AsyncCachedFetcher {
+    pub fn new(inner: impl AsyncFetcher + 'static, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            dir: dir.into(),
+        }
+    }
+
+    /// Path of the cache file for `url`.
+    pub fn cache_path(&self, url: &str) -> PathBuf {
+        self.dir.join(cache_key(url))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncFetcher for AsyncCachedFetcher {
+    fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(async move {
+            let path = self.cache_path(url);
+            if path.is_file() {
+                return Ok(fs::read(path)?);
+            }
+
+            let bytes = self.inner.fetch_async(url).await?;
+            write_atomic(&path, &bytes)?;
+            Ok(bytes)
+        })
+    }
+
+    /// The cache file once `url` is cached, otherwise the location of the inner fetcher.
+    fn location(&self, url: &str) -> String {
+        let path = self.cache_path(url);
+        if path.is_file() {
+            path.display().to_string()
+        } else {
+            self.inner.location(url)
+        }
+    }
+}
+
+// ================================================
+// DEFAULT FETCHER
+// ================================================
+
+static DEFAULT_FETCHER: Lazy<RwLock<Arc<dyn Fetcher>>> =
+    Lazy::new(|| RwLock::new(fetcher_from_env()));
+
+/// Fetcher used by the URL loaders that do not take one explicitly.
+pub fn default_fetcher() -> Arc<dyn Fetcher> {
+    match DEFAULT_FETCHER.read() {
+        Ok(fetcher) => Arc::clone(&fetcher),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+    }
+}
+
+/// Replace the default fetcher for the whole process.
+pub fn set_default_fetcher(fetcher: impl Fetcher + 'static) {
+    let fetcher: Arc<dyn Fetcher> = Arc::new(fetcher);
+    match DEFAULT_FETCHER.write() {
+        Ok(mut current) => *current = fetcher,
+        Err(poisoned) => *poisoned.into_inner() = fetcher,
+    }
+}
+
+#[cfg(feature = "async")]
+static DEFAULT_ASYNC_FETCHER: Lazy<RwLock<Arc<dyn AsyncFetcher>>> =
+    Lazy::new(|| RwLock::new(async_fetcher_from_env()));
+
+/// Fetcher used by the `*_async` loaders that do not take one explicitly.
+#[cfg(feature = "async")]
+pub fn default_async_fetcher() -> Arc<dyn AsyncFetcher> {
+    match DEFAULT_ASYNC_FETCHER.read() {
+        Ok(fetcher) => Arc::clone(&fetcher),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+    }
+}
+
+/// Replace the default async fetcher for the whole process.
+#[cfg(feature = "async")]
+pub fn set_default_async_fetcher(fetcher: impl AsyncFetcher + 'static) {
+    let fetcher: Arc<dyn AsyncFetcher> = Arc::new(fetcher);
+    match DEFAULT_ASYNC_FETCHER.write() {
+        Ok(mut current) => *current = fetcher,
+        Err(poisoned) => *poisoned.into_inner() = fetcher,
+    }
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// Settings of the default fetchers: offline flag, mirror and cache directories.
+fn env_settings() -> (bool, Option<std::ffi::OsString>, Option<std::ffi::OsString>) {
+    let offline = std::env::var("RSLIFE_OFFLINE")
+        .map(|value| !matches!(value.trim(), "" | "0" | "false"))
+        .unwrap_or(false);
+    let mirror_dir = std::env::var_os("RSLIFE_MIRROR_DIR");
+    let cache_dir = std::env::var_os("RSLIFE_CACHE_DIR");
+    (offline, mirror_dir, cache_dir)
+}
+
+/// Default async fetcher from the same environment as [`fetcher_from_env`].
+#[cfg(feature = "async")]
+fn async_fetcher_from_env() -> Arc<dyn AsyncFetcher> {
+    let (offline, mirror_dir, cache_dir) = env_settings();
+    let source: Arc<dyn AsyncFetcher> = match (offline, mirror_dir) {
+        (true, _) => Arc::new(OfflineFetcher),
+        (false, Some(dir)) => Arc::new(MirrorFetcher::new(dir)),
+        (false, None) => Arc::new(AsyncHttpFetcher::default()),
+    };
+
+    match cache_dir {
+        Some(dir) => Arc::new(AsyncCachedFetcher {
+            inner: source,
+            dir: dir.into(),
+        }),
+        None => source,
+    }
+}
+
+/// Default fetcher from `RSLIFE_OFFLINE`, `RSLIFE_MIRROR_DIR` and `RSLIFE_CACHE_DIR`.
+fn fetcher_from_env() -> Arc<dyn Fetcher> {
+    let (offline, mirror_dir, cache_dir) = env_settings();
+
+    let source: Arc<dyn Fetcher> = match (offline, mirror_dir) {
+        (true, _) => Arc::new(OfflineFetcher),
+        (false, Some(dir)) => Arc::new(MirrorFetcher::new(dir)),
+        #[cfg(feature = "blocking")]
+        (false, None) => Arc::new(HttpFetcher::default()),
+        #[cfg(not(feature = "blocking"))]
+        (false, None) => Arc::new(OfflineFetcher),
+    };
+
+    match cache_dir {
+        Some(dir) => Arc::new(CachedFetcher {
+            inner: source,
+            dir: dir.into(),
+        }),
+        None => source,
+    }
+}
+
+/// File name for a URL: readable characters kept, everything else replaced by `_`.
+fn cache_key(url: &str) -> String {
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    url.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Write through a temporary file so that concurrent readers never see a partial file.
+fn write_atomic(path: &Path, bytes: &[u8]) -> RSLifeResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension(format!("tmp{}", std::process::id()));
+    fs::write(&tmp_path, bytes)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Stand-in for an HTTP server serving one document.
+    struct StubFetcher {
+        body: &'static [u8],
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Fetcher for StubFetcher {
+        fn fetch(&self, _url: &str) -> RSLifeResult<Vec<u8>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.body.to_vec())
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rslife_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_cache_key() {
+        assert_eq!(
+            cache_key("https://mort.soa.org/data/t1704.xml"),
+            "mort.soa.org_data_t1704.xml"
+        );
+    }
+
+    #[test]
+    fn test_cached_fetcher_reuses_content() {
+        let dir = temp_dir("cache");
+        let calls = Arc::new(AtomicUsize::new(0));
+        let stub = StubFetcher {
+            body: b"table",
+            calls: Arc::clone(&calls),
+        };
+        let fetcher = CachedFetcher::new(stub, &dir);
+        let url = "https://example.org/tables/t1.xml";
+
+        assert_eq!(fetcher.fetch(url).unwrap(), b"table");
+        assert_eq!(fetcher.fetch(url).unwrap(), b"table");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Cache hits are served even when offline
+        let offline = CachedFetcher::new(OfflineFetcher, &dir);
+        assert_eq!(offline.fetch(url).unwrap(), b"table");
+        assert!(matches!(
+            offline.fetch("https://example.org/tables/t2.xml"),
+            Err(RSLifeError::Offline(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetcher_location() {
+        let dir = temp_dir("location");
+        let mirror_dir = dir.join("mirror");
+        fs::create_dir_all(&mirror_dir).unwrap();
+        fs::write(mirror_dir.join("t1.xml"), b"table").unwrap();
+        let url = "https://example.org/tables/t1.xml";
+
+        // Downloads are recorded by URL, mirrored files by path
+        assert_eq!(Fetcher::location(&OfflineFetcher, url), url);
+        let mirror = MirrorFetcher::new(&mirror_dir);
+        let mirror_path = mirror_dir.join("t1.xml").display().to_string();
+        assert_eq!(Fetcher::location(&mirror, url), mirror_path);
+
+        // The cache defers to its inner fetcher until the file is cached
+        let cached = CachedFetcher::new(mirror, dir.join("cache"));
+        assert_eq!(cached.location(url), mirror_path);
+        cached.fetch(url).unwrap();
+        assert_eq!(
+            cached.location(url),
+            cached.cache_path(url).display().to_string()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_mirror_fetcher() {
+        let dir = temp_dir("mirror");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("t1704.xml"), b"<XTbML/>").unwrap();
+
+        let fetcher = MirrorFetcher::new(&dir);
+        let bytes = fetcher
+            .fetch("https://mort.soa.org/data/t1704.xml")
+            .unwrap();
+        assert_eq!(bytes, b"<XTbML/>");
+        assert!(matches!(
+            fetcher.fetch("https://mort.soa.org/data/t9999.xml"),
+            Err(RSLifeError::Io(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_cached_fetcher() {
+        let dir = temp_dir("async_cache");
+        let mirror_dir = temp_dir("async_mirror");
+        fs::create_dir_all(&mirror_dir).unwrap();
+        fs::write(mirror_dir.join("t1.xml"), b"table").unwrap();
+        let url = "https://example.org/tables/t1.xml";
+
+        // First read from the mirror, then from the cache alone
+        let fetcher = AsyncCachedFetcher::new(MirrorFetcher::new(&mirror_dir), &dir);
+        assert_eq!(fetcher.fetch_async(url).await.unwrap(), b"table");
+        fs::remove_dir_all(&mirror_dir).unwrap();
+        assert_eq!(fetcher.fetch_async(url).await.unwrap(), b"table");
+
+        // Shared with the blocking cache
+        let offline = CachedFetcher::new(OfflineFetcher, &dir);
+        assert_eq!(offline.fetch(url).unwrap(), b"table");
+        assert!(matches!(
+            OfflineFetcher.fetch_async(url).await,
+            Err(RSLifeError::Offline(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
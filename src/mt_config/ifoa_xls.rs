@@ -1,9 +1,12 @@
-use crate::RSLifeResult;
+#[cfg(feature = "async")]
+use crate::mt_config::fetch::AsyncFetcher;
+use crate::mt_config::fetch::{Fetcher, default_fetcher};
+use crate::mt_config::improvement::reduction_factor_92;
 use crate::mt_config::spreadsheet_helpers::{parse_excel_data, parse_excel_headers};
+use crate::{RSLifeError, RSLifeResult};
 use calamine::{Data, Reader, Xls, open_workbook_auto};
 use polars::prelude::*;
-use reqwest::blocking::get;
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek};
 
 // IFOA XLS tables embedded at compile time.
 // Paths are relative to this source file.
@@ -54,6 +57,8 @@ const XLS_00SERIES: &[u8] = include_bytes!("../../data/00series.xls");
 pub struct IFOAMortXLS {
     pub description: String,
     pub dataframe: DataFrame,
+    /// Where `from_url_id` and `from_custom` fetched the workbook from (`None` for embedded data).
+    pub location: Option<String>,
 }
 
 impl IFOAMortXLS {
@@ -76,7 +81,9 @@ impl IFOAMortXLS {
         let mut workbook = open_workbook_auto(file_path)?;
         let sheet_names = workbook.sheet_names().to_owned();
         if !sheet_names.iter().any(|n| n == sheet_name) {
-            return Err(format!("Sheet '{sheet_name}' not found in workbook").into());
+            return Err(RSLifeError::Parse(format!(
+                "Sheet '{sheet_name}' not found in workbook"
+            )));
         }
         let range = workbook.worksheet_range(sheet_name)?;
         // Obtain structure to identify correct parsing process
@@ -91,26 +98,27 @@ impl IFOAMortXLS {
     ///
     /// # Parameters
     /// - `url`: Direct URL to the XLS file on the IFOA website.
+    /// - `fetcher`: Source of the downloaded file.
     ///
     /// # Errors
     /// - Network errors or invalid URL
     /// - Sheet not found in workbook
     /// - Invalid data or unsupported structure
-    pub fn from_url(url: &str) -> RSLifeResult<Self> {
-        // Eg: https://www.actuaries.org.uk/documents/tm92-temporary-assurances-males
-        // Extract last part of url . Eg "tm92-temporary-assurances-males"
-        let full_name = url
-            .split('/')
-            .next_back()
-            .ok_or("Invalid URL format, no sheet name found")?;
-        // Extract the first part of full name which is sheet name/id Eg: "TM92"
-        let id_owned = full_name
-            .split('-')
-            .next()
-            .ok_or("Invalid URL format, no sheet name found")?
-            .to_uppercase();
-        let id = id_owned.as_str();
-        let range = fetch_range_from_url(url, id)?;
+    pub fn from_url(url: &str, fetcher: &dyn Fetcher) -> RSLifeResult<Self> {
+        let id = sheet_name_from_url(url)?;
+        let bytes = fetcher.fetch(url)?;
+        let range = range_from_xls(Cursor::new(bytes), &id)?;
+        data_process(1, range)
+    }
+
+    /// Load an IFOA mortality table from a direct URL without blocking, downloading through `fetcher`.
+    ///
+    /// Refer to `from_url()` for details.
+    #[cfg(feature = "async")]
+    pub async fn from_url_async(url: &str, fetcher: &dyn AsyncFetcher) -> RSLifeResult<Self> {
+        let id = sheet_name_from_url(url)?;
+        let bytes = fetcher.fetch_async(url).await?;
+        let range = range_from_xls(Cursor::new(bytes), &id)?;
         data_process(1, range)
     }
 
@@ -146,13 +154,21 @@ impl IFOAMortXLS {
             _ => return Err(format!("{id} is not supported").into()),
         };
 
-        let range = if !xls_name.is_empty() {
-            fetch_range_from_local_data(xls_name, sheet_name)?
+        let (range, location) = if !xls_name.is_empty() {
+            (fetch_range_from_local_data(xls_name, sheet_name)?, None)
         } else {
-            fetch_range_from_url(&url, sheet_name)?
+            let fetcher = default_fetcher();
+            let bytes = fetcher.fetch(&url)?;
+            let location = fetcher.location(&url);
+            (
+                range_from_xls(Cursor::new(bytes), sheet_name)?,
+                Some(location),
+            )
         };
 
-        data_process(structure, range)
+        let mut result = data_process(structure, range)?;
+        result.location = location;
+        Ok(result)
     }
 
     pub fn from_custom(id: &str) -> RSLifeResult<Self> {
@@ -171,13 +187,21 @@ impl IFOAMortXLS {
         };
 
         // Check if xls_name is some string then use it
-        let range = if !xls_name.is_empty() {
-            fetch_range_from_local_data(xls_name, sheet_name)?
+        let (range, location) = if !xls_name.is_empty() {
+            (fetch_range_from_local_data(xls_name, sheet_name)?, None)
         } else {
-            fetch_range_from_url(&url, sheet_name)?
+            let fetcher = default_fetcher();
+            let bytes = fetcher.fetch(&url)?;
+            let location = fetcher.location(&url);
+            (
+                range_from_xls(Cursor::new(bytes), sheet_name)?,
+                Some(location),
+            )
         };
 
-        data_process(structure, range)
+        let mut result = data_process(structure, range)?;
+        result.location = location;
+        Ok(result)
     }
 }
 
@@ -197,6 +221,7 @@ fn data_process(structure: u32, range: calamine::Range<Data>) -> RSLifeResult<IF
     let result = IFOAMortXLS {
         description,
         dataframe: df,
+        location: None,
     };
 
     Ok(result)
@@ -254,44 +279,27 @@ fn data_process_10x(
 
     // Modify description
     let new_description = format!(
-        "{description}\nThis is a custom series based on the 92-series base mortality tables with C20 projection."
+        "{description}\nThis is a custom series based on the 92-series base mortality tables with C{} projection.",
+        c_input as i32 % 100
     );
 
-    // Project the orginal data
-    let dataframe = dataframe
-        .lazy()
-        .with_columns(vec![
-            // Add 'alpha' column using the specified piecewise logic
-            when(col("age").lt(lit(60)))
-                .then(lit(0.13))
-                .when(col("age").gt_eq(lit(60)).and(col("age").lt_eq(lit(110))))
-                .then(lit(1.0) - lit(0.87) * (lit(110.0) - col("age")) / lit(50.0))
-                .when(col("age").gt_eq(lit(110)))
-                .then(lit(1.0))
-                .otherwise(lit(f64::NAN))
-                .alias("alpha"),
-            // Add column 'f' with specified piecewise logic
-            when(col("age").lt(lit(60)))
-                .then(lit(0.55))
-                .when(col("age").gt_eq(lit(60)).and(col("age").lt_eq(lit(110))))
-                .then(
-                    lit(0.55) * (lit(110.0) - col("age")) / lit(50.0)
-                        + lit(0.29) * (col("age") - lit(60.0)) / lit(50.0),
-                )
-                .when(col("age").gt_eq(lit(110)))
-                .then(lit(0.29))
-                .otherwise(lit(f64::NAN))
-                .alias("f"),
-        ])
-        .with_column(
-            (col("alpha")
-                + (lit(1.0) - col("alpha"))
-                    * (lit(1.0) - col("f")).pow(lit((c_input - 1992.0) / 20.0)))
-            .alias("reduction_factor"),
-        )
-        .with_column((col("qx") * col("reduction_factor")).alias("qx_reduced"))
-        .select(&[col("age"), col("qx_reduced").alias("qx")])
-        .collect()?;
+    // Project the orginal data with the 92-series reduction factors
+    let t = c_input - 1992.0;
+    let ages = dataframe.column("age")?.cast(&DataType::Float64)?;
+    let qx = dataframe.column("qx")?.cast(&DataType::Float64)?;
+    let qx_reduced: Vec<Option<f64>> = ages
+        .f64()?
+        .iter()
+        .zip(qx.f64()?.iter())
+        .map(|(age, qx)| Some(qx? * reduction_factor_92(age?, t)))
+        .collect();
+    let dataframe = DataFrame::new(
+        dataframe.height(),
+        vec![
+            dataframe.column("age")?.clone(),
+            Series::new("qx".into(), qx_reduced).into_column(),
+        ],
+    )?;
 
     // Return result
     Ok((new_description, dataframe))
@@ -299,6 +307,11 @@ fn data_process_10x(
 
 //---------------------------------------------------------------------
 
+/// Whether `id` is a base table of the 92-series workbook (e.g. "AM92", "PMA92").
+pub(crate) fn is_92_series_base(id: &str) -> bool {
+    matches!(get_info_from_id(id), Ok((1, "92series", _)))
+}
+
 fn get_info_from_id(id: &str) -> RSLifeResult<(u32, &str, &str)> {
     // These are updated manually from the IFOA website
     match id {
@@ -343,7 +356,7 @@ fn get_info_from_id(id: &str) -> RSLifeResult<(u32, &str, &str)> {
         )),
 
         // Unsupported
-        _ => Err(format!("Unknown id: {id}").into()),
+        _ => Err(RSLifeError::UnknownTable(id.to_string())),
     }
 }
 
@@ -363,23 +376,38 @@ fn fetch_range_from_local_data(
         "00series" => XLS_00SERIES,
         _ => return Err(format!("No embedded data for {file_name}").into()),
     };
-    let mut workbook = Xls::new(Cursor::new(bytes))?;
-    let sheet_names = workbook.sheet_names().to_owned();
-    if !sheet_names.iter().any(|n| n == sheet_name) {
-        return Err(format!("Sheet '{sheet_name}' not found in workbook").into());
-    }
-    let range = workbook.worksheet_range(sheet_name)?;
-    Ok(range)
+    range_from_xls(Cursor::new(bytes), sheet_name)
 }
 
-/// 1b. Retrieve the data from a URL and return the calamine::Range<Data> for the first sheet
-fn fetch_range_from_url(url: &str, sheet_name: &str) -> RSLifeResult<calamine::Range<Data>> {
-    let response = get(url)?;
-    let bytes = response.bytes()?;
-    let mut workbook = Xls::new(Cursor::new(bytes))?;
+/// 1b. Sheet name of an IFOA document URL
+///
+/// Eg: https://www.actuaries.org.uk/documents/tm92-temporary-assurances-males -> "TM92"
+pub(crate) fn sheet_name_from_url(url: &str) -> RSLifeResult<String> {
+    // Extract last part of url . Eg "tm92-temporary-assurances-males"
+    let full_name = url
+        .split('/')
+        .next_back()
+        .ok_or("Invalid URL format, no sheet name found")?;
+    // Extract the first part of full name which is sheet name/id Eg: "TM92"
+    let id = full_name
+        .split('-')
+        .next()
+        .ok_or("Invalid URL format, no sheet name found")?
+        .to_uppercase();
+    Ok(id)
+}
+
+/// 1c. Read an XLS workbook (embedded or downloaded) and return the calamine::Range<Data> for the specified sheet
+fn range_from_xls<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> RSLifeResult<calamine::Range<Data>> {
+    let mut workbook = Xls::new(reader)?;
     let sheet_names = workbook.sheet_names().to_owned();
     if !sheet_names.iter().any(|n| n == sheet_name) {
-        return Err(format!("Sheet '{sheet_name}' not found in workbook").into());
+        return Err(RSLifeError::Parse(format!(
+            "Sheet '{sheet_name}' not found in workbook"
+        )));
     }
     let range = workbook.worksheet_range(sheet_name)?;
     Ok(range)
@@ -0,0 +1,490 @@
+//! # Mortality Improvement
+//!
+//! Project a base mortality table to other calendar years with annual rates of mortality
+//! improvement, and build generational (year-of-birth) tables for pension valuations.
+//!
+//! An improvement rate AIₓ,ᵧ reduces mortality at age x from calendar year y-1 to year y:
+//! - qₓ,ᵧ = qₓ,ᵧ₋₁ · (1 - AIₓ,ᵧ)
+//! - qₓ,Y = qₓ(base) · Πᵧ₌base+1..Y (1 - AIₓ,ᵧ) period table for calendar year Y
+//! - qₓ = qₓ,YoB+x generational table for year of birth YoB
+//!
+//! Improvement scales come in several styles, all held by [`ImprovementScale`]:
+//! - **CMI / MP style**: rates by age and calendar year, the last year's rates continuing as
+//!   long-term (ultimate) rates
+//! - **Scale AA style**: rates by age only, constant over calendar years
+//! - **Constant**: one rate at all ages and years
+//! - **CMI 92-series**: the reduction factors of the 92-series base tables (`cmi_92_series()`)
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! use polars::prelude::*;
+//!
+//! // Scale AA style: rates by age only
+//! let ages: Vec<u32> = (0..=120).collect();
+//! let rates: Vec<f64> = ages.iter().map(|&x| if x < 85 { 0.015 } else { 0.005 }).collect();
+//! let scale = ImprovementScale::from_df(&df! { "age" => ages, "rate" => rates }?)?;
+//!
+//! let projection = MortalityProjection::builder()
+//!     .base(MortData::from_builtin("PMA92")?)
+//!     .base_year(1992)
+//!     .scale(scale)
+//!     .build()?;
+//!
+//! // Period table for 2025 and generational table for members born in 1960
+//! let period = projection.period_table(2025)?;
+//! let cohort = projection.generational_table(1960)?;
+//! let config = MortTableConfig::builder().data(cohort).build()?;
+//! let annuity = aax().mt(&config).i(0.03).x(65.0).call()?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::mt_data::MortData;
+use crate::error::invalid_parameter;
+use crate::{RSLifeError, RSLifeResult};
+use bon::bon;
+use polars::prelude::*;
+use std::collections::BTreeSet;
+
+// ===============================================
+// IMPROVEMENT SCALE
+// ===============================================
+
+/// Annual rates of mortality improvement by age and calendar year.
+///
+/// Ages use the rates of the closest age of the scale at or below them, so ages between the
+/// ages of the scale take the rates of the age below and ages above the scale those of the
+/// oldest age; ages below the scale use the youngest age. Calendar years are looked up the
+/// same way: years after the last year of the scale use the last year's rates, and years
+/// before the first use the first year's rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImprovementScale {
+    data: ScaleData,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ScaleData {
+    /// Same rate at all ages and years
+    Constant(f64),
+    /// CMI 92-series reduction factors from the 1992 base year
+    Series92,
+    /// Rates by age (rows) and calendar year (columns); a single column if by age only
+    Table {
+        ages: Vec<u32>,
+        years: Vec<i32>,
+        rates: Vec<Vec<f64>>,
+    },
+}
+
+impl ImprovementScale {
+    /// Improvement scale from a DataFrame of rates.
+    ///
+    /// # Schema Requirements
+    /// - "age": ages (u32 convertible)
+    /// - "rate": annual improvement rates (f64, below 1.0; negative for worsening mortality)
+    /// - Optional "year": calendar years (i32 convertible). Without it, rates apply to all years.
+    /// - One rate for each combination of age and year
+    ///
+    /// # Errors
+    /// - Missing columns or invalid data types
+    /// - Rate not finite or not below 1.0
+    /// - Duplicate or missing combinations of age and year
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use rslife::prelude::*;
+    /// use polars::prelude::*;
+    ///
+    /// // CMI / MP style: rates by age and calendar year
+    /// let df = df! {
+    ///     "age" => [60u32, 60, 61, 61],
+    ///     "year" => [2024i32, 2025, 2024, 2025],
+    ///     "rate" => [0.020, 0.018, 0.021, 0.019],
+    /// }?;
+    /// let scale = ImprovementScale::from_df(&df)?;
+    /// assert_eq!(scale.rate(60, 2025), 0.018);
+    /// assert_eq!(scale.rate(61, 2040), 0.019); // last year's rate continues
+    /// # RSLifeResult::Ok(())
+    /// ```
+    pub fn from_df(df: &DataFrame) -> RSLifeResult<Self> {
+        let ages = df.column("age")?.cast(&DataType::UInt32)?;
+        let ages: Vec<u32> = ages
+            .u32()?
+            .into_iter()
+            .map(|age| age.ok_or_else(|| RSLifeError::Schema("Missing age in scale".into())))
+            .collect::<RSLifeResult<_>>()?;
+        let rates = df.column("rate")?.cast(&DataType::Float64)?;
+        let rates: Vec<f64> = rates
+            .f64()?
+            .into_iter()
+            .map(|rate| rate.unwrap_or(f64::NAN))
+            .collect();
+        let years: Vec<i32> = if df.get_column_names().iter().any(|n| n.as_str() == "year") {
+            let years = df.column("year")?.cast(&DataType::Int32)?;
+            years
+                .i32()?
+                .into_iter()
+                .map(|year| year.ok_or_else(|| RSLifeError::Schema("Missing year in scale".into())))
+                .collect::<RSLifeResult<_>>()?
+        } else {
+            vec![0; ages.len()]
+        };
+
+        if let Some(rate) = rates.iter().find(|rate| !rate.is_finite() || **rate >= 1.0) {
+            return Err(RSLifeError::Schema(format!(
+                "Improvement rate {rate} must be finite and below 1.0"
+            )));
+        }
+
+        let age_axis: Vec<u32> = ages
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let year_axis: Vec<i32> = years
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if age_axis.is_empty() {
+            return Err(RSLifeError::Schema("Improvement scale is empty".into()));
+        }
+        if age_axis.len() * year_axis.len() != rates.len() {
+            return Err(RSLifeError::Schema(
+                "Improvement scale needs exactly one rate per age and year".into(),
+            ));
+        }
+
+        let mut grid = vec![vec![f64::NAN; year_axis.len()]; age_axis.len()];
+        for ((age, year), rate) in ages.iter().zip(&years).zip(&rates) {
+            let row = age_axis.partition_point(|a| a < age);
+            let col = year_axis.partition_point(|y| y < year);
+            if !grid[row][col].is_nan() {
+                return Err(RSLifeError::Schema(format!(
+                    "Duplicate improvement rate at age {age}, year {year}"
+                )));
+            }
+            grid[row][col] = *rate;
+        }
+
+        let result = Self {
+            data: ScaleData::Table {
+                ages: age_axis,
+                years: year_axis,
+                rates: grid,
+            },
+        };
+        Ok(result)
+    }
+
+    /// Same improvement rate at all ages and calendar years.
+    ///
+    /// # Errors
+    /// - `InvalidParameters`: rate not finite or not below 1.0
+    pub fn constant(rate: f64) -> RSLifeResult<Self> {
+        if !rate.is_finite() || rate >= 1.0 {
+            return Err(invalid_parameter(
+                "rate",
+                format!("Improvement rate {rate} must be finite and below 1.0"),
+            ));
+        }
+        Ok(Self {
+            data: ScaleData::Constant(rate),
+        })
+    }
+
+    /// Improvement implied by the CMI reduction factors of the 92-series base tables.
+    ///
+    /// RF(x, t) = α(x) + (1 - α(x))·(1 - f(x))^(t/20), with t the calendar year less 1992.
+    /// The cumulative factor from 1992 to year Y is RF(x, Y - 1992), as used for the
+    /// PMA92C20 and PFA92C20 tables.
+    pub fn cmi_92_series() -> Self {
+        Self {
+            data: ScaleData::Series92,
+        }
+    }
+
+    /// Improvement rate AIₓ,ᵧ from calendar year `year - 1` to `year` at age `age`.
+    pub fn rate(&self, age: u32, year: i32) -> f64 {
+        match &self.data {
+            ScaleData::Constant(rate) => *rate,
+            ScaleData::Series92 => 1.0 - self.cumulative_factor(age, year - 1, year),
+            ScaleData::Table { ages, years, rates } => {
+                let row = floor_index(ages, &age);
+                let col = floor_index(years, &year);
+                rates[row][col]
+            }
+        }
+    }
+
+    /// Factor Πᵧ (1 - AIₓ,ᵧ) taking qₓ from calendar year `from_year` to `to_year`.
+    ///
+    /// Projecting back in time (`to_year < from_year`) divides by the improvement instead.
+    pub fn cumulative_factor(&self, age: u32, from_year: i32, to_year: i32) -> f64 {
+        if let ScaleData::Series92 = self.data {
+            let age = age as f64;
+            return reduction_factor_92(age, (to_year - 1992) as f64)
+                / reduction_factor_92(age, (from_year - 1992) as f64);
+        }
+
+        let factor = |from: i32, to: i32| -> f64 {
+            ((from + 1)..=to)
+                .map(|year| 1.0 - self.rate(age, year))
+                .product()
+        };
+        if to_year >= from_year {
+            factor(from_year, to_year)
+        } else {
+            1.0 / factor(to_year, from_year)
+        }
+    }
+}
+
+// ===============================================
+// MORTALITY PROJECTION
+// ===============================================
+
+/// Base mortality table with an improvement scale, projecting qₓ to any calendar year.
+#[derive(Debug, Clone)]
+pub struct MortalityProjection {
+    /// Base table of qₓ (select tables are projected by attained age).
+    pub base: MortData,
+
+    /// Calendar year the base table applies to.
+    pub base_year: i32,
+
+    /// Annual improvement rates applied from the base year.
+    pub scale: ImprovementScale,
+}
+
+#[bon]
+impl MortalityProjection {
+    /// Create a projection of `base` from calendar year `base_year`.
+    ///
+    /// # Errors
+    /// - Base table without `qx` column
+    #[builder]
+    pub fn new(base: MortData, base_year: i32, scale: ImprovementScale) -> RSLifeResult<Self> {
+        if !base
+            .dataframe
+            .get_column_names()
+            .iter()
+            .any(|n| n.as_str() == "qx")
+        {
+            return Err(RSLifeError::Schema(
+                "Mortality projection requires a base table of qx".into(),
+            ));
+        }
+        Ok(Self {
+            base,
+            base_year,
+            scale,
+        })
+    }
+}
+
+impl MortalityProjection {
+    /// Base rate `base_qx` at age `age` projected to calendar year `year`, capped at 1.0.
+    fn projected_qx(&self, base_qx: f64, age: u32, year: i32) -> f64 {
+        (base_qx * self.scale.cumulative_factor(age, self.base_year, year)).min(1.0)
+    }
+
+    /// Period table: qₓ of every age projected to calendar year `year`.
+    ///
+    /// # Errors
+    /// - Invalid data types in the base table
+    pub fn period_table(&self, year: i32) -> RSLifeResult<MortData> {
+        let description = format!("{} projected to {year}", self.base.description);
+        self.project(description, |_| year)
+    }
+
+    /// Generational table for lives born in `year_of_birth`: qₓ projected to year of birth + x.
+    ///
+    /// # Errors
+    /// - Invalid data types in the base table
+    pub fn generational_table(&self, year_of_birth: i32) -> RSLifeResult<MortData> {
+        let description = format!(
+            "{} generational for year of birth {year_of_birth}",
+            self.base.description
+        );
+        self.project(description, |age| year_of_birth + age as i32)
+    }
+
+    /// Base table with qₓ at each age projected to `year_of(age)`.
+    fn project(&self, description: String, year_of: impl Fn(u32) -> i32) -> RSLifeResult<MortData> {
+        let df = &self.base.dataframe;
+        let ages = df.column("age")?.cast(&DataType::UInt32)?;
+        let qx = df.column("qx")?.cast(&DataType::Float64)?;
+        let projected: Vec<Option<f64>> = ages
+            .u32()?
+            .iter()
+            .zip(qx.f64()?.iter())
+            .map(|(age, qx)| match (age, qx) {
+                (Some(age), Some(qx)) => Some(self.projected_qx(qx, age, year_of(age))),
+                _ => qx,
+            })
+            .collect();
+
+        let mut data = df.clone();
+        data.with_column(Series::new("qx".into(), projected).into_column())?;
+        let category = "Projected Mortality Data".to_string();
+        let mut result = MortData::new(category, description, data)?;
+
+        // Publisher classification of the base table
+        let base = &self.base.metadata;
+        result.metadata.table_identity = base.table_identity.clone();
+        result.metadata.provider = base.provider.clone();
+        result.metadata.nation = base.nation.clone();
+        result.metadata.content_type = base.content_type.clone();
+        Ok(result)
+    }
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// CMI 92-series reduction factor RF(x, t) at age `age`, `t` years after 1992.
+pub(crate) fn reduction_factor_92(age: f64, t: f64) -> f64 {
+    let (alpha, f) = if age < 60.0 {
+        (0.13, 0.55)
+    } else if age <= 110.0 {
+        (
+            1.0 - 0.87 * (110.0 - age) / 50.0,
+            0.55 * (110.0 - age) / 50.0 + 0.29 * (age - 60.0) / 50.0,
+        )
+    } else {
+        (1.0, 0.29)
+    };
+    alpha + (1.0 - alpha) * (1.0 - f).powf(t / 20.0)
+}
+
+/// Index of `value` in the sorted `axis`, or of the last entry below it (first entry if none).
+fn floor_index<T: PartialOrd>(axis: &[T], value: &T) -> usize {
+    axis.partition_point(|entry| entry <= value)
+        .saturating_sub(1)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// qx at `age`, from the first row of that age (shared with the `mt_data` tests).
+    pub(in crate::mt_config) fn qx_at(mort_data: &MortData, age: u32) -> f64 {
+        let df = mort_data
+            .dataframe
+            .clone()
+            .lazy()
+            .filter(col("age").eq(lit(age)))
+            .collect()
+            .unwrap();
+        df.column("qx").unwrap().f64().unwrap().get(0).unwrap()
+    }
+
+    #[test]
+    fn test_fn_scale_lookup() {
+        let df = df! {
+            "age" => [60u32, 60, 61, 61],
+            "year" => [2024i32, 2025, 2024, 2025],
+            "rate" => [0.020, 0.018, 0.021, 0.019],
+        }
+        .unwrap();
+        let scale = ImprovementScale::from_df(&df).unwrap();
+        assert_eq!(scale.rate(60, 2024), 0.020);
+        assert_eq!(scale.rate(61, 2025), 0.019);
+        // Closest age and year of the scale outside it
+        assert_eq!(scale.rate(50, 2000), 0.020);
+        assert_eq!(scale.rate(90, 2050), 0.019);
+
+        // Ages between those of the scale take the rates of the age below
+        let quinquennial = df! {
+            "age" => [60u32, 65],
+            "rate" => [0.020, 0.010],
+        }
+        .unwrap();
+        let scale_by_age = ImprovementScale::from_df(&quinquennial).unwrap();
+        assert_eq!(scale_by_age.rate(64, 2030), 0.020);
+        assert_eq!(scale_by_age.rate(65, 2030), 0.010);
+
+        let factor = scale.cumulative_factor(60, 2023, 2025);
+        assert!((factor - 0.98 * 0.982).abs() < 1e-15);
+        assert!((scale.cumulative_factor(60, 2025, 2023) * factor - 1.0).abs() < 1e-12);
+
+        // Missing combination of age and year
+        let incomplete = df! {
+            "age" => [60u32, 60, 61],
+            "year" => [2024i32, 2025, 2024],
+            "rate" => [0.020, 0.018, 0.021],
+        }
+        .unwrap();
+        assert!(matches!(
+            ImprovementScale::from_df(&incomplete),
+            Err(RSLifeError::Schema(_))
+        ));
+        assert!(matches!(
+            ImprovementScale::constant(1.0),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_period_and_generational_tables() {
+        let base = MortData::from_builtin("PMA92").unwrap();
+        let projection = MortalityProjection::builder()
+            .base(base.clone())
+            .base_year(1992)
+            .scale(ImprovementScale::constant(0.01).unwrap())
+            .build()
+            .unwrap();
+
+        // Period table: all ages projected by the same number of years
+        let period = projection.period_table(2002).unwrap();
+        let expected = qx_at(&base, 70) * 0.99f64.powi(10);
+        assert!((qx_at(&period, 70) - expected).abs() < 1e-15);
+
+        // Generational table: age 70 reached in 1940 + 70 = 2010
+        let cohort = projection.generational_table(1940).unwrap();
+        let expected = qx_at(&base, 70) * 0.99f64.powi(18);
+        assert!((qx_at(&cohort, 70) - expected).abs() < 1e-15);
+        assert_eq!(cohort.dataframe.height(), base.dataframe.height());
+    }
+
+    #[test]
+    fn test_fn_cmi_92_series() {
+        let scale = ImprovementScale::cmi_92_series();
+        // RF(x, 0) = 1 and RF(x, t) → α(x)
+        assert!((scale.cumulative_factor(70, 1992, 1992) - 1.0).abs() < 1e-15);
+        assert!((reduction_factor_92(50.0, 20.0) - (0.13 + 0.87 * 0.45)).abs() < 1e-15);
+        assert_eq!(reduction_factor_92(115.0, 20.0), 1.0);
+
+        // Annual rates compound to the cumulative factor
+        let compounded: f64 = (1993..=2020).map(|y| 1.0 - scale.rate(70, y)).product();
+        assert!((compounded - scale.cumulative_factor(70, 1992, 2020)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fn_select_table_projection() {
+        let base = MortData::from_builtin("AM92").unwrap();
+        let projection = MortalityProjection::builder()
+            .base(base.clone())
+            .base_year(1992)
+            .scale(ImprovementScale::constant(0.02).unwrap())
+            .build()
+            .unwrap();
+        let period = projection.period_table(1993).unwrap();
+        assert_eq!(period.dataframe.shape(), base.dataframe.shape());
+        assert!(
+            period
+                .dataframe
+                .get_column_names()
+                .iter()
+                .any(|n| n.as_str() == "duration")
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Long and pivoted layouts of select tables.
+//!
+//! `MortData` stores select tables in long layout (`age`, `qx`, `duration`). Files use the
+//! pivoted layout of the IFOA workbooks: one row per age and one column per duration, the
+//! last column holding the ultimate rates. Duration headers are either written out or, as in
+//! the IFOA workbooks, bare numbers.
+//!
+//! ```text
+//! age | Duration 0 | Duration 1 | Durations 2+
+//! age | 0          | 1          | 2
+//! ```
+//!
+//! [`ColumnMapping`] maps the column names of CSV and Parquet files to the `MortData` schema.
+//!
+//! ```rust, ignore
+//! # use rslife::prelude::*;
+//! let mapping = ColumnMapping::builder().age("Age").qx("q").duration("Dur").build();
+//! let mort_data = MortData::from_csv_with_mapping("experience.csv", &mapping)?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+use crate::{RSLifeError, RSLifeResult};
+use bon::bon;
+use polars::prelude::*;
+use std::collections::BTreeSet;
+
+/// Column names of a table file for each column of the `MortData` schema.
+///
+/// Names are matched case-insensitively, ignoring surrounding spaces. Columns not mapped
+/// are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// Column of ages (default `age`)
+    pub age: String,
+    /// Column of mortality rates (default `qx`)
+    pub qx: String,
+    /// Column of survivors, used when there is no `qx` column (default `lx`)
+    pub lx: String,
+    /// Column of durations since selection, for select tables in long layout (default `duration`)
+    pub duration: String,
+}
+
+#[bon]
+impl ColumnMapping {
+    #[builder]
+    pub fn new(
+        #[builder(default = "age".to_string(), into)] age: String,
+        #[builder(default = "qx".to_string(), into)] qx: String,
+        #[builder(default = "lx".to_string(), into)] lx: String,
+        #[builder(default = "duration".to_string(), into)] duration: String,
+    ) -> Self {
+        Self {
+            age,
+            qx,
+            lx,
+            duration,
+        }
+    }
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl ColumnMapping {
+    /// Columns of `df` renamed to the `MortData` schema, pivoted tables in long layout.
+    pub(crate) fn apply(&self, df: &DataFrame) -> RSLifeResult<DataFrame> {
+        let find = |name: &str| {
+            df.columns()
+                .iter()
+                .find(|column| column.name().trim().eq_ignore_ascii_case(name.trim()))
+        };
+        let age = find(&self.age)
+            .ok_or_else(|| RSLifeError::Schema(format!("Column '{}' not found", self.age)))?
+            .clone()
+            .with_name("age".into());
+
+        // Long layout: one rate column, durations in a column of their own
+        let value = find(&self.qx)
+            .map(|column| column.clone().with_name("qx".into()))
+            .or_else(|| find(&self.lx).map(|column| column.clone().with_name("lx".into())));
+        if let Some(value) = value {
+            let mut columns = vec![age, value];
+            if let Some(duration) = find(&self.duration) {
+                columns.push(duration.clone().with_name("duration".into()));
+            }
+            return Ok(DataFrame::new(df.height(), columns)?);
+        }
+
+        // Pivoted layout: one rate column per duration
+        let mut columns = vec![age];
+        columns.extend(
+            df.columns()
+                .iter()
+                .filter(|column| duration_from_header(column.name()).is_some())
+                .cloned(),
+        );
+        if columns.len() == 1 {
+            return Err(RSLifeError::Schema(format!(
+                "No '{}', '{}' or duration columns found",
+                self.qx, self.lx
+            )));
+        }
+        unpivot(&DataFrame::new(df.height(), columns)?)
+    }
+}
+
+/// Duration of a pivoted column header such as "Duration 0", "Durations 2+" (case-insensitive)
+/// or a bare "0", "2+" as in the IFOA workbooks.
+pub(crate) fn duration_from_header(header: &str) -> Option<u32> {
+    let header = header.trim().to_lowercase();
+    let number = header
+        .strip_prefix("durations ")
+        .or_else(|| header.strip_prefix("duration "))
+        .unwrap_or(&header);
+    number.trim_end_matches('+').trim().parse().ok()
+}
+
+/// Column header of `duration` in the pivoted layout.
+pub(crate) fn duration_header(duration: u32, is_ultimate: bool) -> String {
+    if is_ultimate {
+        format!("Durations {duration}+")
+    } else {
+        format!("Duration {duration}")
+    }
+}
+
+/// Whether the column names are `age` followed by one column per duration.
+pub(crate) fn is_pivoted<S: AsRef<str>>(names: &[S]) -> bool {
+    match names.split_first() {
+        Some((first, rest)) => {
+            first.as_ref().trim().eq_ignore_ascii_case("age")
+                && !rest.is_empty()
+                && rest
+                    .iter()
+                    .all(|name| duration_from_header(name.as_ref()).is_some())
+        }
+        None => false,
+    }
+}
+
+/// Long layout (`age`, `qx`, `duration`) of a pivoted table. Empty cells are dropped.
+pub(crate) fn unpivot(df: &DataFrame) -> RSLifeResult<DataFrame> {
+    let columns = df.columns();
+    let Some((age_col, duration_cols)) = columns.split_first() else {
+        return Err(RSLifeError::Schema("Pivoted table has no columns".into()));
+    };
+
+    let ages = age_col.cast(&DataType::Float64)?;
+    let durations = duration_cols
+        .iter()
+        .map(|col| {
+            let duration = duration_from_header(col.name()).ok_or_else(|| {
+                RSLifeError::Schema(format!("Column '{}' is not a duration", col.name()))
+            })?;
+            Ok((duration, col.cast(&DataType::Float64)?))
+        })
+        .collect::<RSLifeResult<Vec<_>>>()?;
+
+    let mut age_vec: Vec<f64> = Vec::new();
+    let mut qx_vec: Vec<f64> = Vec::new();
+    let mut duration_vec: Vec<f64> = Vec::new();
+    for (row, age) in ages.f64()?.iter().enumerate() {
+        let Some(age) = age.filter(|age| !age.is_nan()) else {
+            continue;
+        };
+        for (duration, values) in &durations {
+            if let Some(qx) = values.f64()?.get(row).filter(|qx| !qx.is_nan()) {
+                age_vec.push(age);
+                qx_vec.push(qx);
+                duration_vec.push(*duration as f64);
+            }
+        }
+    }
+
+    let result = df! {
+        "age" => age_vec,
+        "qx" => qx_vec,
+        "duration" => duration_vec,
+    }?;
+    Ok(result)
+}
+
+/// Pivoted layout of a long select table: `age` then one `qx` column per duration.
+pub(crate) fn pivot(df: &DataFrame) -> RSLifeResult<DataFrame> {
+    let ages: Vec<u32> = df
+        .column("age")?
+        .cast(&DataType::UInt32)?
+        .u32()?
+        .into_no_null_iter()
+        .collect();
+    let durations: Vec<u32> = df
+        .column("duration")?
+        .cast(&DataType::UInt32)?
+        .u32()?
+        .into_no_null_iter()
+        .collect();
+    let qx = df.column("qx")?.cast(&DataType::Float64)?;
+    let qx = qx.f64()?;
+
+    let age_rows: Vec<u32> = ages
+        .iter()
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let (min_duration, max_duration) = match (durations.iter().min(), durations.iter().max()) {
+        (Some(&min), Some(&max)) => (min, max),
+        _ => return Err(RSLifeError::Schema("Select table has no durations".into())),
+    };
+    let n_durations = (max_duration - min_duration + 1) as usize;
+
+    // Cells by duration then age, missing combinations left empty
+    let mut cells: Vec<Vec<Option<f64>>> = vec![vec![None; age_rows.len()]; n_durations];
+    for ((age, duration), value) in ages.iter().zip(&durations).zip(qx.iter()) {
+        let row = age_rows.partition_point(|row_age| row_age < age);
+        cells[(duration - min_duration) as usize][row] = value;
+    }
+
+    let mut columns = vec![Series::new("age".into(), age_rows.clone()).into_column()];
+    for (offset, values) in cells.into_iter().enumerate() {
+        let duration = min_duration + offset as u32;
+        let header = duration_header(duration, duration == max_duration);
+        columns.push(Series::new(header.into(), values).into_column());
+    }
+
+    Ok(DataFrame::new(age_rows.len(), columns)?)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_duration_headers() {
+        assert_eq!(duration_from_header("Duration 0"), Some(0));
+        assert_eq!(duration_from_header("durations 2+"), Some(2));
+        assert_eq!(duration_from_header("qx"), None);
+        assert_eq!(duration_from_header("2"), Some(2));
+        assert_eq!(duration_from_header(" 2+ "), Some(2));
+        assert!(is_pivoted(&["age", "duration 0", "durations 1+"]));
+        assert!(is_pivoted(&["Age", "0", "1", "2"]));
+        assert!(!is_pivoted(&["age", "qx", "duration"]));
+        assert!(!is_pivoted(&["age"]));
+    }
+
+    #[test]
+    fn test_fn_column_mapping() {
+        let df = df! {
+            "Age" => [20.0, 21.0],
+            "q" => [0.1, 0.2],
+            "Dur" => [0.0, 1.0],
+            "Exposure" => [100.0, 90.0],
+        }
+        .unwrap();
+        let mapping = ColumnMapping::builder()
+            .age("AGE")
+            .qx("q")
+            .duration("Dur")
+            .build();
+        let mapped = mapping.apply(&df).unwrap();
+        let names: Vec<&str> = mapped
+            .get_column_names()
+            .iter()
+            .map(|n| n.as_str())
+            .collect();
+        assert_eq!(names, ["age", "qx", "duration"]);
+
+        // Default names do not match
+        assert!(ColumnMapping::default().apply(&df).is_err());
+
+        let wide = df! {
+            "Age" => [20.0, 21.0],
+            "Duration 0" => [0.1, 0.2],
+            "Durations 1+" => [0.15, 0.25],
+        }
+        .unwrap();
+        let mapped = ColumnMapping::default().apply(&wide).unwrap();
+        assert_eq!(mapped.height(), 4);
+
+        // IFOA header row: bare durations
+        let ifoa = df! {
+            "Age" => [20.0, 21.0],
+            "0" => [0.1, 0.2],
+            "1" => [0.15, 0.25],
+            "2" => [0.18, 0.28],
+        }
+        .unwrap();
+        let mapped = ColumnMapping::default().apply(&ifoa).unwrap();
+        assert_eq!(mapped.height(), 6);
+        let durations: Vec<f64> = mapped
+            .column("duration")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(durations, [0.0, 1.0, 2.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_fn_pivot_round_trip() {
+        let long = df! {
+            "age" => [20u32, 21, 21, 22],
+            "qx" => [0.1, 0.2, 0.25, 0.3],
+            "duration" => [0u32, 0, 1, 1],
+        }
+        .unwrap();
+
+        let wide = pivot(&long).unwrap();
+        let names: Vec<&str> = wide.get_column_names().iter().map(|n| n.as_str()).collect();
+        assert_eq!(names, ["age", "Duration 0", "Durations 1+"]);
+        assert_eq!(
+            wide.column("Durations 1+").unwrap().f64().unwrap().get(0),
+            None
+        );
+
+        let back = unpivot(&wide).unwrap();
+        assert_eq!(back.height(), 4);
+        let qx: Vec<f64> = back
+            .column("qx")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(qx, [0.1, 0.2, 0.25, 0.3]);
+    }
+}
@@ -0,0 +1,265 @@
+//! Precomputed, index-based lookups of `qx` and `lx`.
+//!
+//! Built once from the mortality table DataFrame so that survival functions read values
+//! by array index instead of filtering the DataFrame on every call.
+//!
+//! The lookup is built when a `MortTableConfig` is constructed and shared by its clones. It keeps
+//! the DataFrame it was built from, so a config whose `data.dataframe` is replaced or modified
+//! afterwards is never served values from its previous table.
+
+use polars::prelude::*;
+use std::fmt;
+use std::sync::Arc;
+
+/// Contiguous `qx` / `lx` arrays indexed by age (and duration for select tables).
+///
+/// Values are stored row-major by age: `index = (age - min_age) · n_durations + (duration - min_duration)`.
+/// Cells missing from the source table hold `NaN`.
+pub(crate) struct MortLookup {
+    /// DataFrame the arrays were built from. Holding it keeps its columns shared, so they can
+    /// be neither freed (and their address reused) nor mutated in place.
+    source: DataFrame,
+    min_age: u32,
+    max_age: u32,
+    durations: Option<(u32, u32)>,
+    qx: Vec<f64>,
+    lx: Vec<f64>,
+}
+
+impl MortLookup {
+    /// Build the lookup arrays from a DataFrame with `age`, `qx`, `lx` and optional `duration` columns.
+    ///
+    /// A missing `qx` or `lx` column (raw data before conversion) leaves that array as `NaN`.
+    /// Null ages or durations are an error: every row must land on its own cell.
+    pub(crate) fn from_dataframe(df: &DataFrame) -> PolarsResult<Self> {
+        let ages = non_null_u32_column(df, "age")?;
+        let min_age = ages.iter().copied().min().ok_or_else(no_age_error)?;
+        let max_age = ages.iter().copied().max().ok_or_else(no_age_error)?;
+
+        let has_duration = df.get_column_names().contains(&&"duration".into());
+        let durations: Option<Vec<u32>> = if has_duration {
+            Some(non_null_u32_column(df, "duration")?)
+        } else {
+            None
+        };
+        let duration_range = match &durations {
+            Some(d) => Some((
+                d.iter().copied().min().ok_or_else(no_duration_error)?,
+                d.iter().copied().max().ok_or_else(no_duration_error)?,
+            )),
+            None => None,
+        };
+
+        let mut lookup = Self {
+            source: df.clone(),
+            min_age,
+            max_age,
+            durations: duration_range,
+            qx: Vec::new(),
+            lx: Vec::new(),
+        };
+
+        let size = (max_age - min_age + 1) as usize * lookup.n_durations();
+        lookup.qx = vec![f64::NAN; size];
+        lookup.lx = vec![f64::NAN; size];
+
+        let qx_ca = optional_f64_column(df, "qx")?;
+        let lx_ca = optional_f64_column(df, "lx")?;
+        for (row, &age) in ages.iter().enumerate() {
+            let duration = durations.as_ref().map(|d| d[row]);
+            if let Some(index) = lookup.index(age, duration) {
+                if let Some(ca) = &qx_ca {
+                    lookup.qx[index] = ca.get(row).unwrap_or(f64::NAN);
+                }
+                if let Some(ca) = &lx_ca {
+                    lookup.lx[index] = ca.get(row).unwrap_or(f64::NAN);
+                }
+            }
+        }
+
+        Ok(lookup)
+    }
+
+    /// Whether the lookup was built from the very same column buffers as `df`.
+    pub(crate) fn is_built_from(&self, df: &DataFrame) -> bool {
+        let source = &self.source;
+        source.height() == df.height()
+            && source.width() == df.width()
+            && source.columns().iter().zip(df.columns()).all(|(a, b)| {
+                a.name() == b.name()
+                    && Arc::ptr_eq(&a.as_materialized_series().0, &b.as_materialized_series().0)
+            })
+    }
+
+    pub(crate) fn min_age(&self) -> u32 {
+        self.min_age
+    }
+
+    pub(crate) fn max_age(&self) -> u32 {
+        self.max_age
+    }
+
+    /// `(min_duration, max_duration)` for select tables, `None` for 1D tables.
+    pub(crate) fn durations(&self) -> Option<(u32, u32)> {
+        self.durations
+    }
+
+    /// qx at `age` and `duration` (ultimate duration when `None`).
+    pub(crate) fn qx(&self, age: u32, duration: Option<u32>) -> Option<f64> {
+        self.get(&self.qx, age, duration)
+    }
+
+    /// lx at `age` and `duration` (ultimate duration when `None`).
+    pub(crate) fn lx(&self, age: u32, duration: Option<u32>) -> Option<f64> {
+        self.get(&self.lx, age, duration)
+    }
+
+    fn get(&self, values: &[f64], age: u32, duration: Option<u32>) -> Option<f64> {
+        let index = self.index(age, duration)?;
+        let value = values[index];
+        (!value.is_nan()).then_some(value)
+    }
+
+    fn n_durations(&self) -> usize {
+        match self.durations {
+            Some((min_dur, max_dur)) => (max_dur - min_dur + 1) as usize,
+            None => 1,
+        }
+    }
+
+    fn index(&self, age: u32, duration: Option<u32>) -> Option<usize> {
+        if age < self.min_age || age > self.max_age {
+            return None;
+        }
+        let age_offset = (age - self.min_age) as usize * self.n_durations();
+        match self.durations {
+            None => Some(age_offset),
+            Some((min_dur, max_dur)) => {
+                let duration = duration.unwrap_or(max_dur);
+                if duration < min_dur || duration > max_dur {
+                    return None;
+                }
+                Some(age_offset + (duration - min_dur) as usize)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for MortLookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MortLookup")
+            .field("min_age", &self.min_age)
+            .field("max_age", &self.max_age)
+            .field("durations", &self.durations)
+            .finish_non_exhaustive()
+    }
+}
+
+fn non_null_u32_column(df: &DataFrame, name: &str) -> PolarsResult<Vec<u32>> {
+    let ca = df.column(name)?.u32()?;
+    if ca.null_count() > 0 {
+        return Err(PolarsError::ComputeError(
+            format!("{} column contains {} null values", name, ca.null_count()).into(),
+        ));
+    }
+    Ok(ca.into_no_null_iter().collect())
+}
+
+fn optional_f64_column(df: &DataFrame, name: &str) -> PolarsResult<Option<Float64Chunked>> {
+    if df.get_column_names().contains(&&name.into()) {
+        Ok(Some(df.column(name)?.f64()?.clone()))
+    } else {
+        Ok(None)
+    }
+}
+
+fn no_age_error() -> PolarsError {
+    PolarsError::ComputeError("No age data available".into())
+}
+
+fn no_duration_error() -> PolarsError {
+    PolarsError::ComputeError("No duration data available".into())
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::MortTableConfig;
+    use crate::mt_config::mt_data::MortData;
+
+    #[test]
+    fn test_lookup_matches_dataframe() {
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let df = &mt.data.dataframe;
+        let lookup = MortLookup::from_dataframe(df).unwrap();
+        let (_, max_dur) = lookup.durations().unwrap();
+
+        let filtered = df
+            .clone()
+            .lazy()
+            .filter(col("age").eq(lit(40u32)))
+            .collect()
+            .unwrap();
+        let durations = filtered.column("duration").unwrap().u32().unwrap();
+        let qx = filtered.column("qx").unwrap().f64().unwrap();
+        for row in 0..filtered.height() {
+            let duration = durations.get(row).unwrap();
+            assert_eq!(lookup.qx(40, Some(duration)), qx.get(row));
+            if duration == max_dur {
+                assert_eq!(lookup.qx(40, None), qx.get(row));
+            }
+        }
+
+        // Outside the table
+        assert_eq!(lookup.qx(lookup.max_age() + 1, None), None);
+        assert_eq!(lookup.lx(40, Some(max_dur + 1)), None);
+    }
+
+    #[test]
+    fn test_lookup_follows_replaced_dataframe() {
+        let elt15 = MortData::from_builtin("ELT15_M").expect("Failed to load ELT15_M");
+        let mut mt = MortTableConfig::builder().data(elt15).build().unwrap();
+        let qx_40 = mt.lookup().unwrap().qx(40, None).unwrap();
+        // Built with the config and shared by its clones
+        assert!(Arc::ptr_eq(
+            &mt.lookup().unwrap(),
+            &mt.clone().lookup().unwrap()
+        ));
+
+        // Same height, different values
+        let df = mt.data.dataframe.clone();
+        mt.data.dataframe = df
+            .lazy()
+            .with_column((col("qx") * lit(2.0)).alias("qx"))
+            .collect()
+            .unwrap();
+        assert_eq!(mt.lookup().unwrap().qx(40, None), Some(2.0 * qx_40));
+    }
+
+    #[test]
+    fn test_lookup_rejects_null_ages() {
+        let df = df! {
+            "age" => [Some(40u32), None, Some(42)],
+            "qx" => [0.01, 0.02, 0.03],
+        }
+        .unwrap();
+        let err = MortLookup::from_dataframe(&df).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("age column contains 1 null values")
+        );
+
+        let df = df! {
+            "age" => [40u32, 40, 41],
+            "duration" => [Some(0u32), None, Some(0)],
+            "qx" => [0.01, 0.02, 0.03],
+        }
+        .unwrap();
+        assert!(MortLookup::from_dataframe(&df).is_err());
+    }
+}
@@ -0,0 +1,852 @@
+//! # Multiple Decrement Tables
+//!
+//! A multiple decrement table holds several causes of decrement at each age, e.g. death,
+//! withdrawal and retirement for a pension scheme service table.
+//!
+//! The table is stored with dependent rates (aq)ₓ⁽ʲ⁾, from which the following are derived:
+//! - (aq)ₓ = Σⱼ (aq)ₓ⁽ʲ⁾ total rate of decrement
+//! - (al)ₓ₊₁ = (al)ₓ · (1 - (aq)ₓ) number in force
+//! - (ad)ₓ⁽ʲ⁾ = (al)ₓ · (aq)ₓ⁽ʲ⁾ number of decrements by cause j
+//!
+//! Dependent rates can be converted to and from independent rates qₓ'⁽ʲ⁾ under:
+//! - **UDD**: each decrement uniformly distributed in its associated single decrement table
+//! - **CFM**: constant force for each decrement over the year of age
+//!
+//! Tables are built from a DataFrame or loaded with `from_csv`, `from_parquet` or `from_xlsx`,
+//! and a single decrement can be passed on to `MortTableConfig` through `to_mort_data`.
+//!
+//! ## Usage Example
+//! ```rust
+//! # use rslife::prelude::*;
+//! use polars::prelude::*;
+//!
+//! let df = df! {
+//!     "age" => [60u32, 61, 62],
+//!     "death" => [0.010, 0.011, 0.012],
+//!     "retirement" => [0.100, 0.150, 1.0 - 0.012],
+//! }?;
+//! let mdt = MultiDecrementTable::builder().dataframe(df).build()?;
+//!
+//! let al_61 = mdt.al(61)?;
+//! let ad_61_death = mdt.ad(61, "death")?;
+//! let q_death = mdt.independent_rates(AssumptionEnum::UDD)?;
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::AssumptionEnum;
+use super::mt_data::MortData;
+use super::spreadsheet_helpers::{parse_excel_data, parse_excel_headers};
+use crate::error::invalid_parameter;
+use crate::{RSLifeError, RSLifeResult};
+use bon::bon;
+use calamine::{Reader, open_workbook_auto};
+use polars::prelude::*;
+use std::fs;
+
+// Column names reserved for the derived functions
+const RESERVED_COLUMNS: [&str; 3] = ["aq", "al", "duration"];
+
+// Iterations allowed for the UDD inversion from dependent to independent rates
+const UDD_MAX_ITERATIONS: usize = 200;
+
+// Ages, decrement names and one rate vector per decrement
+type MdData = (Vec<u32>, Vec<String>, Vec<Vec<f64>>);
+
+/// Multiple decrement table with named decrements.
+#[derive(Debug, Clone)]
+pub struct MultiDecrementTable {
+    /// Free text description of the table.
+    pub description: String,
+
+    /// Names of the decrements, in the order of the source columns.
+    pub decrements: Vec<String>,
+
+    /// Initial number in force (al) at the youngest age.
+    pub radix: u32,
+
+    /// Table with `age`, dependent rates per decrement, `aq`, `al` and `ad_<decrement>` columns.
+    pub dataframe: DataFrame,
+}
+
+#[bon]
+impl MultiDecrementTable {
+    /// Create a multiple decrement table from dependent rates.
+    ///
+    /// # Schema Requirements
+    /// - First column: "age" (u32 convertible, consecutive ages)
+    /// - One or more decrement columns with dependent rates (aq)ₓ⁽ʲ⁾ (f64, between 0 and 1)
+    /// - Total rate Σⱼ (aq)ₓ⁽ʲ⁾ must not exceed 1.0 at any age
+    ///
+    /// # Errors
+    /// - Invalid DataFrame schema or data values
+    /// - Decrement column named `aq`, `al` or `duration`
+    #[builder]
+    pub fn new(
+        dataframe: DataFrame,
+        #[builder(default = "Multiple Decrement Table".to_string())] description: String,
+        #[builder(default = 100_000)] radix: u32,
+    ) -> RSLifeResult<Self> {
+        let (ages, decrements, rates) = extract_md_data(&dataframe)?;
+
+        // Total rate of decrement cannot exceed 1.0
+        for (row, age) in ages.iter().enumerate() {
+            let total: f64 = rates.iter().map(|col| col[row]).sum();
+            if total > 1.0 + 1e-12 {
+                return Err(RSLifeError::Schema(format!(
+                    "Total dependent rate {total} at age {age} must not exceed 1.0"
+                )));
+            }
+        }
+
+        build_table(description, decrements, radix, ages, rates)
+    }
+
+    /// Create a multiple decrement table from independent rates qₓ'⁽ʲ⁾.
+    ///
+    /// The independent rates are converted to dependent rates under the given assumption.
+    /// The schema requirements are the same as [`MultiDecrementTable::new`] except that
+    /// each independent rate only needs to lie between 0 and 1.
+    ///
+    /// # Errors
+    /// - Invalid DataFrame schema or data values
+    /// - `AssumptionEnum::HPB` is not supported for decrement conversions
+    #[builder]
+    pub fn from_independent_rates(
+        dataframe: DataFrame,
+        #[builder(default = AssumptionEnum::UDD)] assumption: AssumptionEnum,
+        #[builder(default = "Multiple Decrement Table".to_string())] description: String,
+        #[builder(default = 100_000)] radix: u32,
+    ) -> RSLifeResult<Self> {
+        let (ages, decrements, independent) = extract_md_data(&dataframe)?;
+        let rates = convert_by_row(&independent, assumption, dependent_from_independent)?;
+        build_table(description, decrements, radix, ages, rates)
+    }
+
+    /// Load a multiple decrement table of dependent rates from a CSV file with a header row.
+    ///
+    /// The age column is matched by name (case-insensitive) and every other column is read
+    /// as a decrement, so a service table with `Age`, `death`, `withdrawal` and `retirement`
+    /// columns loads as is. The result feeds `MortTableConfig` through [`Self::to_mort_data`].
+    ///
+    /// # Errors
+    /// - File not found or not readable
+    /// - Invalid CSV format
+    /// - Schema validation errors (via `new`)
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// let mdt = MultiDecrementTable::from_csv("data/service_table.csv")?;
+    /// let death_only = mdt.to_mort_data("death", AssumptionEnum::UDD)?;
+    /// let mt = MortTableConfig::builder().data(death_only).build()?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn from_csv(csv_file_path_str: &str) -> RSLifeResult<Self> {
+        let df = CsvReadOptions::default()
+            .with_has_header(true)
+            .try_into_reader_with_file_path(Some(csv_file_path_str.into()))?
+            .finish()?;
+        Self::from_file_data(df, csv_file_path_str)
+    }
+
+    /// Load a multiple decrement table of dependent rates from a Parquet file.
+    ///
+    /// Columns are matched as in `from_csv()`.
+    pub fn from_parquet(parquet_file_path_str: &str) -> RSLifeResult<Self> {
+        let file = fs::File::open(parquet_file_path_str)?;
+        let df = ParquetReader::new(file).finish()?;
+        Self::from_file_data(df, parquet_file_path_str)
+    }
+
+    /// Load a multiple decrement table of dependent rates from an XLSX sheet.
+    ///
+    /// Headers are read in lowercase as in `MortData::from_xlsx()`, otherwise columns are
+    /// matched as in `from_csv()`.
+    pub fn from_xlsx(xlsx_file_path_str: &str, sheet_name: &str) -> RSLifeResult<Self> {
+        let mut workbook = open_workbook_auto(xlsx_file_path_str).map_err(|e| {
+            RSLifeError::Parse(format!(
+                "Failed to open XLSX file '{xlsx_file_path_str}': {e}"
+            ))
+        })?;
+        let range = workbook
+            .worksheet_range(sheet_name)
+            .map_err(|e| RSLifeError::Parse(format!("Failed to read sheet '{sheet_name}': {e}")))?;
+        if range.is_empty() {
+            return Err(RSLifeError::Parse(format!("Sheet '{sheet_name}' is empty")));
+        }
+
+        let headers = parse_excel_headers(&range, 0)?;
+        let data_cols = parse_excel_data(&range, 1, headers.len())?;
+        let height = data_cols.first().map_or(0, |col| col.len());
+        let columns = headers
+            .iter()
+            .zip(data_cols)
+            .map(|(name, values)| Column::new(name.as_str().into(), values))
+            .collect();
+        Self::from_file_data(DataFrame::new(height, columns)?, xlsx_file_path_str)
+    }
+
+    pub fn min_age(&self) -> PolarsResult<u32> {
+        self.ages()?
+            .into_iter()
+            .min()
+            .ok_or_else(|| PolarsError::ComputeError("No age data available".into()))
+    }
+
+    pub fn max_age(&self) -> PolarsResult<u32> {
+        self.ages()?
+            .into_iter()
+            .max()
+            .ok_or_else(|| PolarsError::ComputeError("No age data available".into()))
+    }
+
+    /// Number in force at age x: (al)ₓ
+    pub fn al(&self, x: u32) -> RSLifeResult<f64> {
+        self.get_value(x, "al")
+    }
+
+    /// Number of decrements by cause j at age x: (ad)ₓ⁽ʲ⁾
+    pub fn ad(&self, x: u32, decrement: &str) -> RSLifeResult<f64> {
+        self.check_decrement(decrement)?;
+        self.get_value(x, &format!("ad_{decrement}"))
+    }
+
+    /// Dependent rate of decrement by cause j at age x: (aq)ₓ⁽ʲ⁾
+    pub fn aq(&self, x: u32, decrement: &str) -> RSLifeResult<f64> {
+        self.check_decrement(decrement)?;
+        self.get_value(x, decrement)
+    }
+
+    /// Total dependent rate of decrement at age x: (aq)ₓ
+    pub fn aq_total(&self, x: u32) -> RSLifeResult<f64> {
+        self.get_value(x, "aq")
+    }
+
+    /// Probability of remaining in force for one year at age x: (ap)ₓ = 1 - (aq)ₓ
+    pub fn ap(&self, x: u32) -> RSLifeResult<f64> {
+        Ok(1.0 - self.aq_total(x)?)
+    }
+
+    /// Independent rates qₓ'⁽ʲ⁾ implied by the dependent rates under the given assumption.
+    ///
+    /// Returns a DataFrame with `age` and one column per decrement.
+    ///
+    /// # Formula
+    /// ```text
+    /// CFM: qₓ'⁽ʲ⁾ = 1 - (ap)ₓ^((aq)ₓ⁽ʲ⁾ / (aq)ₓ)
+    /// UDD: (aq)ₓ⁽ʲ⁾ = qₓ'⁽ʲ⁾ · ∫₀¹ Πₖ≠ⱼ (1 - t·qₓ'⁽ᵏ⁾) dt, solved for qₓ'⁽ʲ⁾
+    /// ```
+    pub fn independent_rates(&self, assumption: AssumptionEnum) -> RSLifeResult<DataFrame> {
+        let ages = self.ages()?;
+        let rates = self
+            .decrements
+            .iter()
+            .map(|name| column_as_f64(&self.dataframe, name))
+            .collect::<RSLifeResult<Vec<_>>>()?;
+        let independent = convert_by_row(&rates, assumption, independent_from_dependent)?;
+
+        let height = ages.len();
+        let mut columns = vec![Column::new("age".into(), ages)];
+        for (name, values) in self.decrements.iter().zip(independent) {
+            columns.push(Column::new(name.as_str().into(), values));
+        }
+        Ok(DataFrame::new(height, columns)?)
+    }
+
+    /// Single decrement table of independent rates for one decrement.
+    ///
+    /// The result can be passed to `MortTableConfig` to use the single life functions
+    /// on one cause of decrement in isolation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use rslife::prelude::*;
+    /// # use polars::prelude::*;
+    /// # let df = df! {
+    /// #     "age" => [60u32, 61, 62],
+    /// #     "death" => [0.010, 0.011, 0.012],
+    /// #     "retirement" => [0.100, 0.150, 0.988],
+    /// # }?;
+    /// # let mdt = MultiDecrementTable::builder().dataframe(df).build()?;
+    /// let death_only = mdt.to_mort_data("death", AssumptionEnum::UDD)?;
+    /// let mt = MortTableConfig::builder().data(death_only).build()?;
+    /// let p = tpx().mt(&mt).x(60.0).t(2.0).call()?;
+    /// # RSLifeResult::Ok(())
+    /// ```
+    pub fn to_mort_data(
+        &self,
+        decrement: &str,
+        assumption: AssumptionEnum,
+    ) -> RSLifeResult<MortData> {
+        self.check_decrement(decrement)?;
+        let independent = self.independent_rates(assumption)?;
+        let df = df! {
+            "age" => self.ages()?,
+            "qx" => column_as_f64(&independent, decrement)?,
+        }?;
+        MortData::new(
+            "Multiple Decrement Table".to_string(),
+            format!("{} - independent {decrement} rates", self.description),
+            df,
+        )
+    }
+
+    /// Move the age column of a table read from file first, under the name `age`.
+    fn from_file_data(df: DataFrame, file_path: &str) -> RSLifeResult<Self> {
+        let is_age = |column: &Column| column.name().trim().eq_ignore_ascii_case("age");
+        let age = df
+            .columns()
+            .iter()
+            .find(|column| is_age(column))
+            .ok_or_else(|| RSLifeError::Schema("Column 'age' not found".into()))?
+            .clone()
+            .with_name("age".into());
+        let mut columns = vec![age];
+        columns.extend(
+            df.columns()
+                .iter()
+                .filter(|column| !is_age(column))
+                .map(|column| {
+                    let name = column.name().trim().to_string();
+                    column.clone().with_name(name.into())
+                }),
+        );
+
+        Self::builder()
+            .dataframe(DataFrame::new(df.height(), columns)?)
+            .description(format!("Created from file {file_path}."))
+            .build()
+    }
+
+    fn ages(&self) -> PolarsResult<Vec<u32>> {
+        Ok(self
+            .dataframe
+            .column("age")?
+            .u32()?
+            .iter()
+            .flatten()
+            .collect())
+    }
+
+    fn check_decrement(&self, decrement: &str) -> RSLifeResult<()> {
+        if !self.decrements.iter().any(|name| name == decrement) {
+            return Err(RSLifeError::Schema(format!(
+                "Decrement '{decrement}' not found, available decrements: {:?}",
+                self.decrements
+            )));
+        }
+        Ok(())
+    }
+
+    /// Value of `col_name` at age x, read by position since ages are consecutive.
+    fn get_value(&self, x: u32, col_name: &str) -> RSLifeResult<f64> {
+        let ages = self.dataframe.column("age")?.u32()?;
+        let (Some(min_age), Some(max_age)) = (ages.first(), ages.last()) else {
+            return Err(RSLifeError::Schema(
+                "Multiple decrement table has no ages".into(),
+            ));
+        };
+        if x < min_age || x > max_age {
+            return Err(RSLifeError::AgeOutOfRange {
+                age: x as f64,
+                min_age: min_age as f64,
+                max_age: max_age as f64,
+            });
+        }
+
+        let row = (x - min_age) as usize;
+        if ages.get(row) != Some(x) {
+            return Err(RSLifeError::Schema(
+                "Column 'age' must contain consecutive ages in ascending order".into(),
+            ));
+        }
+        self.dataframe
+            .column(col_name)?
+            .f64()?
+            .get(row)
+            .ok_or_else(|| {
+                RSLifeError::Schema(format!("Column '{col_name}' has no value at age {x}"))
+            })
+    }
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// Read ages, decrement names and rates from the source DataFrame with validation.
+fn extract_md_data(df: &DataFrame) -> RSLifeResult<MdData> {
+    if df.height() == 0 {
+        return Err(RSLifeError::Schema(
+            "DataFrame must contain at least one row of data".into(),
+        ));
+    }
+
+    let col_names = df.get_column_names();
+    if col_names.len() < 2 || col_names[0] != "age" {
+        return Err(RSLifeError::Schema(
+            "DataFrame columns must be ['age', <decrement>, ...]".into(),
+        ));
+    }
+
+    // Ages must be whole numbers in consecutive order
+    let ages: Vec<u32> = df
+        .column("age")?
+        .cast(&DataType::UInt32)
+        .map_err(|_| RSLifeError::Schema("Column 'age' must be u32 convertible".into()))?
+        .u32()?
+        .iter()
+        .map(|age| {
+            age.ok_or_else(|| {
+                RSLifeError::Schema("Column 'age' must not contain null values".into())
+            })
+        })
+        .collect::<RSLifeResult<_>>()?;
+    if ages.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+        return Err(RSLifeError::Schema(
+            "Column 'age' must contain consecutive ages in ascending order".into(),
+        ));
+    }
+
+    let mut decrements = Vec::new();
+    let mut rates = Vec::new();
+    for name in col_names.iter().skip(1) {
+        let name = name.as_str();
+        if RESERVED_COLUMNS.contains(&name) || name.starts_with("ad_") {
+            return Err(RSLifeError::Schema(format!(
+                "Decrement name '{name}' is reserved"
+            )));
+        }
+
+        let values = column_as_f64(df, name)?;
+        if values.iter().any(|&q| !(0.0..=1.0).contains(&q)) {
+            return Err(RSLifeError::Schema(format!(
+                "Column '{name}' must be between 0.0 and 1.0"
+            )));
+        }
+
+        decrements.push(name.to_string());
+        rates.push(values);
+    }
+
+    Ok((ages, decrements, rates))
+}
+
+fn column_as_f64(df: &DataFrame, name: &str) -> RSLifeResult<Vec<f64>> {
+    let values = df
+        .column(name)?
+        .cast(&DataType::Float64)
+        .map_err(|_| RSLifeError::Schema(format!("Column '{name}' must be f64 convertible")))?
+        .f64()?
+        .iter()
+        .map(|q| {
+            q.ok_or_else(|| {
+                RSLifeError::Schema(format!("Column '{name}' must not contain null values"))
+            })
+        })
+        .collect::<RSLifeResult<Vec<f64>>>()?;
+    Ok(values)
+}
+
+/// Build the derived (aq), (al) and (ad) columns from dependent rates.
+fn build_table(
+    description: String,
+    decrements: Vec<String>,
+    radix: u32,
+    ages: Vec<u32>,
+    rates: Vec<Vec<f64>>,
+) -> RSLifeResult<MultiDecrementTable> {
+    if radix == 0 {
+        return Err(invalid_parameter("radix", "radix must be at least 1"));
+    }
+
+    // (aq)ₓ = Σⱼ (aq)ₓ⁽ʲ⁾
+    let aq: Vec<f64> = (0..ages.len())
+        .map(|row| rates.iter().map(|col| col[row]).sum::<f64>().min(1.0))
+        .collect();
+
+    // (al)ₓ₊₁ = (al)ₓ · (1 - (aq)ₓ)
+    let mut al = Vec::with_capacity(ages.len());
+    let mut current = radix as f64;
+    for q in &aq {
+        al.push(current);
+        current *= 1.0 - q;
+    }
+
+    let height = ages.len();
+    let mut columns = vec![Column::new("age".into(), ages)];
+    for (name, values) in decrements.iter().zip(&rates) {
+        columns.push(Column::new(name.as_str().into(), values.clone()));
+    }
+    columns.push(Column::new("aq".into(), aq));
+    // (ad)ₓ⁽ʲ⁾ = (al)ₓ · (aq)ₓ⁽ʲ⁾
+    for (name, values) in decrements.iter().zip(&rates) {
+        let ad: Vec<f64> = values.iter().zip(&al).map(|(q, l)| q * l).collect();
+        columns.push(Column::new(format!("ad_{name}").into(), ad));
+    }
+    columns.push(Column::new("al".into(), al));
+
+    Ok(MultiDecrementTable {
+        description,
+        decrements,
+        radix,
+        dataframe: DataFrame::new(height, columns)?,
+    })
+}
+
+/// Apply a per-age conversion across all decrement columns.
+fn convert_by_row(
+    rates: &[Vec<f64>],
+    assumption: AssumptionEnum,
+    convert: fn(&[f64], AssumptionEnum) -> RSLifeResult<Vec<f64>>,
+) -> RSLifeResult<Vec<Vec<f64>>> {
+    let rows = rates.first().map_or(0, |col| col.len());
+    let mut result = vec![Vec::with_capacity(rows); rates.len()];
+    for row in 0..rows {
+        let input: Vec<f64> = rates.iter().map(|col| col[row]).collect();
+        for (j, value) in convert(&input, assumption)?.into_iter().enumerate() {
+            result[j].push(value);
+        }
+    }
+    Ok(result)
+}
+
+/// Dependent rates (aq)⁽ʲ⁾ from independent rates q'⁽ʲ⁾ at a single age.
+fn dependent_from_independent(
+    independent: &[f64],
+    assumption: AssumptionEnum,
+) -> RSLifeResult<Vec<f64>> {
+    match assumption {
+        // (aq)⁽ʲ⁾ = q'⁽ʲ⁾ · ∫₀¹ Πₖ≠ⱼ (1 - t·q'⁽ᵏ⁾) dt
+        AssumptionEnum::UDD => Ok((0..independent.len())
+            .map(|j| independent[j] * integral_of_other_survivals(independent, j))
+            .collect()),
+
+        // (aq)⁽ʲ⁾ = μ⁽ʲ⁾/μ · (1 - (ap)), with μ⁽ʲ⁾ = -ln(1 - q'⁽ʲ⁾)
+        AssumptionEnum::CFM => {
+            let certain = independent.iter().filter(|&&q| q >= 1.0).count();
+            if certain > 0 {
+                // Infinite forces take the whole population, shared equally
+                return Ok(independent
+                    .iter()
+                    .map(|&q| if q >= 1.0 { 1.0 / certain as f64 } else { 0.0 })
+                    .collect());
+            }
+            let forces: Vec<f64> = independent.iter().map(|&q| -(1.0 - q).ln()).collect();
+            let total_force: f64 = forces.iter().sum();
+            if total_force == 0.0 {
+                return Ok(vec![0.0; independent.len()]);
+            }
+            let ap: f64 = independent.iter().map(|&q| 1.0 - q).product();
+            Ok(forces
+                .iter()
+                .map(|mu| mu / total_force * (1.0 - ap))
+                .collect())
+        }
+
+        AssumptionEnum::HPB => {
+            Err("HPB assumption is not supported for multiple decrement conversions".into())
+        }
+    }
+}
+
+/// Independent rates q'⁽ʲ⁾ from dependent rates (aq)⁽ʲ⁾ at a single age.
+fn independent_from_dependent(
+    dependent: &[f64],
+    assumption: AssumptionEnum,
+) -> RSLifeResult<Vec<f64>> {
+    match assumption {
+        // Fixed point of (aq)⁽ʲ⁾ = q'⁽ʲ⁾ · ∫₀¹ Πₖ≠ⱼ (1 - t·q'⁽ᵏ⁾) dt
+        AssumptionEnum::UDD => {
+            let mut independent = dependent.to_vec();
+            for _ in 0..UDD_MAX_ITERATIONS {
+                let next: Vec<f64> = (0..dependent.len())
+                    .map(|j| {
+                        let integral = integral_of_other_survivals(&independent, j);
+                        (dependent[j] / integral).min(1.0)
+                    })
+                    .collect();
+                let change = next
+                    .iter()
+                    .zip(&independent)
+                    .map(|(a, b)| (a - b).abs())
+                    .fold(0.0, f64::max);
+                independent = next;
+                if change < 1e-14 {
+                    return Ok(independent);
+                }
+            }
+            Err(format!(
+                "Independent rates for dependent rates {dependent:?} did not converge within \
+                 {UDD_MAX_ITERATIONS} iterations"
+            )
+            .into())
+        }
+
+        // q'⁽ʲ⁾ = 1 - (ap)^((aq)⁽ʲ⁾ / (aq))
+        AssumptionEnum::CFM => {
+            let total: f64 = dependent.iter().sum();
+            let ap = (1.0 - total).max(0.0);
+            Ok(dependent
+                .iter()
+                .map(|&q| match (q > 0.0, ap > 0.0) {
+                    (false, _) => 0.0,
+                    (true, false) => 1.0,
+                    (true, true) => 1.0 - ap.powf(q / total),
+                })
+                .collect())
+        }
+
+        AssumptionEnum::HPB => {
+            Err("HPB assumption is not supported for multiple decrement conversions".into())
+        }
+    }
+}
+
+/// ∫₀¹ Πₖ≠ⱼ (1 - t·q'⁽ᵏ⁾) dt, computed exactly by expanding the polynomial in t.
+fn integral_of_other_survivals(independent: &[f64], j: usize) -> f64 {
+    let mut coefficients = vec![1.0];
+    for (k, &q) in independent.iter().enumerate() {
+        if k == j {
+            continue;
+        }
+        // Multiply the polynomial by (1 - q·t)
+        let mut next = vec![0.0; coefficients.len() + 1];
+        for (power, c) in coefficients.iter().enumerate() {
+            next[power] += c;
+            next[power + 1] -= c * q;
+        }
+        coefficients = next;
+    }
+    coefficients
+        .iter()
+        .enumerate()
+        .map(|(power, c)| c / (power as f64 + 1.0))
+        .sum()
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    fn service_table() -> DataFrame {
+        df! {
+            "age" => [60u32, 61, 62],
+            "death" => [0.010, 0.011, 0.012],
+            "withdrawal" => [0.050, 0.040, 0.0],
+            "retirement" => [0.100, 0.150, 0.988],
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_al_ad_derivation() {
+        let mdt = MultiDecrementTable::builder()
+            .dataframe(service_table())
+            .radix(10_000)
+            .build()
+            .unwrap();
+
+        assert_eq!(mdt.decrements, vec!["death", "withdrawal", "retirement"]);
+        assert_abs_diff_eq!(mdt.al(60).unwrap(), 10_000.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(mdt.aq_total(60).unwrap(), 0.16, epsilon = 1e-12);
+        assert_abs_diff_eq!(mdt.al(61).unwrap(), 8_400.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(mdt.ad(61, "death").unwrap(), 92.4, epsilon = 1e-9);
+        assert_abs_diff_eq!(mdt.ad(61, "retirement").unwrap(), 1_260.0, epsilon = 1e-9);
+
+        // (al)ₓ₊₁ = (al)ₓ - Σⱼ (ad)ₓ⁽ʲ⁾
+        let total_ad: f64 = mdt
+            .decrements
+            .iter()
+            .map(|name| mdt.ad(61, name).unwrap())
+            .sum();
+        assert_abs_diff_eq!(mdt.al(62).unwrap(), 8_400.0 - total_ad, epsilon = 1e-9);
+
+        assert!(matches!(
+            mdt.ad(61, "disability"),
+            Err(RSLifeError::Schema(_))
+        ));
+        assert!(matches!(
+            mdt.al(63),
+            Err(RSLifeError::AgeOutOfRange { min_age, max_age, .. })
+                if min_age == 60.0 && max_age == 62.0
+        ));
+        assert!(matches!(
+            mdt.aq(59, "death"),
+            Err(RSLifeError::AgeOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_udd_inversion_not_converged() {
+        // Both independent rates tend to 1.0, too slowly for the fixed point iteration
+        let df = df! {
+            "age" => [60u32],
+            "death" => [0.5],
+            "retirement" => [0.5],
+        }
+        .unwrap();
+        let mdt = MultiDecrementTable::builder()
+            .dataframe(df)
+            .build()
+            .unwrap();
+        assert!(mdt.independent_rates(AssumptionEnum::UDD).is_err());
+
+        // A certain decrement converges once clamped at 1.0
+        let mdt = MultiDecrementTable::builder()
+            .dataframe(service_table())
+            .build()
+            .unwrap();
+        let independent = mdt.independent_rates(AssumptionEnum::UDD).unwrap();
+        let retirement = independent.column("retirement").unwrap().f64().unwrap();
+        assert_abs_diff_eq!(retirement.get(2).unwrap(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let dir = std::env::temp_dir().join(format!("rslife_md_csv_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("service.csv");
+        std::fs::write(
+            &path,
+            "Age,death,withdrawal,retirement\n\
+             60,0.010,0.050,0.100\n\
+             61,0.011,0.040,0.150\n\
+             62,0.012,0.0,0.988\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let mdt = MultiDecrementTable::from_csv(path).unwrap();
+        assert_eq!(mdt.decrements, vec!["death", "withdrawal", "retirement"]);
+        assert_abs_diff_eq!(mdt.al(61).unwrap(), 84_000.0, epsilon = 1e-9);
+
+        // One decrement in isolation through MortTableConfig
+        let death_only = mdt.to_mort_data("death", AssumptionEnum::UDD).unwrap();
+        let mt = crate::mt_config::MortTableConfig::builder()
+            .data(death_only)
+            .build()
+            .unwrap();
+        let p = crate::prelude::tpx().mt(&mt).x(60.0).t(1.0).call().unwrap();
+        let independent = mdt.independent_rates(AssumptionEnum::UDD).unwrap();
+        let q_death = independent.column("death").unwrap().f64().unwrap().get(0);
+        assert_abs_diff_eq!(p, 1.0 - q_death.unwrap(), epsilon = 1e-12);
+
+        // MortData rejects the extra decrement columns
+        assert!(
+            crate::mt_config::mt_data::MortData::new(
+                "Service".to_string(),
+                String::new(),
+                mdt.dataframe.clone()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_udd_two_decrements_closed_form() {
+        // (aq)⁽¹⁾ = q'⁽¹⁾ · (1 - ½q'⁽²⁾)
+        let df = df! {
+            "age" => [40u32],
+            "death" => [0.02],
+            "withdrawal" => [0.10],
+        }
+        .unwrap();
+        let mdt = MultiDecrementTable::from_independent_rates()
+            .dataframe(df)
+            .assumption(AssumptionEnum::UDD)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(mdt.aq(40, "death").unwrap(), 0.02 * 0.95, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            mdt.aq(40, "withdrawal").unwrap(),
+            0.10 * 0.99,
+            epsilon = 1e-12
+        );
+
+        // Round trip back to the independent rates
+        let independent = mdt.independent_rates(AssumptionEnum::UDD).unwrap();
+        let death = independent.column("death").unwrap().f64().unwrap().get(0);
+        assert_abs_diff_eq!(death.unwrap(), 0.02, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cfm_round_trip() {
+        let mdt = MultiDecrementTable::builder()
+            .dataframe(service_table())
+            .build()
+            .unwrap();
+        let independent = mdt.independent_rates(AssumptionEnum::CFM).unwrap();
+
+        // (ap)ₓ = Πⱼ (1 - qₓ'⁽ʲ⁾)
+        let ap: f64 = mdt
+            .decrements
+            .iter()
+            .map(|name| {
+                1.0 - independent
+                    .column(name)
+                    .unwrap()
+                    .f64()
+                    .unwrap()
+                    .get(0)
+                    .unwrap()
+            })
+            .product();
+        assert_abs_diff_eq!(ap, mdt.ap(60).unwrap(), epsilon = 1e-12);
+
+        let rebuilt = MultiDecrementTable::from_independent_rates()
+            .dataframe(independent)
+            .assumption(AssumptionEnum::CFM)
+            .call()
+            .unwrap();
+        for name in &mdt.decrements {
+            assert_abs_diff_eq!(
+                rebuilt.aq(61, name).unwrap(),
+                mdt.aq(61, name).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        // Total dependent rate above 1.0
+        let df = df! {
+            "age" => [60u32],
+            "death" => [0.6],
+            "retirement" => [0.5],
+        }
+        .unwrap();
+        assert!(
+            MultiDecrementTable::builder()
+                .dataframe(df)
+                .build()
+                .is_err()
+        );
+
+        // Reserved decrement name
+        let df = df! {
+            "age" => [60u32],
+            "al" => [0.1],
+        }
+        .unwrap();
+        assert!(
+            MultiDecrementTable::builder()
+                .dataframe(df)
+                .build()
+                .is_err()
+        );
+
+        // HPB is not supported for conversions
+        let mdt = MultiDecrementTable::builder()
+            .dataframe(service_table())
+            .build()
+            .unwrap();
+        assert!(mdt.independent_rates(AssumptionEnum::HPB).is_err());
+    }
+}
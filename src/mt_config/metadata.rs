@@ -0,0 +1,166 @@
+//! # Table Metadata
+//!
+//! Provenance of a [`MortData`](crate::mt_config::mt_data::MortData) table for audit trails:
+//! the loader and location it came from, the publisher's identity and classification of the
+//! table, when it was loaded and a hash of its content.
+//!
+//! ```rust
+//! # use rslife::prelude::*;
+//! let mort_data = MortData::from_builtin("ELT15_F")?;
+//! let metadata = &mort_data.metadata;
+//!
+//! // Builtin copy of SOA table 1704
+//! assert_eq!(metadata.source, TableSourceEnum::Builtin);
+//! assert_eq!(metadata.table_identity.as_deref(), Some("1704"));
+//! assert_eq!(metadata.nation.as_deref(), Some("United Kingdom"));
+//! println!("Content hash: {}", metadata.content_hash_hex());
+//! # RSLifeResult::Ok(())
+//! ```
+
+use super::mt_data::MortalityLawEnum;
+use polars::prelude::*;
+use std::time::SystemTime;
+
+/// Loader a mortality table was created by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSourceEnum {
+    /// Preloaded builtin table (`MortData::from_builtin`)
+    Builtin,
+    /// SOA XTbML document (string, file or URL)
+    Soa,
+    /// IFOA XLS workbook (embedded, file or URL)
+    Ifoa,
+    /// Australian Government Actuary workbook
+    Aga,
+    /// ODS or XLSX spreadsheet
+    Spreadsheet,
+    /// CSV file
+    Csv,
+    /// Parquet file
+    Parquet,
+    /// Parametric mortality law
+    ParametricLaw,
+    /// DataFrame supplied by the caller
+    DataFrame,
+}
+
+/// Structured provenance of a mortality table.
+///
+/// Fields the source does not provide are `None` (or empty for `key_words`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableMetadata {
+    /// Loader the table was created by
+    pub source: TableSourceEnum,
+    /// URL or file path the table was read from
+    pub location: Option<String>,
+    /// Publisher's identifier (SOA table identity, IFOA table id, builtin id, law name)
+    pub table_identity: Option<String>,
+    /// Organisation publishing the table
+    pub provider: Option<String>,
+    /// Country or region the table applies to
+    pub nation: Option<String>,
+    /// Publisher's classification of the table content (e.g. "Population Mortality")
+    pub content_type: Option<String>,
+    /// Publisher's keywords
+    pub key_words: Vec<String>,
+    /// Parametric law the table was generated from, kept so that exact quantities such as
+    /// μₓ can be recovered
+    pub law: Option<MortalityLawEnum>,
+    /// Time the table was loaded
+    pub loaded_at: SystemTime,
+    /// FNV-1a hash of the table content as loaded (see [`content_hash`])
+    pub content_hash: u64,
+}
+
+impl TableMetadata {
+    /// Metadata of a table created from `source`, stamped with the current time and the hash of `df`.
+    pub(crate) fn new(source: TableSourceEnum, df: &DataFrame) -> PolarsResult<Self> {
+        let result = Self {
+            source,
+            location: None,
+            table_identity: None,
+            provider: None,
+            nation: None,
+            content_type: None,
+            key_words: Vec::new(),
+            law: None,
+            loaded_at: SystemTime::now(),
+            content_hash: content_hash(df)?,
+        };
+        Ok(result)
+    }
+
+    /// Content hash as 16 hexadecimal digits.
+    pub fn content_hash_hex(&self) -> String {
+        format!("{:016x}", self.content_hash)
+    }
+}
+
+/// FNV-1a (64-bit) hash of the column names and values of a table.
+///
+/// Values are hashed as `f64` so that the hash depends on the content only, and is stable
+/// across platforms and releases. Null cells hash differently from any value.
+pub fn content_hash(df: &DataFrame) -> PolarsResult<u64> {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+
+    for column in df.columns() {
+        write(column.name().as_bytes());
+        write(&[0]);
+        let values = column.cast(&DataType::Float64)?;
+        for value in values.f64()?.iter() {
+            match value {
+                Some(value) => {
+                    write(&[1]);
+                    write(&value.to_bits().to_le_bytes());
+                }
+                None => write(&[0]),
+            }
+        }
+    }
+
+    Ok(hash)
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_content_hash() {
+        let df = df! {
+            "age" => [20u32, 21, 22],
+            "qx" => [0.001, 0.002, 0.003],
+        }
+        .unwrap();
+        let same = df! {
+            "age" => [20.0, 21.0, 22.0],
+            "qx" => [0.001, 0.002, 0.003],
+        }
+        .unwrap();
+        let other = df! {
+            "age" => [20u32, 21, 22],
+            "qx" => [0.001, 0.002, 0.0031],
+        }
+        .unwrap();
+
+        // Content only, independent of the numeric type of the columns
+        assert_eq!(content_hash(&df).unwrap(), content_hash(&same).unwrap());
+        assert_ne!(content_hash(&df).unwrap(), content_hash(&other).unwrap());
+
+        let metadata = TableMetadata::new(TableSourceEnum::DataFrame, &df).unwrap();
+        assert_eq!(metadata.content_hash_hex().len(), 16);
+    }
+}
@@ -28,6 +28,12 @@
 //!
 //! ## See Also
 //! - [`crate::mt_config::soa_xml`] for XML parsing and table structure
+//! - [`crate::mt_config::fetch`] for offline, mirrored and cached downloads
+//! - [`crate::mt_config::metadata`] for table provenance (source, identity, content hash)
+//! - [`crate::mt_config::layout`] for column mapping and pivoted select layouts of CSV and Parquet files
+//! - `MortData::to_soa_xml`, `to_xlsx`, `to_ods`, `to_csv` and `to_parquet` for writing tables back out
+//! - [`crate::mt_config::improvement`] for improvement scales, period and generational tables
+//! - [`crate::mt_config::md_table`] for multiple decrement (service) tables
 //! - [`crate::single_life::benefits`] for insurance functions
 //! - [`crate::single_life::annuities`] for annuity functions
 //! - [`crate::fractional`] for fractional period calculations
@@ -38,17 +44,26 @@
 // Create a structure for the module
 mod aga_xls;
 mod builtin;
+mod export;
+pub mod fetch;
 mod ifoa_xls;
+pub mod improvement;
+pub mod layout;
+pub(crate) mod lookup;
+pub mod md_table;
+pub mod metadata;
 pub mod mt_data;
 mod soa_xml;
 mod spreadsheet_helpers;
 
 // Declare the module for MortData
+use self::lookup::MortLookup;
 use self::mt_data::MortData;
 use crate::RSLifeResult;
 use bon::bon;
 use garde::Validate;
 use polars::prelude::*;
+use std::sync::Arc;
 
 // ===============================================
 // MORTALITY ASSUMPTIONS
@@ -96,11 +111,16 @@ pub struct MortTableConfig {
     pub radix: u32,
 
     /// Mortality rate multiplier. Examples: 1.0 (standard), 0.75 (preferred), 0.5 (reduced).
+    /// Applied to qₓ (capped at 1.0) before lₓ is rebuilt from the radix.
     #[garde(custom(validate_pct))]
     pub pct: f64,
 
     /// Mortality assumption for fractional ages (reserved for future implementation).
     pub assumption: AssumptionEnum,
+
+    /// qx/lx lookup of `data.dataframe`, built with the config and shared by its clones.
+    #[garde(skip)]
+    lookup: Arc<MortLookup>,
 }
 
 /// Custom validation function for pct field
@@ -123,17 +143,17 @@ impl MortTableConfig {
         #[builder(default = AssumptionEnum::UDD)] assumption: AssumptionEnum,
     ) -> RSLifeResult<Self> {
         // Temporarily allow unvalidated data
+        let lookup = Arc::new(MortLookup::from_dataframe(&data.dataframe)?);
         let config = MortTableConfig {
             data,
             radix,
             pct,
             assumption,
+            lookup,
         };
 
         // Validate the configuration
-        config
-            .validate()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        config.validate()?;
 
         // MortData contains raw data which is usually contains only lx or qx.
         // Convert data to include both lx and qx for future calculations
@@ -142,25 +162,11 @@ impl MortTableConfig {
     }
 
     pub fn min_age(&self) -> PolarsResult<u32> {
-        // Get the minimum age from the dataframe
-        let age_column = self.data.dataframe.column("age")?;
-        let age_series = age_column.u32()?;
-        age_series
-            .iter()
-            .flatten()
-            .min()
-            .ok_or_else(|| PolarsError::ComputeError("No age data available".into()))
+        Ok(self.lookup()?.min_age())
     }
 
     pub fn max_age(&self) -> PolarsResult<u32> {
-        // Get the maximum age from the dataframe
-        let age_column = self.data.dataframe.column("age")?;
-        let age_series = age_column.u32()?;
-        age_series
-            .iter()
-            .flatten()
-            .max()
-            .ok_or_else(|| PolarsError::ComputeError("No age data available".into()))
+        Ok(self.lookup()?.max_age())
     }
 
     // Alias for max_age
@@ -169,49 +175,36 @@ impl MortTableConfig {
     }
 
     pub fn min_duration(&self) -> PolarsResult<u32> {
-        // Return error if duration column does not exist
-        let has_duration = self
-            .data
-            .dataframe
-            .get_column_names()
-            .contains(&&"duration".into());
-
-        if !has_duration {
-            return Err(PolarsError::ColumnNotFound(
-                "duration column not found".into(),
-            ));
-        }
-
-        let duration_column = self.data.dataframe.column("duration")?;
-        let duration_series = duration_column.u32()?;
-        duration_series
-            .iter()
-            .flatten()
-            .min()
-            .ok_or_else(|| PolarsError::ComputeError("No duration data available".into()))
+        self.duration_range().map(|(min_dur, _)| min_dur)
     }
 
     pub fn max_duration(&self) -> PolarsResult<u32> {
-        // Return error if duration column does not exist
-        let has_duration = self
-            .data
-            .dataframe
-            .get_column_names()
-            .contains(&&"duration".into());
-
-        if !has_duration {
-            return Err(PolarsError::ColumnNotFound(
-                "duration column not found".into(),
-            ));
+        self.duration_range().map(|(_, max_dur)| max_dur)
+    }
+
+    /// Precomputed qx/lx lookup of the table.
+    ///
+    /// The lookup stored with the config, or a fresh one when `data.dataframe` has been
+    /// replaced or modified since the config was built.
+    pub(crate) fn lookup(&self) -> PolarsResult<Arc<MortLookup>> {
+        if self.lookup.is_built_from(&self.data.dataframe) {
+            return Ok(self.lookup.clone());
         }
+        Ok(Arc::new(MortLookup::from_dataframe(&self.data.dataframe)?))
+    }
 
-        let duration_column = self.data.dataframe.column("duration")?;
-        let duration_series = duration_column.u32()?;
-        duration_series
-            .iter()
-            .flatten()
-            .max()
-            .ok_or_else(|| PolarsError::ComputeError("No duration data available".into()))
+    /// Clone the configuration with a new (age, qx, lx) DataFrame and its lookup.
+    pub(crate) fn with_dataframe(&self, dataframe: DataFrame) -> PolarsResult<Self> {
+        let mut config = self.clone();
+        config.lookup = Arc::new(MortLookup::from_dataframe(&dataframe)?);
+        config.data.dataframe = dataframe;
+        Ok(config)
+    }
+
+    fn duration_range(&self) -> PolarsResult<(u32, u32)> {
+        self.lookup()?
+            .durations()
+            .ok_or_else(|| PolarsError::ColumnNotFound("duration column not found".into()))
     }
 
     fn get_qx_lx_data_config(&self) -> RSLifeResult<Self> {
@@ -240,8 +233,14 @@ impl MortTableConfig {
             )),
         }?;
 
-        let mut config = self.clone();
-        config.data.dataframe = new_df;
+        // Apply the mortality rate multiplier to qx and rebuild lx from the adjusted qx
+        let (new_df, new_radix) = if self.pct != 1.0 {
+            apply_pct_to_qx(new_df, self.pct, is_2d, min_dur, max_dur, new_radix)?
+        } else {
+            (new_df, new_radix)
+        };
+
+        let mut config = self.with_dataframe(new_df)?;
         config.radix = new_radix;
 
         // Return the configured MortTableConfig
@@ -504,6 +503,35 @@ fn _unpivot_data_2D(df: DataFrame, min_dur: u32, max_dur: u32) -> PolarsResult<D
     Ok(unpivoted_df)
 }
 
+// --------pct adjustment----------
+
+// Scale qx by pct (capped at 1.0) then rebuild lx from the adjusted qx with the same radix
+fn apply_pct_to_qx(
+    df: DataFrame,
+    pct: f64,
+    is_2d: bool,
+    min_dur: u32,
+    max_dur: u32,
+    radix: u32,
+) -> PolarsResult<(DataFrame, u32)> {
+    // qₓ' = min(pct · qₓ, 1)
+    let qx_adjusted = when((col("qx") * lit(pct)).gt(lit(1.0)))
+        .then(lit(1.0))
+        .otherwise(col("qx") * lit(pct))
+        .alias("qx");
+
+    if is_2d {
+        let df = df
+            .lazy()
+            .select([col("age"), qx_adjusted, col("duration")])
+            .collect()?;
+        get_lx_from_qx_2D(df, min_dur, max_dur, radix)
+    } else {
+        let df = df.lazy().select([col("age"), qx_adjusted]).collect()?;
+        get_lx_from_qx_1D(df, radix)
+    }
+}
+
 fn _pivot_2D_data(df: DataFrame, value_column: &str) -> PolarsResult<DataFrame> {
     // Get unique durations to create pivot columns dynamically
     let unique_durations: Vec<u32> = df
@@ -541,6 +569,7 @@ fn _pivot_2D_data(df: DataFrame, value_column: &str) -> PolarsResult<DataFrame>
 mod tests {
     use super::*;
     use crate::mt_config::MortTableConfig;
+    use approx::assert_abs_diff_eq;
 
     // -----------------------------------------------------
     // Test data print out
@@ -596,4 +625,79 @@ mod tests {
         println!("\nStep 3a: _unpivot_data_2D\n{}", df2.head(Some(10)));
         println!("\nStep 3b: _unpivot_data_2D\n{}", df2.tail(Some(10)));
     }
+
+    #[test]
+    fn test_pct_applied_to_qx_1d() {
+        let elt15 = MortData::from_builtin("ELT15_F").expect("Failed to load ELT15_F table");
+        let base = MortTableConfig::builder()
+            .data(elt15.clone())
+            .build()
+            .unwrap();
+        let loaded = MortTableConfig::builder()
+            .data(elt15)
+            .pct(1.5)
+            .build()
+            .unwrap();
+
+        let base_qx = base.data.dataframe.column("qx").unwrap().f64().unwrap();
+        let loaded_qx = loaded.data.dataframe.column("qx").unwrap().f64().unwrap();
+        let loaded_lx = loaded.data.dataframe.column("lx").unwrap().f64().unwrap();
+
+        for (i, (q, q_loaded)) in base_qx.iter().zip(loaded_qx.iter()).enumerate() {
+            let (q, q_loaded) = (q.unwrap(), q_loaded.unwrap());
+            // qₓ' = min(1.5 · qₓ, 1)
+            assert_abs_diff_eq!(q_loaded, f64::min(1.5 * q, 1.0), epsilon = 1e-12);
+
+            // lₓ₊₁ = lₓ · (1 - qₓ')
+            if i + 1 < loaded_lx.len() {
+                let lx = loaded_lx.get(i).unwrap();
+                let lx_next = loaded_lx.get(i + 1).unwrap();
+                assert_abs_diff_eq!(lx_next, lx * (1.0 - q_loaded), epsilon = 1e-6);
+            }
+        }
+
+        // Radix is kept
+        assert_abs_diff_eq!(loaded_lx.get(0).unwrap(), 100_000.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_pct_applied_to_qx_2d() {
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let base = MortTableConfig::builder()
+            .data(am92.clone())
+            .build()
+            .unwrap();
+        let loaded = MortTableConfig::builder()
+            .data(am92)
+            .pct(0.75)
+            .build()
+            .unwrap();
+
+        let filter = |mt: &MortTableConfig, age: u32, duration: u32| -> (f64, f64) {
+            let df = mt
+                .data
+                .dataframe
+                .clone()
+                .lazy()
+                .filter(col("age").eq(lit(age)))
+                .filter(col("duration").eq(lit(duration)))
+                .collect()
+                .unwrap();
+            let qx = df.column("qx").unwrap().f64().unwrap().get(0).unwrap();
+            let lx = df.column("lx").unwrap().f64().unwrap().get(0).unwrap();
+            (qx, lx)
+        };
+
+        // Select and ultimate rates are both scaled
+        for (age, duration) in [(40, 0), (40, 1), (40, 2), (70, 2)] {
+            let (q, _) = filter(&base, age, duration);
+            let (q_loaded, _) = filter(&loaded, age, duration);
+            assert_abs_diff_eq!(q_loaded, 0.75 * q, epsilon = 1e-12);
+        }
+
+        // l[x] = l[x]+1 / (1 - q[x]) is consistent with the scaled rates
+        let (q_select, l_select) = filter(&loaded, 40, 0);
+        let (_, l_next) = filter(&loaded, 41, 1);
+        assert_abs_diff_eq!(l_next, l_select * (1.0 - q_select), epsilon = 1e-6);
+    }
 }
@@ -1,19 +1,52 @@
-use super::aga_xls::AusGovActMortXLS;
-use super::ifoa_xls::IFOAMortXLS;
+use super::aga_xls::{AGA_URL, AusGovActMortXLS};
+#[cfg(feature = "async")]
+use super::fetch::{AsyncFetcher, default_async_fetcher};
+use super::fetch::{Fetcher, default_fetcher};
+use super::ifoa_xls::{IFOAMortXLS, is_92_series_base, sheet_name_from_url};
+use super::improvement::{ImprovementScale, MortalityProjection};
+use super::layout::{ColumnMapping, is_pivoted, unpivot};
+use super::metadata::{TableMetadata, TableSourceEnum};
 use super::soa_xml::SOAMortXML;
-use crate::RSLifeResult;
+use crate::error::invalid_parameter;
 use crate::mt_config::spreadsheet_helpers::*;
+use crate::{RSLifeError, RSLifeResult};
 use bon::bon;
 use calamine::{Reader, open_workbook_auto};
 use polars::prelude::*;
 use spreadsheet_ods::read_ods;
 use std::fs;
+use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
 pub struct MortData {
     pub category: String,
     pub description: String,
     pub dataframe: DataFrame,
+    /// Provenance of the table (source, publisher identity, load time, content hash).
+    pub metadata: TableMetadata,
+}
+
+/// Parametric mortality law with its original parameters.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MortalityLawEnum {
+    /// μₓ = B·Cˣ
+    Gompertz { B: f64, C: f64 },
+    /// μₓ = A + B·Cˣ
+    Makeham { A: f64, B: f64, C: f64 },
+    /// μₓ = k·xⁿ
+    Weibull { k: f64, n: f64 },
+}
+
+impl MortalityLawEnum {
+    /// Exact force of mortality μₓ at (fractional) age x.
+    pub fn mu(&self, x: f64) -> f64 {
+        match *self {
+            Self::Gompertz { B, C } => B * C.powf(x),
+            Self::Makeham { A, B, C } => A + B * C.powf(x),
+            Self::Weibull { k, n } => k * x.powf(n),
+        }
+    }
 }
 
 #[bon]
@@ -55,14 +88,17 @@ impl MortData {
         validate_df_schema(&dataframe)?;
 
         // Validate and transform DataFrame to ensure it has the correct schema
-        let dataframe = setup_dataframe_to_correct_schema(dataframe)
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        let dataframe = setup_dataframe_to_correct_schema(dataframe)?;
+
+        // Metadata of a caller supplied table, refined by the loaders
+        let metadata = TableMetadata::new(TableSourceEnum::DataFrame, &dataframe)?;
 
         // Return result
         let result = Self {
             category,
             description,
             dataframe,
+            metadata,
         };
 
         Ok(result)
@@ -118,7 +154,7 @@ impl MortData {
         // qₓ = 1 - exp(-λ)
 
         if lambda <= 0.0 {
-            return Err("Lambda must be positive".into());
+            return Err(invalid_parameter("lambda", "Lambda must be positive"));
         }
 
         let ages: Vec<u32> = (start_age..=omega).collect();
@@ -135,7 +171,8 @@ impl MortData {
         // Create MortData from the first table in the XML
         let category = "Parametric Mortality Data".to_string();
         let description = "Constant Force Law".to_string();
-        Self::new(category, description, data)
+        let result = Self::new(category, description, data)?.with_law_metadata("Constant Force");
+        Ok(result)
     }
 
     /// Create a parametric mortality table using the De Moirve Law.
@@ -191,7 +228,8 @@ impl MortData {
         // Create MortData from the first table in the XML
         let category = "Parametric Mortality Data".to_string();
         let description = "De Moirve Law".to_string();
-        Self::new(category, description, data)
+        let result = Self::new(category, description, data)?.with_law_metadata("De Moivre");
+        Ok(result)
     }
 
     /// Create a parametric mortality table using the Gompertz Law.
@@ -245,7 +283,11 @@ impl MortData {
 
         // Validate
         if B <= 0.0 || C <= 1.0 {
-            return Err("Gompertz parameters must be B > 0 and C > 1".into());
+            let path = if B <= 0.0 { "B" } else { "C" };
+            return Err(invalid_parameter(
+                path,
+                "Gompertz parameters must be B > 0 and C > 1",
+            ));
         }
 
         let ages: Vec<u32> = (start_age..=omega).collect();
@@ -264,7 +306,9 @@ impl MortData {
         // Create MortData from the first table in the XML
         let category = "Parametric Mortality Data".to_string();
         let description = "Gompertz Law".to_string();
-        Self::new(category, description, data)
+        let mut result = Self::new(category, description, data)?.with_law_metadata("Gompertz");
+        result.metadata.law = Some(MortalityLawEnum::Gompertz { B, C });
+        Ok(result)
     }
 
     /// Create a parametric mortality table using the Makeham Law.
@@ -284,14 +328,14 @@ impl MortData {
     /// ```
     ///
     /// # Parameters
-    /// - `A`: Makeham parameter, must be >= -B.
+    /// - `A`: Makeham parameter, must be >= -B·C^start_age (μₓ ≥ 0 at every age of the table).
     /// - `B`: Makeham parameter, must be > 0.
     /// - `C`: Makeham parameter, must be > 1.
     /// - `start_age`: Starting age for the table (default: 0).
     /// - `omega`: Limiting age for the table (default: 150).
     ///
     /// # Errors
-    /// - B must be > 0, C must be > 1, A must be >= -B.
+    /// - B must be > 0, C must be > 1, A must be >= -B·C^start_age.
     ///
     /// # Example
     /// ```rust
@@ -314,14 +358,23 @@ impl MortData {
         #[builder(default = 150)] omega: u32, // end_age
     ) -> RSLifeResult<Self> {
         // Makeham law:
-        // μₓ = A + B.Cˣ  x ≥ 0, B > 0, c > 1, A >= -B
+        // μₓ = A + B.Cˣ  x ≥ start_age, B > 0, c > 1, A >= -B.C^start_age
         // S₀(x) = exp(-Ax - B  / ln(C) * (Cˣ - 1))
         // ₜpₓ = S₀(x + t) / S₀(x) = exp[-At - B / ln(C). Cˣ(Cᵗ - 1)]
         // qₓ = 1 - exp[-A - B / ln(C).Cˣ.(C - 1)]
 
         // Validate parameters
-        if B <= 0.0 || C <= 1.0 || A < -B {
-            return Err("Makeham parameters must be B > 0, C > 1, and A >= -B".into());
+        // μₓ increases with x, so it is non-negative over the table if it is at start_age
+        if B <= 0.0 || C <= 1.0 || A < -B * C.powf(f64::from(start_age)) {
+            let path = match (B <= 0.0, C <= 1.0) {
+                (true, _) => "B",
+                (false, true) => "C",
+                (false, false) => "A",
+            };
+            return Err(invalid_parameter(
+                path,
+                "Makeham parameters must be B > 0, C > 1, and A >= -B·C^start_age",
+            ));
         }
 
         let ages: Vec<u32> = (start_age..=omega).collect();
@@ -340,7 +393,9 @@ impl MortData {
         // Create MortData from the first table in the XML
         let category = "Parametric Mortality Data".to_string();
         let description = "MakeHam Law".to_string();
-        Self::new(category, description, data)
+        let mut result = Self::new(category, description, data)?.with_law_metadata("Makeham");
+        result.metadata.law = Some(MortalityLawEnum::Makeham { A, B, C });
+        Ok(result)
     }
 
     /// Create a parametric mortality table using the Weibull Law.
@@ -394,7 +449,11 @@ impl MortData {
 
         // Validate parameters
         if k <= 0.0 || n <= 1.0 {
-            return Err("Weibull parameters must be k > 0, n > 1".into());
+            let path = if k <= 0.0 { "k" } else { "n" };
+            return Err(invalid_parameter(
+                path,
+                "Weibull parameters must be k > 0, n > 1",
+            ));
         }
 
         let ages: Vec<u32> = (start_age..=omega).collect();
@@ -413,7 +472,9 @@ impl MortData {
         // Create MortData from the first table in the XML
         let category = "Parametric Mortality Data".to_string();
         let description = "MakeHam Law".to_string();
-        Self::new(category, description, data)
+        let mut result = Self::new(category, description, data)?.with_law_metadata("Weibull");
+        result.metadata.law = Some(MortalityLawEnum::Weibull { k, n });
+        Ok(result)
     }
 
     // ========================================================
@@ -430,12 +491,18 @@ impl MortData {
     /// - Table layouts: "Aggregate", "Ultimate", "Select", "Select & Ultimate"
     /// - Content types: Various mortality and disability tables (see source for full list)
     ///
+    /// Documents with several tables are supported when they hold one select and one
+    /// ultimate table (e.g. select & ultimate CSO/VBT tables): both are combined into a 2D
+    /// table indexed by attained age and duration, with the ultimate rates at the final
+    /// duration. Other documents with several tables require `from_soa_xml_string_with_table()`.
+    ///
     /// Schema validation is performed by `new()` after parsing.
     ///
     /// # Errors
     /// - Invalid XML format
     /// - XML parsing errors
     /// - Unsupported or unapproved table types
+    /// - Several tables that are not a select and ultimate pair
     /// - Schema validation errors (via `new()`)
     /// - Missing required XML elements
     ///
@@ -449,20 +516,68 @@ impl MortData {
     ///# RSLifeResult::Ok(())
     /// ```
     pub fn from_soa_xml_string(xml_str: &str) -> RSLifeResult<Self> {
+        Self::from_soa_xml_data(xml_str, None)
+    }
+
+    /// Parse one table of a SOA XML document, picked by its position (0-based) in the document.
+    ///
+    /// Select tables are converted to attained age and duration since selection.
+    /// Refer to `from_soa_xml_string()` for details.
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    ///
+    /// // Ultimate table only of a select & ultimate table
+    /// let xml_content = std::fs::read_to_string("data/t2682.xml")?;
+    /// let mort_data = MortData::from_soa_xml_string_with_table(&xml_content, 1)?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn from_soa_xml_string_with_table(xml_str: &str, index: usize) -> RSLifeResult<Self> {
+        Self::from_soa_xml_data(xml_str, Some(index))
+    }
+
+    /// Parse SOA XML into `MortData`, using the whole document or the table at `index`.
+    fn from_soa_xml_data(xml_str: &str, index: Option<usize>) -> RSLifeResult<Self> {
         // Parse the XML string into SOAMortXML
-        let xml_data = SOAMortXML::from_string(xml_str)
-            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let xml_data = SOAMortXML::from_string(xml_str)?;
 
         // Return error if the XML data is not in category of our filter
         if !is_soa_xml_data_approved(&xml_data) {
-            return Err("XML data is not approved for calculation.".into());
+            return Err(RSLifeError::Schema(
+                "XML data is not approved for calculation.".into(),
+            ));
         }
 
-        // Create MortData from the first table in the XML
+        // Create MortData from the selected table, or from all tables of the document
         let category = "SOA Mortality Data".to_string();
-        let description = xml_data.content_classification.table_description.clone();
-        let data = xml_data.tables[0].values.clone();
-        let result = Self::new(category, description, data)?;
+        let (description, data) = match index {
+            Some(index) => {
+                let table_description = &xml_data.table(index)?.meta_data.table_description;
+                let description = if table_description.is_empty() {
+                    xml_data.content_classification.table_description.clone()
+                } else {
+                    table_description.clone()
+                };
+                (description, xml_data.table_values(index)?)
+            }
+            None => (
+                xml_data.content_classification.table_description.clone(),
+                xml_data.values()?,
+            ),
+        };
+        let mut result = Self::new(category, description, data)?;
+
+        // Classification of the document, nation of the table used
+        let cc = &xml_data.content_classification;
+        let nation = &xml_data.table(index.unwrap_or(0))?.meta_data.nation;
+        result.metadata.source = TableSourceEnum::Soa;
+        result.metadata.table_identity = Some(cc.table_identity.to_string());
+        result.metadata.provider =
+            non_empty(&cc.provider_name).or_else(|| non_empty(&cc.provider_domain));
+        result.metadata.nation = non_empty(nation);
+        result.metadata.content_type = non_empty(&cc.content_type);
+        result.metadata.key_words = cc.key_words.clone();
         Ok(result)
     }
 
@@ -491,25 +606,27 @@ impl MortData {
     /// ```
     pub fn from_soa_xml_file_path_str(file_path: &str) -> RSLifeResult<Self> {
         // Read the XML file into a string
-        let xml_str = fs::read_to_string(file_path)
-            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        let xml_str = fs::read_to_string(file_path)?;
 
         // Use the from_soa_xml_string method to create MortData
-        Self::from_soa_xml_string(&xml_str)
+        let mut result = Self::from_soa_xml_string(&xml_str)?;
+        result.metadata.location = Some(file_path.to_string());
+        Ok(result)
     }
 
     /// Parse mortality table from SOA URL.
     ///
     /// Downloads and parses mortality table data directly from a SOA (Society of Actuaries)
-    /// URL. This method makes an HTTP GET request to fetch XML data and then parses it.
+    /// URL through the default fetcher (see [`crate::mt_config::fetch`]), then parses it.
     ///
-    /// Requires internet connection and the URL must return valid SOA XML format.
+    /// Requires internet connection unless the default fetcher points at a mirror or cache.
     /// Only approved SOA table layouts and content types are accepted.
     ///
     /// # Errors
     /// - Network connectivity issues
     /// - HTTP request failures (4xx, 5xx status codes)
     /// - Invalid or unreachable URL
+    /// - Offline mode without a cached copy
     /// - All errors from `from_soa_xml_string()`
     ///
     /// # Examples
@@ -523,20 +640,76 @@ impl MortData {
     ///# RSLifeResult::Ok(())
     /// ```
     pub fn from_soa_url(url: &str) -> RSLifeResult<Self> {
-        // Fetch the XML data from the URL
-        let response = reqwest::blocking::get(url)
-            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        Self::from_soa_url_with_fetcher(url, default_fetcher().as_ref())
+    }
 
-        if !response.status().is_success() {
-            return Err("Failed to fetch XML data from URL".into());
-        }
+    /// Parse mortality table from SOA URL, reading the XML through `fetcher`.
+    ///
+    /// Refer to `from_soa_url()` for details.
+    pub fn from_soa_url_with_fetcher(url: &str, fetcher: &dyn Fetcher) -> RSLifeResult<Self> {
+        let location = fetcher.location(url);
+        let bytes = fetcher.fetch(url)?;
+        Self::from_soa_xml_bytes(url, bytes, location)
+    }
+
+    /// Parse one table of a SOA XML document downloaded from `url`, picked by its position (0-based).
+    ///
+    /// Refer to `from_soa_url()` and `from_soa_xml_string_with_table()` for details.
+    pub fn from_soa_url_with_table(url: &str, index: usize) -> RSLifeResult<Self> {
+        let fetcher = default_fetcher();
+        let location = fetcher.location(url);
+        let bytes = fetcher.fetch(url)?;
+        let xml_str = soa_xml_string(url, bytes)?;
+        let mut result = Self::from_soa_xml_string_with_table(&xml_str, index)?;
+        result.metadata.location = Some(location);
+        Ok(result)
+    }
+
+    /// Parse mortality table from SOA URL without blocking (requires the `async` feature).
+    ///
+    /// Downloads through the default async fetcher (see [`crate::mt_config::fetch`]), which follows
+    /// the same offline, mirror and cache settings as the blocking loaders and otherwise uses
+    /// [`AsyncHttpFetcher`](crate::mt_config::fetch::AsyncHttpFetcher) inside the caller's tokio
+    /// runtime. The XML is then parsed as `from_soa_url()` does; parsing goes through polars,
+    /// which may block, so the IFOA and AGA loaders need a multi-thread runtime.
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// # async fn load() -> RSLifeResult<()> {
+    /// let mort_data = MortData::from_soa_url_async("https://mort.soa.org/data/t1704.xml").await?;
+    /// println!("Downloaded: {}", mort_data.category);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn from_soa_url_async(url: &str) -> RSLifeResult<Self> {
+        Self::from_soa_url_async_with_fetcher(url, default_async_fetcher().as_ref()).await
+    }
 
-        let xml_str = response
-            .text()
-            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+    /// Parse mortality table from SOA URL without blocking, reading the XML through `fetcher`.
+    ///
+    /// Refer to `from_soa_url_async()` for details.
+    #[cfg(feature = "async")]
+    pub async fn from_soa_url_async_with_fetcher(
+        url: &str,
+        fetcher: &dyn AsyncFetcher,
+    ) -> RSLifeResult<Self> {
+        let location = fetcher.location(url);
+        let bytes = fetcher.fetch_async(url).await?;
+        Self::from_soa_xml_bytes(url, bytes, location)
+    }
+
+    /// Decode downloaded XML and parse it, shared by the blocking and async SOA loaders.
+    ///
+    /// `location` is where the fetcher read `url` from.
+    fn from_soa_xml_bytes(url: &str, bytes: Vec<u8>, location: String) -> RSLifeResult<Self> {
+        let xml_str = soa_xml_string(url, bytes)?;
 
         // Use the from_soa_xml_string method to create MortData
-        Self::from_soa_xml_string(&xml_str)
+        let mut result = Self::from_soa_xml_string(&xml_str)?;
+        result.metadata.location = Some(location);
+        Ok(result)
     }
 
     /// Parse mortality table from SOA website by table ID.
@@ -570,16 +743,30 @@ impl MortData {
         Self::from_soa_url(&url)
     }
 
+    /// Parse mortality table from SOA website by table ID without blocking (requires the `async` feature).
+    ///
+    /// Refer to `from_soa_url_id()` and `from_soa_url_async()` for details.
+    #[cfg(feature = "async")]
+    pub async fn from_soa_url_id_async(id: i32) -> RSLifeResult<Self> {
+        let url = format!("https://mort.soa.org/data/t{id}.xml");
+        Self::from_soa_url_async(&url).await
+    }
+
     pub fn from_soa_custom(id: &str) -> RSLifeResult<Self> {
         match id {
             // Makeham law with A=0.00022, B=2.7e-6, C=1.124
-            "SULT" => Self::from_Makeham_law()
-                .A(0.00022)
-                .B(2.7e-6)
-                .C(1.124)
-                .start_age(20)
-                .call(),
-            _ => Err(format!("Unknown SOA custom id: {id}").into()),
+            "SULT" => {
+                let mut result = Self::from_Makeham_law()
+                    .A(0.00022)
+                    .B(2.7e-6)
+                    .C(1.124)
+                    .start_age(20)
+                    .call()?;
+                result.metadata.table_identity = Some(id.to_string());
+                result.metadata.provider = Some("Society of Actuaries".to_string());
+                Ok(result)
+            }
+            _ => Err(RSLifeError::UnknownTable(id.to_string())),
         }
     }
 
@@ -589,41 +776,128 @@ impl MortData {
 
     pub fn from_ifoa_xls_file_path_str(file_path: &str, sheet_name: &str) -> RSLifeResult<Self> {
         let data = IFOAMortXLS::from_xls_file_path_str(file_path, sheet_name)?;
-        let result = Self::new(
-            "IFOA Mortality Data".to_string(),
-            data.description,
-            data.dataframe,
-        )?;
-        Ok(result)
+        Self::from_ifoa_data(data, sheet_name, Some(file_path))
     }
 
     pub fn from_ifoa_url(url: &str) -> RSLifeResult<Self> {
-        let data = IFOAMortXLS::from_url(url)?;
-        let result = Self::new(
-            "IFOA Mortality Data".to_string(),
-            data.description,
-            data.dataframe,
-        )?;
-        Ok(result)
+        Self::from_ifoa_url_with_fetcher(url, default_fetcher().as_ref())
+    }
+
+    /// Parse mortality table from an IFOA XLS URL, reading the file through `fetcher`.
+    pub fn from_ifoa_url_with_fetcher(url: &str, fetcher: &dyn Fetcher) -> RSLifeResult<Self> {
+        let location = fetcher.location(url);
+        let data = IFOAMortXLS::from_url(url, fetcher)?;
+        Self::from_ifoa_data(data, &sheet_name_from_url(url)?, Some(&location))
+    }
+
+    /// Parse mortality table from an IFOA XLS URL without blocking (requires the `async` feature).
+    ///
+    /// Refer to `from_soa_url_async()` for how the download is performed.
+    #[cfg(feature = "async")]
+    pub async fn from_ifoa_url_async(url: &str) -> RSLifeResult<Self> {
+        Self::from_ifoa_url_async_with_fetcher(url, default_async_fetcher().as_ref()).await
+    }
+
+    /// Parse mortality table from an IFOA XLS URL without blocking, reading the file through `fetcher`.
+    #[cfg(feature = "async")]
+    pub async fn from_ifoa_url_async_with_fetcher(
+        url: &str,
+        fetcher: &dyn AsyncFetcher,
+    ) -> RSLifeResult<Self> {
+        let location = fetcher.location(url);
+        let data = IFOAMortXLS::from_url_async(url, fetcher).await?;
+        Self::from_ifoa_data(data, &sheet_name_from_url(url)?, Some(&location))
     }
 
     pub fn from_ifoa_url_id(id: &str) -> RSLifeResult<Self> {
         let data = IFOAMortXLS::from_url_id(id)?;
-        let result = Self::new(
-            "IFOA Mortality Data".to_string(),
-            data.description,
-            data.dataframe,
-        )?;
-        Ok(result)
+        let location = data.location.clone();
+        Self::from_ifoa_data(data, id, location.as_deref())
     }
 
     pub fn from_ifoa_custom(id: &str) -> RSLifeResult<Self> {
         let data = IFOAMortXLS::from_custom(id)?;
-        let result = Self::new(
+        let location = data.location.clone();
+        Self::from_ifoa_data(data, id, location.as_deref())
+    }
+
+    /// 92-series base table projected with the CMI 92-series reduction factors.
+    ///
+    /// Generalises the `PMA92C20`-style tables to any base table of the 92-series workbook
+    /// (AM92, AF92, PMA92, PFA92, TM92, ...) and any calendar year:
+    /// - `year`: period table, every age projected to calendar year `year` (2020 gives C20)
+    /// - `year_of_birth`: cohort table, age x projected to calendar year `year_of_birth + x`
+    ///
+    /// Select tables keep their durations and are projected by attained age.
+    /// Refer to [`ImprovementScale::cmi_92_series`](crate::mt_config::improvement::ImprovementScale::cmi_92_series) for the formula.
+    ///
+    /// # Errors
+    /// - `UnknownTable`: `base` is not a 92-series base table
+    /// - `InvalidParameters`: neither or both of `year` and `year_of_birth` given
+    /// - Download errors for base tables that are not builtin
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use rslife::prelude::*;
+    /// // PMA92 projected to 2025
+    /// let pma92c25 = MortData::from_92_series_projection().base("PMA92").year(2025).call()?;
+    ///
+    /// // PMA92 for members born in 1960
+    /// let cohort = MortData::from_92_series_projection()
+    ///     .base("PMA92")
+    ///     .year_of_birth(1960)
+    ///     .call()?;
+    /// let config = MortTableConfig::builder().data(cohort).build()?;
+    /// # RSLifeResult::Ok(())
+    /// ```
+    #[builder]
+    pub fn from_92_series_projection(
+        #[builder(into)] base: String,
+        year: Option<i32>,
+        year_of_birth: Option<i32>,
+    ) -> RSLifeResult<Self> {
+        if !is_92_series_base(&base) {
+            return Err(RSLifeError::UnknownTable(base));
+        }
+        // Download only the base tables that are not preloaded
+        let base_data = match Self::from_builtin(&base) {
+            Err(RSLifeError::UnknownTable(_)) => Self::from_ifoa_url_id(&base)?,
+            result => result?,
+        };
+        let location = base_data.metadata.location.clone();
+        let projection = MortalityProjection::builder()
+            .base(base_data)
+            .base_year(1992)
+            .scale(ImprovementScale::cmi_92_series())
+            .build()?;
+
+        let mut result = match (year, year_of_birth) {
+            (Some(year), None) => projection.period_table(year)?,
+            (None, Some(year_of_birth)) => projection.generational_table(year_of_birth)?,
+            _ => {
+                return Err(invalid_parameter(
+                    "year",
+                    "Give exactly one of year and year_of_birth",
+                ));
+            }
+        };
+        result.metadata.source = TableSourceEnum::Ifoa;
+        result.metadata.location = location;
+        Ok(result)
+    }
+
+    /// Create MortData from a parsed IFOA workbook, with IFOA metadata.
+    fn from_ifoa_data(data: IFOAMortXLS, id: &str, location: Option<&str>) -> RSLifeResult<Self> {
+        let mut result = Self::new(
             "IFOA Mortality Data".to_string(),
             data.description,
             data.dataframe,
         )?;
+        result.metadata.source = TableSourceEnum::Ifoa;
+        result.metadata.location = location.map(str::to_string);
+        result.metadata.table_identity = Some(id.to_string());
+        result.metadata.provider = Some("Institute and Faculty of Actuaries".to_string());
+        result.metadata.nation = Some("United Kingdom".to_string());
         Ok(result)
     }
 
@@ -658,12 +932,65 @@ impl MortData {
     /// # RSLifeResult::Ok(())
     /// ```
     pub fn from_aus_gov_act(gender: &str, period: &str) -> RSLifeResult<Self> {
-        let data = AusGovActMortXLS::from_url(gender, period)?;
-        let result = Self::new(
+        Self::from_aus_gov_act_with_fetcher(gender, period, default_fetcher().as_ref())
+    }
+
+    /// Parse mortality table from the Australian Government Actuary workbook read through `fetcher`.
+    ///
+    /// Refer to `from_aus_gov_act()` for details.
+    pub fn from_aus_gov_act_with_fetcher(
+        gender: &str,
+        period: &str,
+        fetcher: &dyn Fetcher,
+    ) -> RSLifeResult<Self> {
+        let location = fetcher.location(AGA_URL);
+        let data = AusGovActMortXLS::from_url(gender, period, fetcher)?;
+        Self::from_aga_data(data, gender, period, location)
+    }
+
+    /// Parse mortality table from the Australian Government Actuary workbook without blocking
+    /// (requires the `async` feature).
+    ///
+    /// Refer to `from_aus_gov_act()` and `from_soa_url_async()` for details.
+    #[cfg(feature = "async")]
+    pub async fn from_aus_gov_act_async(gender: &str, period: &str) -> RSLifeResult<Self> {
+        Self::from_aus_gov_act_async_with_fetcher(gender, period, default_async_fetcher().as_ref())
+            .await
+    }
+
+    /// Parse mortality table from the Australian Government Actuary workbook without blocking,
+    /// reading it through `fetcher`.
+    #[cfg(feature = "async")]
+    pub async fn from_aus_gov_act_async_with_fetcher(
+        gender: &str,
+        period: &str,
+        fetcher: &dyn AsyncFetcher,
+    ) -> RSLifeResult<Self> {
+        let location = fetcher.location(AGA_URL);
+        let data = AusGovActMortXLS::from_url_async(gender, period, fetcher).await?;
+        Self::from_aga_data(data, gender, period, location)
+    }
+
+    /// Create MortData from a parsed AGA workbook, with AGA metadata.
+    ///
+    /// `location` is where the fetcher read the workbook from.
+    fn from_aga_data(
+        data: AusGovActMortXLS,
+        gender: &str,
+        period: &str,
+        location: String,
+    ) -> RSLifeResult<Self> {
+        let mut result = Self::new(
             "Australian Government Actuarial Mortality Data".to_string(),
             data.description,
             data.dataframe,
         )?;
+        result.metadata.source = TableSourceEnum::Aga;
+        result.metadata.location = Some(location);
+        result.metadata.table_identity = Some(format!("{gender} {period}"));
+        result.metadata.provider = Some("Australian Government Actuary".to_string());
+        result.metadata.nation = Some("Australia".to_string());
+        result.metadata.content_type = Some("Population Mortality".to_string());
         Ok(result)
     }
 
@@ -715,6 +1042,8 @@ impl MortData {
     /// Parse mortality table from ODS file using spreadsheet-ods.
     ///
     /// Reads ODS files and automatically parses all columns as f64.
+    /// Select tables may be in long layout (`age`, `qx`, `duration`) or pivoted with one column
+    /// per duration, as written by `to_ods()`.
     /// Age and duration columns are validated to contain whole numbers during schema validation.
     ///
     /// Schema validation is performed by `from_df()` after parsing.
@@ -736,12 +1065,17 @@ impl MortData {
     ///
     /// // Select table with duration
     /// let mort_data = MortData::from_ods("data/am92_select.ods", "AM92")?;
+    ///
+    /// // Select table with one column per duration ("age", "Duration 0", "Durations 1+")
+    /// let mort_data = MortData::from_ods("data/am92_pivoted.ods", "AM92")?;
     ///# RSLifeResult::Ok(())
     /// ```
     pub fn from_ods(ods_file_path_str: &str, sheet_name: &str) -> RSLifeResult<Self> {
         // Open ODS workbook
-        let workbook = read_ods(ods_file_path_str).map_err(|e| -> Box<dyn std::error::Error> {
-            format!("Failed to open ODS file '{ods_file_path_str}': {e}").into()
+        let workbook = read_ods(ods_file_path_str).map_err(|e| {
+            RSLifeError::Parse(format!(
+                "Failed to open ODS file '{ods_file_path_str}': {e}"
+            ))
         })?;
 
         // Find the sheet by name - iterate through sheets to find by name
@@ -754,12 +1088,14 @@ impl MortData {
             }
         }
 
-        let sheet = sheet.ok_or_else(|| format!("Sheet '{sheet_name}' not found in ODS file"))?;
+        let sheet = sheet.ok_or_else(|| {
+            RSLifeError::Parse(format!("Sheet '{sheet_name}' not found in ODS file"))
+        })?;
 
         // Check if sheet is empty
         let (max_row, _) = sheet.used_grid_size();
         if max_row < 1 {
-            return Err(format!("Sheet '{sheet_name}' is empty").into());
+            return Err(RSLifeError::Parse(format!("Sheet '{sheet_name}' is empty")));
         }
 
         // Parse headers
@@ -770,7 +1106,7 @@ impl MortData {
 
         // Validate that we have data
         if data_cols.is_empty() || data_cols[0].is_empty() {
-            return Err("No data rows found in sheet".into());
+            return Err(RSLifeError::Parse("No data rows found in sheet".into()));
         }
 
         // Build DataFrame
@@ -781,19 +1117,26 @@ impl MortData {
             columns.push(series.into_column());
         }
         let height = columns[0].len();
-        let df = DataFrame::new(height, columns)
-            .map_err(|e| format!("Failed to create DataFrame: {e}"))?;
+        let mut df = DataFrame::new(height, columns)?;
+
+        // Select tables written as one column per duration
+        if is_pivoted(&headers) {
+            df = unpivot(&df)?;
+        }
 
         // Create MortData with a default category
         let category = "Custom Mortality Data".to_string();
         let description =
             "Created from ODS file {ods_file_path_str}, sheet {sheet_name}.".to_string();
         Self::new(category, description, df)
+            .map(|result| result.with_spreadsheet_metadata(ods_file_path_str, sheet_name))
     }
 
     /// Parse mortality table from XLSX file using calamine.
     ///
     /// Reads XLSX files and automatically parses all columns as f64.
+    /// Select tables may be in long layout (`age`, `qx`, `duration`) or pivoted with one column
+    /// per duration, as written by `to_xlsx()`.
     /// Age and duration columns are validated to contain whole numbers during schema validation.
     ///
     /// Schema validation is performed by `from_df()` after parsing.
@@ -815,21 +1158,27 @@ impl MortData {
     ///
     /// // Select table with duration
     /// let mort_data = MortData::from_xlsx("data/am92_select.xlsx", "AM92")?;
+    ///
+    /// // Select table with one column per duration ("age", "Duration 0", "Durations 1+")
+    /// let mort_data = MortData::from_xlsx("data/am92_pivoted.xlsx", "AM92")?;
     ///# RSLifeResult::Ok(())
     /// ```
     pub fn from_xlsx(xlsx_file_path_str: &str, sheet_name: &str) -> RSLifeResult<Self> {
         // Open workbook
-        let mut workbook = open_workbook_auto(xlsx_file_path_str)
-            .map_err(|e| format!("Failed to open XLSX file '{xlsx_file_path_str}': {e}"))?;
+        let mut workbook = open_workbook_auto(xlsx_file_path_str).map_err(|e| {
+            RSLifeError::Parse(format!(
+                "Failed to open XLSX file '{xlsx_file_path_str}': {e}"
+            ))
+        })?;
 
         // Identify range
         let range = workbook
             .worksheet_range(sheet_name)
-            .map_err(|e| format!("Failed to read sheet '{sheet_name}': {e}"))?;
+            .map_err(|e| RSLifeError::Parse(format!("Failed to read sheet '{sheet_name}': {e}")))?;
 
         // Check if range is empty
         if range.is_empty() {
-            return Err(format!("Sheet '{sheet_name}' is empty").into());
+            return Err(RSLifeError::Parse(format!("Sheet '{sheet_name}' is empty")));
         }
 
         // Extract headers
@@ -840,7 +1189,7 @@ impl MortData {
 
         // Validate that we have data
         if data_cols.is_empty() || data_cols[0].is_empty() {
-            return Err("No data rows found in sheet".into());
+            return Err(RSLifeError::Parse("No data rows found in sheet".into()));
         }
 
         // Build DataFrame
@@ -851,14 +1200,121 @@ impl MortData {
             columns.push(series.into_column());
         }
         let height = columns[0].len();
-        let df = DataFrame::new(height, columns)
-            .map_err(|e| format!("Failed to create DataFrame: {e}"))?;
+        let mut df = DataFrame::new(height, columns)?;
+
+        // Select tables written as one column per duration
+        if is_pivoted(&headers) {
+            df = unpivot(&df)?;
+        }
 
         // Create MortData with a default category
         let category = "Custom Mortality Data".to_string();
         let description =
             "Created from XLSX file {xlsx_file_path_str}, sheet {sheet_name}.".to_string();
         Self::new(category, description, df)
+            .map(|result| result.with_spreadsheet_metadata(xlsx_file_path_str, sheet_name))
+    }
+
+    /// Parse mortality table from a CSV file with a header row.
+    ///
+    /// Columns are matched by name (case-insensitive): `age`, `qx` (or `lx`) and optionally
+    /// `duration`; other columns are ignored. Select tables with one column per duration
+    /// ("Duration 0", ..., "Durations 2+"), as in the IFOA workbooks and as written by
+    /// `to_csv()`, are pivoted to long layout.
+    ///
+    /// Use `from_csv_with_mapping()` for other column names.
+    ///
+    /// # Errors
+    /// - File not found or not readable
+    /// - Invalid CSV format
+    /// - Age or rate columns not found
+    /// - Schema validation errors (via `new`)
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// let mort_data = MortData::from_csv("data/am92.csv")?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn from_csv(csv_file_path_str: &str) -> RSLifeResult<Self> {
+        Self::from_csv_with_mapping(csv_file_path_str, &ColumnMapping::default())
+    }
+
+    /// Parse mortality table from a CSV file, with the column names given by `mapping`.
+    ///
+    /// Refer to `from_csv()` for details.
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// // Experience study output with columns Age, q, Dur, Exposure
+    /// let mapping = ColumnMapping::builder().age("Age").qx("q").duration("Dur").build();
+    /// let mort_data = MortData::from_csv_with_mapping("experience.csv", &mapping)?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn from_csv_with_mapping(
+        csv_file_path_str: &str,
+        mapping: &ColumnMapping,
+    ) -> RSLifeResult<Self> {
+        let df = CsvReadOptions::default()
+            .with_has_header(true)
+            .try_into_reader_with_file_path(Some(csv_file_path_str.into()))?
+            .finish()?;
+        Self::from_file_data(&df, mapping, TableSourceEnum::Csv, csv_file_path_str)
+    }
+
+    /// Parse mortality table from a Parquet file.
+    ///
+    /// Columns are matched as in `from_csv()`, including pivoted select tables.
+    /// Use `from_parquet_with_mapping()` for other column names.
+    ///
+    /// # Errors
+    /// - File not found or not readable
+    /// - Invalid Parquet format
+    /// - Age or rate columns not found
+    /// - Schema validation errors (via `new`)
+    ///
+    /// # Examples
+    /// ```rust, ignore
+    /// # use rslife::prelude::*;
+    /// let mort_data = MortData::from_parquet("data/am92.parquet")?;
+    ///# RSLifeResult::Ok(())
+    /// ```
+    pub fn from_parquet(parquet_file_path_str: &str) -> RSLifeResult<Self> {
+        Self::from_parquet_with_mapping(parquet_file_path_str, &ColumnMapping::default())
+    }
+
+    /// Parse mortality table from a Parquet file, with the column names given by `mapping`.
+    ///
+    /// Refer to `from_parquet()` for details.
+    pub fn from_parquet_with_mapping(
+        parquet_file_path_str: &str,
+        mapping: &ColumnMapping,
+    ) -> RSLifeResult<Self> {
+        let file = fs::File::open(parquet_file_path_str)?;
+        let df = ParquetReader::new(file).finish()?;
+        Self::from_file_data(
+            &df,
+            mapping,
+            TableSourceEnum::Parquet,
+            parquet_file_path_str,
+        )
+    }
+
+    /// Map and validate a table read from a CSV or Parquet file.
+    fn from_file_data(
+        df: &DataFrame,
+        mapping: &ColumnMapping,
+        source: TableSourceEnum,
+        file_path: &str,
+    ) -> RSLifeResult<Self> {
+        let data = mapping.apply(df)?;
+        let category = "Custom Mortality Data".to_string();
+        let description = format!("Created from file {file_path}.");
+        let mut result = Self::new(category, description, data)?;
+        result.metadata.source = source;
+        result.metadata.location = Some(file_path.to_string());
+        Ok(result)
     }
 
     // ========================================================
@@ -878,10 +1334,34 @@ impl MortData {
     /// # Errors
     /// Returns an error if the id is not in the builtin cache.
     pub fn from_builtin(id: &str) -> RSLifeResult<Self> {
-        super::builtin::BUILTIN_MORT_DATA
+        let mut result = super::builtin::BUILTIN_MORT_DATA
             .get(id)
             .cloned()
-            .ok_or_else(|| format!("Builtin mortality table '{id}' not available").into())
+            .ok_or_else(|| RSLifeError::UnknownTable(id.to_string()))?;
+
+        // Publisher metadata is kept from the underlying loader
+        result.metadata.source = TableSourceEnum::Builtin;
+        result.metadata.loaded_at = SystemTime::now();
+        Ok(result)
+    }
+
+    // ========================================================
+    // METADATA HELPERS
+    // ========================================================
+
+    /// Mark the table as generated from the parametric law `name`.
+    fn with_law_metadata(mut self, name: &str) -> Self {
+        self.metadata.source = TableSourceEnum::ParametricLaw;
+        self.metadata.table_identity = Some(name.to_string());
+        self
+    }
+
+    /// Mark the table as read from sheet `sheet_name` of the spreadsheet at `file_path`.
+    fn with_spreadsheet_metadata(mut self, file_path: &str, sheet_name: &str) -> Self {
+        self.metadata.source = TableSourceEnum::Spreadsheet;
+        self.metadata.location = Some(file_path.to_string());
+        self.metadata.table_identity = Some(sheet_name.to_string());
+        self
     }
 }
 
@@ -958,7 +1438,9 @@ fn is_soa_xml_data_approved(data: &SOAMortXML) -> bool {
 fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
     // Check if DataFrame is empty (rows count)
     if df.height() == 0 {
-        return Err("DataFrame must contain at least one row of data".into());
+        return Err(RSLifeError::Schema(
+            "DataFrame must contain at least one row of data".into(),
+        ));
     }
 
     let columns = df.columns();
@@ -969,7 +1451,9 @@ fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
     match cols_count {
         2 => {
             if !(col_names[0] == "age" && (col_names[1] == "qx" || col_names[1] == "lx")) {
-                return Err("DataFrame columns must be ['age', 'qx/lx']".into());
+                return Err(RSLifeError::Schema(
+                    "DataFrame columns must be ['age', 'qx/lx']".into(),
+                ));
             }
         }
 
@@ -977,12 +1461,18 @@ fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
             if !(col_names[0] == "age" && (col_names[1] == "qx")
                 || (col_names[1] == "lx") && col_names[2] == "duration")
             {
-                return Err("DataFrame columns must be ['age', 'qx/lx', 'duration']".into());
+                return Err(RSLifeError::Schema(
+                    "DataFrame columns must be ['age', 'qx/lx', 'duration']".into(),
+                ));
             }
         }
 
         _ => {
-            return Err("DataFrame must have 2 or 3 columns".into());
+            return Err(RSLifeError::Schema(
+                "DataFrame must have 2 or 3 columns; use MultiDecrementTable for tables with \
+                 several decrements"
+                    .into(),
+            ));
         }
     }
 
@@ -994,23 +1484,31 @@ fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
 
         // qx <= 1.0
         if col_name == "qx" && col.f64().unwrap().max().unwrap_or(0.0) > 1.0 {
-            return Err(format!("Column '{col_name}' must not exceed 1.0").into());
+            return Err(RSLifeError::Schema(format!(
+                "Column '{col_name}' must not exceed 1.0"
+            )));
         }
 
         if col_name == "lx" || col_name == "qx" {
             // Check if column is f64 convertible
             if col.f64().is_err() {
-                return Err(format!("Column '{col_name}' must be f64 convertible").into());
+                return Err(RSLifeError::Schema(format!(
+                    "Column '{col_name}' must be f64 convertible"
+                )));
             }
 
             // f64 >=0
             if col.f64().unwrap().min().unwrap_or(0.0) < 0.0 {
-                return Err(format!("Column '{col_name}' must be non-negative").into());
+                return Err(RSLifeError::Schema(format!(
+                    "Column '{col_name}' must be non-negative"
+                )));
             }
         } else {
             // Check if column can be cast to u32 (age and duration)
             if col.cast(&DataType::UInt32).is_err() {
-                return Err(format!("Column '{col_name}' must be u32 convertible").into());
+                return Err(RSLifeError::Schema(format!(
+                    "Column '{col_name}' must be u32 convertible"
+                )));
             }
 
             // Filter unique values and casted to u32
@@ -1028,10 +1526,9 @@ fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
                     .zip(values.iter().skip(1))
                     .all(|(a, b)| *b == *a + 1);
                 if !is_consecutive {
-                    return Err(format!(
+                    return Err(RSLifeError::Schema(format!(
                         "Column '{col_name}' must contain consecutive whole numbers (step 1)"
-                    )
-                    .into());
+                    )));
                 }
             }
         }
@@ -1043,6 +1540,18 @@ fn validate_df_schema(df: &DataFrame) -> RSLifeResult<()> {
 // ================================================
 // PRIVATE FUNCTIONS
 // ================================================
+/// `Some` for a non-empty string.
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Decode an XML document downloaded from `url`.
+fn soa_xml_string(url: &str, bytes: Vec<u8>) -> RSLifeResult<String> {
+    String::from_utf8(bytes)
+        .map_err(|e| RSLifeError::Parse(format!("XML from '{url}' is not valid UTF-8: {e}")))
+}
+
 fn keep_first_qx_1_remove_the_rest(ages: Vec<u32>, qx: Vec<f64>) -> RSLifeResult<DataFrame> {
     let mut found_one = false;
     let filtered: Vec<(u32, f64)> = ages
@@ -1098,6 +1607,8 @@ fn setup_dataframe_to_correct_schema(df: DataFrame) -> PolarsResult<DataFrame> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mt_config::improvement::tests::qx_at;
+    use crate::mt_config::metadata::TableSourceEnum;
 
     #[test]
     fn test_from_soa_xml_file() {
@@ -1206,6 +1717,123 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_from_soa_url_async_with_fetcher() {
+        use crate::mt_config::fetch::{AsyncFetcher, FetchFuture, OfflineFetcher};
+
+        /// Stand-in for an async HTTP server serving the ELT15 male document.
+        struct StubAsyncFetcher;
+
+        impl AsyncFetcher for StubAsyncFetcher {
+            fn fetch_async<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+                Box::pin(async move {
+                    assert_eq!(url, "https://mort.soa.org/data/t1704.xml");
+                    Ok(include_bytes!("../../data/elt15_m.xml").to_vec())
+                })
+            }
+        }
+
+        let url = "https://mort.soa.org/data/t1704.xml";
+        let mort_data = MortData::from_soa_url_async_with_fetcher(url, &StubAsyncFetcher)
+            .await
+            .unwrap();
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/elt15_m.xml");
+        let expected = MortData::from_soa_xml_file_path_str(path).unwrap();
+        assert!(mort_data.dataframe.equals(&expected.dataframe));
+        assert_eq!(mort_data.metadata.location.as_deref(), Some(url));
+
+        let result = MortData::from_soa_url_async_with_fetcher(url, &OfflineFetcher).await;
+        assert!(matches!(result, Err(RSLifeError::Offline(_))));
+    }
+
+    #[test]
+    fn test_fn_table_metadata() {
+        // SOA file: classification of the document and its location
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/elt15_m.xml");
+        let soa = MortData::from_soa_xml_file_path_str(path).unwrap();
+        assert_eq!(soa.metadata.source, TableSourceEnum::Soa);
+        assert_eq!(soa.metadata.location.as_deref(), Some(path));
+        assert_eq!(
+            soa.metadata.content_type.as_deref(),
+            Some("Population Mortality")
+        );
+        assert_eq!(soa.metadata.nation.as_deref(), Some("United Kingdom"));
+        assert!(soa.metadata.key_words.iter().any(|k| k == "Aggregate"));
+
+        // Builtin IFOA table keeps the publisher identity
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        assert_eq!(am92.metadata.source, TableSourceEnum::Builtin);
+        assert_eq!(am92.metadata.table_identity.as_deref(), Some("AM92"));
+        assert_eq!(
+            am92.metadata.provider.as_deref(),
+            Some("Institute and Faculty of Actuaries")
+        );
+        let am92_again = MortData::from_ifoa_url_id("AM92").unwrap();
+        assert_eq!(am92_again.metadata.source, TableSourceEnum::Ifoa);
+        assert_eq!(am92.metadata.content_hash, am92_again.metadata.content_hash);
+        // Served from the embedded workbook, not fetched
+        assert_eq!(am92_again.metadata.location, None);
+
+        // Parametric law
+        let sult = MortData::from_builtin("SULT").unwrap();
+        assert_eq!(sult.metadata.table_identity.as_deref(), Some("SULT"));
+        let gompertz = MortData::from_Gompertz_law()
+            .B(0.0003)
+            .C(1.07)
+            .call()
+            .unwrap();
+        assert_eq!(gompertz.metadata.source, TableSourceEnum::ParametricLaw);
+        assert_eq!(
+            gompertz.metadata.table_identity.as_deref(),
+            Some("Gompertz")
+        );
+
+        // Caller supplied DataFrame
+        let df = df! { "age" => [20u32, 21], "qx" => [0.1, 0.2] }.unwrap();
+        let custom = MortData::from_df(df).unwrap();
+        assert_eq!(custom.metadata.source, TableSourceEnum::DataFrame);
+        assert_eq!(custom.metadata.table_identity, None);
+        assert_ne!(custom.metadata.content_hash, gompertz.metadata.content_hash);
+    }
+
+    #[test]
+    fn test_invalid_law_parameters() {
+        use crate::mt_config::fetch::OfflineFetcher;
+
+        let results = [
+            MortData::from_Constant_Force_law().lambda(0.0).call(),
+            MortData::from_Gompertz_law().B(0.0003).C(1.0).call(),
+            MortData::from_Makeham_law()
+                .A(-1.0)
+                .B(0.0003)
+                .C(1.07)
+                .call(),
+            MortData::from_Weibull_law().k(-1.0).n(2.0).call(),
+            // Rejected before any download
+            MortData::from_aus_gov_act_with_fetcher("X", "2015-17", &OfflineFetcher),
+        ];
+        for result in results {
+            assert!(matches!(result, Err(RSLifeError::InvalidParameters(_))));
+        }
+    }
+
+    #[test]
+    fn test_from_soa_url_with_mirror() {
+        use crate::mt_config::fetch::MirrorFetcher;
+
+        // Local mirror of the SOA site, no network needed
+        let mirror = MirrorFetcher::new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+        let url = "https://mort.soa.org/data/elt15_f.xml";
+        let mort_data = MortData::from_soa_url_with_fetcher(url, &mirror).unwrap();
+        let expected = MortData::from_builtin("ELT15_F").unwrap();
+        assert!(mort_data.dataframe.equals(&expected.dataframe));
+
+        // The mirrored file is recorded, not the URL it stands in for
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/elt15_f.xml");
+        assert_eq!(mort_data.metadata.location.as_deref(), Some(path));
+    }
+
     #[test]
     fn test_from_xlsx_file() {
         // Test loading from XLSX file in data directory
@@ -1312,6 +1940,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_csv_with_mapping() {
+        let dir = std::env::temp_dir().join(format!("rslife_csv_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("experience.csv");
+        std::fs::write(
+            &path,
+            "Age,Dur,Exposure,q\n20,0,1000,0.001\n20,1,950,0.0015\n21,0,980,0.0011\n21,1,900,0.0016\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let mapping = ColumnMapping::builder()
+            .age("Age")
+            .qx("q")
+            .duration("Dur")
+            .build();
+        let mort_data = MortData::from_csv_with_mapping(path, &mapping).unwrap();
+        let names: Vec<&str> = mort_data
+            .dataframe
+            .get_column_names()
+            .iter()
+            .map(|n| n.as_str())
+            .collect();
+        assert_eq!(names, ["age", "qx", "duration"]);
+        assert_eq!(mort_data.dataframe.height(), 4);
+        assert_eq!(mort_data.metadata.source, TableSourceEnum::Csv);
+        assert_eq!(mort_data.metadata.location.as_deref(), Some(path));
+
+        // Default mapping does not find the rate column
+        assert!(MortData::from_csv(path).is_err());
+    }
+
+    #[test]
+    fn test_from_92_series_projection() {
+        // Year 2020 reproduces the C20 table
+        let projected = MortData::from_92_series_projection()
+            .base("PMA92")
+            .year(2020)
+            .call()
+            .unwrap();
+        let c20 = MortData::from_builtin("PMA92C20").unwrap();
+        for age in [50, 70, 90] {
+            assert!((qx_at(&projected, age) - qx_at(&c20, age)).abs() < 1e-15);
+        }
+
+        // Cohort born 1950: age 70 from the 2020 period table, age 60 from 2010
+        let cohort = MortData::from_92_series_projection()
+            .base("PMA92")
+            .year_of_birth(1950)
+            .call()
+            .unwrap();
+        let c10 = MortData::from_builtin("PMA92C10").unwrap();
+        assert!((qx_at(&cohort, 70) - qx_at(&c20, 70)).abs() < 1e-15);
+        assert!((qx_at(&cohort, 60) - qx_at(&c10, 60)).abs() < 1e-15);
+
+        // Select base table keeps its durations
+        let am92 = MortData::from_92_series_projection()
+            .base("AM92")
+            .year(2000)
+            .call()
+            .unwrap();
+        assert_eq!(am92.dataframe.width(), 3);
+
+        assert!(matches!(
+            MortData::from_92_series_projection()
+                .base("ELT15_F")
+                .year(2020)
+                .call(),
+            Err(RSLifeError::UnknownTable(_))
+        ));
+        assert!(matches!(
+            MortData::from_92_series_projection().base("PMA92").call(),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+    }
+
     #[test]
     fn test_from_df_basic() {
         // Test creating MortData from a basic DataFrame
@@ -1413,7 +2118,10 @@ mod tests {
         .expect("Failed to create invalid qx test DataFrame");
 
         let result2 = MortData::from_df(invalid_qx_df);
-        assert!(result2.is_err(), "Should fail with qx > 1.0");
+        assert!(
+            matches!(result2, Err(RSLifeError::Schema(_))),
+            "Should fail with qx > 1.0"
+        );
         println!("✓ Correctly rejected DataFrame with qx > 1.0");
 
         // Test 3: Negative values
@@ -1427,4 +2135,10 @@ mod tests {
         assert!(result3.is_err(), "Should fail with negative values");
         println!("✓ Correctly rejected DataFrame with negative values");
     }
+
+    #[test]
+    fn test_unknown_builtin() {
+        let result = MortData::from_builtin("XYZ92");
+        assert!(matches!(result, Err(RSLifeError::UnknownTable(id)) if id == "XYZ92"));
+    }
 }
@@ -17,8 +17,13 @@
 //! - **DataFrame**: Columnar data (age, value, optional duration)
 //! - **Metadata**: Scaling factors, descriptions, axis definitions
 //! - **Classification**: Table ID, provider, keywords for discovery
+//!
+//! Documents may hold several tables. [`SOAMortXML::values()`] combines a select table with its
+//! ultimate table into one attained age/duration table; [`SOAMortXML::table_values()`] picks a
+//! single table by index.
 
-use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+use crate::{RSLifeError, RSLifeResult};
 use polars::prelude::*;
 
 /// XTbML axis definition for table dimensions.
@@ -35,7 +40,7 @@ pub struct AxisDef {
     pub min_scale_value: u32,
     /// Maximum value on this axis (inclusive)
     pub max_scale_value: u32,
-    /// Step size between consecutive values
+    /// Step size between consecutive values (values are expanded to steps of 1 when parsed)
     pub increment: u32,
 }
 
@@ -46,7 +51,7 @@ pub struct AxisDef {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct MetaData {
-    /// Scaling factor applied to all table values (typically 1.0, 1000.0, or 1000000.0)
+    /// Scaling factor of the published values (0 or 1 for unscaled, 1000.0 per mille, 1000000.0 per million)
     pub scaling_factor: f64,
     /// Type of data contained in the table (e.g., "Mortality Rate", "Disability Rate")
     pub data_type: String,
@@ -127,10 +132,14 @@ impl SOAMortXML {
     ///
     /// Core parsing method used by all other loading functions.
     ///
+    /// Values are divided by the table `ScalingFactor`, and axes with an `Increment` above 1
+    /// are expanded to steps of 1 (each value applying until the next grid point).
+    ///
     /// # Errors
     /// - Invalid XML format
     /// - Missing required XTbML elements
     /// - Malformed numeric data
+    /// - Axis values outside `MinScaleValue`/`MaxScaleValue` or off the `Increment` grid
     ///
     /// # Example
     /// ```rust, ignore
@@ -150,7 +159,7 @@ impl SOAMortXML {
     ///   </ContentClassification>
     ///   <Table>
     ///     <MetaData>
-    ///       <ScalingFactor>1000000</ScalingFactor>
+    ///       <ScalingFactor>0</ScalingFactor>
     ///       <DataType>Mortality Rate</DataType>
     ///       <Nation>United States</Nation>
     ///       <TableDescription>Test mortality rates</TableDescription>
@@ -171,8 +180,10 @@ impl SOAMortXML {
         let content_classification = create_content_classification(&root)?;
         let tables = create_tables(&root)?;
 
-        if tables.len() != 1 {
-            return Err("SOAMortXML must contain exactly one table".into());
+        if tables.is_empty() {
+            return Err(RSLifeError::Parse(
+                "SOAMortXML must contain at least one table".into(),
+            ));
         }
 
         let result = SOAMortXML {
@@ -182,6 +193,73 @@ impl SOAMortXML {
 
         Ok(result)
     }
+
+    /// Table at `index` in document order.
+    ///
+    /// # Errors
+    /// - `index` beyond the number of tables in the document
+    pub fn table(&self, index: usize) -> RSLifeResult<&Table> {
+        self.tables.get(index).ok_or_else(|| {
+            invalid_parameter(
+                "index",
+                format!(
+                    "Table index {index} out of range, document contains {} table(s)",
+                    self.tables.len()
+                ),
+            )
+        })
+    }
+
+    /// Values of the table at `index` in the `age`/`qx`/`duration` layout of `MortData`.
+    ///
+    /// Select tables are converted from issue age and duration to attained age and
+    /// duration since selection starting at 0.
+    pub fn table_values(&self, index: usize) -> RSLifeResult<DataFrame> {
+        let table = self.table(index)?;
+        if table.is_select() {
+            let (values, _) = select_to_attained_age(&table.values)?;
+            Ok(values)
+        } else {
+            Ok(table.values.clone())
+        }
+    }
+
+    /// Values of the whole document in the `age`/`qx`/`duration` layout of `MortData`.
+    ///
+    /// - One table: its values (see [`table_values()`](Self::table_values))
+    /// - One select and one ultimate table: combined into a single 2D table where the
+    ///   select durations are followed by the ultimate rates at the final duration; ages
+    ///   below the ultimate table take their longest select duration as the ultimate rate
+    ///
+    /// # Errors
+    /// - Any other combination of tables, which must be picked by index instead
+    pub fn values(&self) -> RSLifeResult<DataFrame> {
+        match self.tables.as_slice() {
+            [_] => self.table_values(0),
+            [first, second] if first.is_select() != second.is_select() => {
+                let (select, ultimate) = if first.is_select() {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                combine_select_and_ultimate(&select.values, &ultimate.values)
+            }
+            tables => Err(RSLifeError::Parse(format!(
+                "XTbML document contains {} tables that do not form a select and ultimate table, select one by index",
+                tables.len()
+            ))),
+        }
+    }
+}
+
+impl Table {
+    /// Whether the table has a duration axis (select table).
+    pub fn is_select(&self) -> bool {
+        self.values
+            .get_column_names()
+            .iter()
+            .any(|name| name.as_str() == "duration")
+    }
 }
 
 //-----------------------------------------------------------------
@@ -194,14 +272,14 @@ fn create_content_classification(root: &roxmltree::Node) -> RSLifeResult<Content
     let cc = root
         .descendants()
         .find(|n| n.tag_name().name() == "ContentClassification")
-        .ok_or("ContentClassification element not found")?;
+        .ok_or_else(|| RSLifeError::Parse("ContentClassification element not found".into()))?;
 
     let table_identity = cc
         .descendants()
         .find(|n| n.tag_name().name() == "TableIdentity")
         .and_then(|n| n.text())
         .and_then(|t| t.parse::<i32>().ok())
-        .ok_or("TableIdentity not found or invalid")?;
+        .ok_or_else(|| RSLifeError::Parse("TableIdentity not found or invalid".into()))?;
 
     let provider_domain = cc
         .descendants()
@@ -295,16 +373,137 @@ fn create_tables(root: &roxmltree::Node) -> RSLifeResult<Vec<Table>> {
 fn create_table(table_node: &roxmltree::Node) -> RSLifeResult<Table> {
     let meta_data = create_meta_data(table_node)?;
     let values = create_values(table_node)?;
+    let values = apply_axis_defs(&meta_data.axis_defs, values)?;
+    let values = apply_scaling_factor(meta_data.scaling_factor, values)?;
     let result = Table { meta_data, values };
     Ok(result)
 }
 
+/// Divide table values by the XTbML scaling factor.
+///
+/// Rates published per mille carry a scaling factor of 1000, per million 1000000.
+/// A scaling factor of 0 (as used by most SOA tables) or 1 means the values are unscaled.
+fn apply_scaling_factor(scaling_factor: f64, values: DataFrame) -> RSLifeResult<DataFrame> {
+    if !scaling_factor.is_finite() || scaling_factor < 0.0 {
+        return Err(RSLifeError::Parse(format!(
+            "Invalid ScalingFactor {scaling_factor}"
+        )));
+    }
+    if scaling_factor == 0.0 || scaling_factor == 1.0 {
+        return Ok(values);
+    }
+
+    let value_col = value_column_name(&values)?;
+    let scaled = values
+        .lazy()
+        .with_column(col(value_col) / lit(scaling_factor))
+        .collect()?;
+    Ok(scaled)
+}
+
+/// Check the table values against the axis definitions and expand non-unit increments.
+///
+/// Axis definitions map to the table dimensions in order: the first to `age` and, for 2D
+/// tables, the second to `duration`. Each axis must span exactly `MinScaleValue` to
+/// `MaxScaleValue` with points on the `Increment` grid. Axes with an increment above 1
+/// are expanded to every whole value, each value applying until the next grid point.
+fn apply_axis_defs(axis_defs: &[AxisDef], values: DataFrame) -> RSLifeResult<DataFrame> {
+    let has_duration = values
+        .get_column_names()
+        .iter()
+        .any(|name| name.as_str() == "duration");
+    let dimensions: &[&str] = if has_duration {
+        &["age", "duration"]
+    } else {
+        &["age"]
+    };
+
+    if axis_defs.len() > dimensions.len() {
+        return Err(RSLifeError::Parse(format!(
+            "Table defines {} axes but its values have {} dimension(s)",
+            axis_defs.len(),
+            dimensions.len()
+        )));
+    }
+
+    let mut values = values;
+    for (axis_def, column) in axis_defs.iter().zip(dimensions) {
+        validate_axis(axis_def, &values, column)?;
+        if axis_def.increment > 1 {
+            values = expand_axis(axis_def, &values, column)?;
+        }
+    }
+
+    Ok(values)
+}
+
+/// Validate the range and grid of one axis against the values present in `column`.
+fn validate_axis(axis_def: &AxisDef, values: &DataFrame, column: &str) -> RSLifeResult<()> {
+    let name = &axis_def.axis_name;
+    let points = values.column(column)?.f64()?;
+    let (min, max) = match (points.min(), points.max()) {
+        (Some(min), Some(max)) => (min, max),
+        _ => return Err(RSLifeError::Parse(format!("Axis '{name}' has no values"))),
+    };
+
+    if axis_def.increment == 0 {
+        return Err(RSLifeError::Parse(format!(
+            "Axis '{name}' has an increment of 0"
+        )));
+    }
+
+    let (min_scale, max_scale) = (
+        axis_def.min_scale_value as f64,
+        axis_def.max_scale_value as f64,
+    );
+    if min != min_scale || max != max_scale {
+        return Err(RSLifeError::Parse(format!(
+            "Axis '{name}' is defined from {min_scale} to {max_scale} but the table covers {min} to {max}"
+        )));
+    }
+
+    let increment = axis_def.increment as f64;
+    if let Some(point) = points
+        .into_no_null_iter()
+        .find(|point| (point - min_scale) % increment != 0.0)
+    {
+        return Err(RSLifeError::Parse(format!(
+            "Axis '{name}' value {point} is not on its increment of {increment} from {min_scale}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Repeat each row of `values` for every whole value of `column` up to the next grid point.
+fn expand_axis(axis_def: &AxisDef, values: &DataFrame, column: &str) -> RSLifeResult<DataFrame> {
+    let max_scale = axis_def.max_scale_value as f64;
+    let mut rows: Vec<IdxSize> = Vec::new();
+    let mut points: Vec<f64> = Vec::new();
+
+    for (row, point) in values.column(column)?.f64()?.iter().enumerate() {
+        let Some(point) = point else { continue };
+        for step in 0..axis_def.increment {
+            let expanded = point + step as f64;
+            if expanded > max_scale {
+                break;
+            }
+            rows.push(row as IdxSize);
+            points.push(expanded);
+        }
+    }
+
+    let mut expanded = values.take(&IdxCa::from_vec("row".into(), rows))?;
+    expanded.with_column(Series::new(column.into(), points).into_column())?;
+    Ok(expanded)
+}
+
 /// Parse MetaData element containing scaling factor, data type, and axis definitions.
 fn create_meta_data(table_node: &roxmltree::Node) -> RSLifeResult<MetaData> {
     let metadata_node = table_node
         .descendants()
         .find(|n| n.tag_name().name() == "MetaData")
-        .ok_or("MetaData element not found")?;
+        .ok_or_else(|| RSLifeError::Parse("MetaData element not found".into()))?;
 
     let scaling_factor = metadata_node
         .descendants()
@@ -466,6 +665,96 @@ fn create_values(table_node: &roxmltree::Node) -> RSLifeResult<DataFrame> {
     Ok(result)
 }
 
+/// Name of the rate column (`qx` or `lx`) of parsed table values.
+fn value_column_name(values: &DataFrame) -> RSLifeResult<&'static str> {
+    let names = values.get_column_names();
+    if names.iter().any(|name| name.as_str() == "lx") {
+        Ok("lx")
+    } else if names.iter().any(|name| name.as_str() == "qx") {
+        Ok("qx")
+    } else {
+        Err(RSLifeError::Parse("Table has no qx or lx values".into()))
+    }
+}
+
+/// Convert select table values from issue age and duration to attained age and duration since selection.
+///
+/// XTbML select tables are indexed by issue age with durations starting at 1, while `MortData`
+/// expects attained age with durations starting at 0. Returns the converted values together with
+/// the number of select durations.
+fn select_to_attained_age(values: &DataFrame) -> RSLifeResult<(DataFrame, u32)> {
+    let durations = values.column("duration")?.f64()?;
+    let (min_duration, max_duration) = match (durations.min(), durations.max()) {
+        (Some(min), Some(max)) => (min, max),
+        _ => return Err(RSLifeError::Parse("Select table has no durations".into())),
+    };
+    let select_period = (max_duration - min_duration) as u32 + 1;
+
+    let converted = values
+        .clone()
+        .lazy()
+        .with_columns([
+            (col("age") + col("duration") - lit(min_duration)).alias("age"),
+            (col("duration") - lit(min_duration)).alias("duration"),
+        ])
+        .collect()?;
+
+    Ok((converted, select_period))
+}
+
+/// Combine a select table with its ultimate table into one 2D table.
+///
+/// The ultimate rates are placed at the duration following the select period, which the
+/// lookups of `MortTableConfig` treat as the ultimate duration. Attained ages of the select
+/// table below the youngest ultimate age take their longest select duration as the ultimate
+/// rate, so that every age of the combined table has an ultimate row.
+fn combine_select_and_ultimate(
+    select: &DataFrame,
+    ultimate: &DataFrame,
+) -> RSLifeResult<DataFrame> {
+    let value_col = value_column_name(select)?;
+    if value_column_name(ultimate)? != value_col {
+        return Err(RSLifeError::Parse(
+            "Select and ultimate tables must contain the same kind of values".into(),
+        ));
+    }
+
+    let (select, select_period) = select_to_attained_age(select)?;
+    let min_ultimate_age = ultimate
+        .column("age")?
+        .f64()?
+        .min()
+        .ok_or_else(|| RSLifeError::Parse("Ultimate table has no ages".into()))?;
+    let ultimate = ultimate
+        .clone()
+        .lazy()
+        .with_column(lit(select_period as f64).alias("duration"));
+
+    // Ultimate rows for the young attained ages only reached during selection
+    let filled = select
+        .clone()
+        .lazy()
+        .filter(col("age").lt(lit(min_ultimate_age)))
+        .sort(["age", "duration"], Default::default())
+        .group_by_stable([col("age")])
+        .agg([col(value_col).last()])
+        .with_column(lit(select_period as f64).alias("duration"));
+
+    let columns = [col("age"), col(value_col), col("duration")];
+    let combined = concat(
+        [
+            select.lazy().select(columns.clone()),
+            filled.select(columns.clone()),
+            ultimate.select(columns),
+        ],
+        Default::default(),
+    )?
+    .sort(["age", "duration"], Default::default())
+    .collect()?;
+
+    Ok(combined)
+}
+
 type AxisValues = (Vec<Option<f64>>, Vec<Option<f64>>, Vec<f64>);
 
 /// Extract mortality values from single Axis element.
@@ -487,7 +776,7 @@ fn get_axis_values(axis_node: &roxmltree::Node) -> RSLifeResult<AxisValues> {
         let value = text.and_then(|t| t.parse::<f64>().ok());
 
         if value.is_none() {
-            return Err("Invalid value in Y node".into());
+            return Err(RSLifeError::Parse("Invalid value in Y node".into()));
         }
 
         let value = value.unwrap();
@@ -518,4 +807,215 @@ fn get_axis_values(axis_node: &roxmltree::Node) -> RSLifeResult<AxisValues> {
 //-----------------------------------------------------------------
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// Select (issue ages 20-21, durations 1-2) and ultimate (ages 22-24) tables in one document.
+    const SELECT_ULTIMATE_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<XTbML>
+  <ContentClassification>
+    <TableIdentity>9001</TableIdentity>
+    <ProviderName>Test</ProviderName>
+    <ContentType tc="1">CSO / CET</ContentType>
+    <TableName>Test Select and Ultimate</TableName>
+    <TableDescription>Test select and ultimate table</TableDescription>
+    <KeyWord>Select &amp; Ultimate</KeyWord>
+  </ContentClassification>
+  <Table>
+    <MetaData>
+      <ScalingFactor>0</ScalingFactor>
+      <TableDescription>Select rates</TableDescription>
+      <AxisDef><ScaleType>Age</ScaleType><AxisName>Issue Age</AxisName><MinScaleValue>20</MinScaleValue><MaxScaleValue>21</MaxScaleValue><Increment>1</Increment></AxisDef>
+      <AxisDef><ScaleType>Duration</ScaleType><AxisName>Duration</AxisName><MinScaleValue>1</MinScaleValue><MaxScaleValue>2</MaxScaleValue><Increment>1</Increment></AxisDef>
+    </MetaData>
+    <Values>
+      <Axis t="20"><Axis><Y t="1">0.001</Y><Y t="2">0.002</Y></Axis></Axis>
+      <Axis t="21"><Axis><Y t="1">0.0011</Y><Y t="2">0.0021</Y></Axis></Axis>
+    </Values>
+  </Table>
+  <Table>
+    <MetaData>
+      <ScalingFactor>0</ScalingFactor>
+      <TableDescription>Ultimate rates</TableDescription>
+      <AxisDef><ScaleType>Age</ScaleType><AxisName>Attained Age</AxisName><MinScaleValue>22</MinScaleValue><MaxScaleValue>24</MaxScaleValue><Increment>1</Increment></AxisDef>
+    </MetaData>
+    <Values>
+      <Axis><Y t="22">0.003</Y><Y t="23">0.004</Y><Y t="24">0.005</Y></Axis>
+    </Values>
+  </Table>
+</XTbML>"#;
+
+    /// One-dimensional table document with the given metadata and `(age, value)` points.
+    fn aggregate_xml(
+        scaling_factor: &str,
+        axis: (u32, u32, u32),
+        points: &[(u32, &str)],
+    ) -> String {
+        let (min, max, increment) = axis;
+        let values: String = points
+            .iter()
+            .map(|(age, value)| format!(r#"<Y t="{age}">{value}</Y>"#))
+            .collect();
+        format!(
+            r#"<XTbML><ContentClassification><TableIdentity>9002</TableIdentity></ContentClassification>
+<Table><MetaData><ScalingFactor>{scaling_factor}</ScalingFactor>
+<AxisDef><ScaleType>Age</ScaleType><AxisName>Age</AxisName><MinScaleValue>{min}</MinScaleValue><MaxScaleValue>{max}</MaxScaleValue><Increment>{increment}</Increment></AxisDef>
+</MetaData><Values><Axis>{values}</Axis></Values></Table></XTbML>"#
+        )
+    }
+
+    fn rows(df: &DataFrame) -> Vec<(f64, f64, f64)> {
+        let age = df.column("age").unwrap().cast(&DataType::Float64).unwrap();
+        let qx = df.column("qx").unwrap().cast(&DataType::Float64).unwrap();
+        let duration = df
+            .column("duration")
+            .unwrap()
+            .cast(&DataType::Float64)
+            .unwrap();
+        (0..df.height())
+            .map(|i| {
+                (
+                    age.f64().unwrap().get(i).unwrap(),
+                    duration.f64().unwrap().get(i).unwrap(),
+                    qx.f64().unwrap().get(i).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fn_select_and_ultimate_combined() {
+        let xml = SOAMortXML::from_string(SELECT_ULTIMATE_XML).unwrap();
+        assert_eq!(xml.tables.len(), 2);
+        assert!(xml.tables[0].is_select());
+        assert!(!xml.tables[1].is_select());
+
+        // (attained age, duration since selection, qx)
+        // Attained ages 20 and 21 have no ultimate rate and take their longest select duration
+        let expected = vec![
+            (20.0, 0.0, 0.001),
+            (20.0, 2.0, 0.001),
+            (21.0, 0.0, 0.0011),
+            (21.0, 1.0, 0.002),
+            (21.0, 2.0, 0.002),
+            (22.0, 1.0, 0.0021),
+            (22.0, 2.0, 0.003),
+            (23.0, 2.0, 0.004),
+            (24.0, 2.0, 0.005),
+        ];
+        assert_eq!(rows(&xml.values().unwrap()), expected);
+    }
+
+    #[test]
+    fn test_fn_table_by_index() {
+        let xml = SOAMortXML::from_string(SELECT_ULTIMATE_XML).unwrap();
+
+        let select = xml.table_values(0).unwrap();
+        assert_eq!(select.height(), 4);
+        assert_eq!(rows(&select)[1], (21.0, 1.0, 0.002));
+
+        let ultimate = xml.table_values(1).unwrap();
+        assert_eq!(ultimate.height(), 3);
+        assert!(!ultimate.get_column_names().contains(&&"duration".into()));
+
+        assert!(matches!(
+            xml.table(2),
+            Err(RSLifeError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_scaling_factor_and_increment() {
+        // Per mille rates every 5 years of age
+        let xml = aggregate_xml(
+            "1000",
+            (20, 30, 5),
+            &[(20, "1.0"), (25, "2.0"), (30, "3.0")],
+        );
+        let values = SOAMortXML::from_string(&xml)
+            .unwrap()
+            .table_values(0)
+            .unwrap();
+
+        let ages: Vec<f64> = values
+            .column("age")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        let qx: Vec<f64> = values
+            .column("qx")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(ages, (20..=30).map(f64::from).collect::<Vec<_>>());
+        assert_eq!(&qx[..5], &[0.001; 5]);
+        assert_eq!(&qx[5..10], &[0.002; 5]);
+        assert_eq!(qx[10], 0.003);
+
+        // Unscaled tables keep their values
+        let xml = aggregate_xml("0", (20, 21, 1), &[(20, "0.5"), (21, "0.6")]);
+        let values = SOAMortXML::from_string(&xml)
+            .unwrap()
+            .table_values(0)
+            .unwrap();
+        assert_eq!(
+            values.column("qx").unwrap().f64().unwrap().get(1),
+            Some(0.6)
+        );
+    }
+
+    #[test]
+    fn test_fn_axis_definition_mismatch() {
+        // Declared maximum age beyond the data
+        let xml = aggregate_xml("0", (20, 25, 1), &[(20, "0.1"), (21, "0.2")]);
+        assert!(matches!(
+            SOAMortXML::from_string(&xml),
+            Err(RSLifeError::Parse(_))
+        ));
+
+        // Point off the increment grid
+        let xml = aggregate_xml("0", (20, 30, 5), &[(20, "0.1"), (23, "0.2"), (30, "0.3")]);
+        assert!(matches!(
+            SOAMortXML::from_string(&xml),
+            Err(RSLifeError::Parse(_))
+        ));
+
+        // Zero increment
+        let xml = aggregate_xml("0", (20, 21, 0), &[(20, "0.1"), (21, "0.2")]);
+        assert!(matches!(
+            SOAMortXML::from_string(&xml),
+            Err(RSLifeError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_select_and_ultimate_mort_data() {
+        let data = MortData::from_soa_xml_string(SELECT_ULTIMATE_XML).unwrap();
+        assert_eq!(data.dataframe.width(), 3);
+        assert_eq!(data.description, "Test select and ultimate table");
+
+        let mt = MortTableConfig::builder().data(data).build().unwrap();
+        // Select rates for a life selected at 20, ultimate rates after two years
+        let p20 = tpx().mt(&mt).x(20.0).t(1.0).entry_age(20).call().unwrap();
+        let p21 = tpx().mt(&mt).x(21.0).t(1.0).entry_age(20).call().unwrap();
+        let p22 = tpx().mt(&mt).x(22.0).t(1.0).entry_age(20).call().unwrap();
+        assert!((p20 - (1.0 - 0.001)).abs() < 1e-12);
+        assert!((p21 - (1.0 - 0.002)).abs() < 1e-12);
+        assert!((p22 - (1.0 - 0.003)).abs() < 1e-12);
+
+        // Ultimate rates at every age, including those only reached during selection
+        let p20_ultimate = tpx().mt(&mt).x(20.0).t(1.0).call().unwrap();
+        assert!((p20_ultimate - (1.0 - 0.001)).abs() < 1e-12);
+        let p21_ultimate = tpx().mt(&mt).x(21.0).t(1.0).call().unwrap();
+        assert!((p21_ultimate - (1.0 - 0.002)).abs() < 1e-12);
+
+        let ultimate = MortData::from_soa_xml_string_with_table(SELECT_ULTIMATE_XML, 1).unwrap();
+        assert_eq!(ultimate.dataframe.width(), 2);
+        assert_eq!(ultimate.description, "Ultimate rates");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::RSLifeResult;
+use crate::{RSLifeError, RSLifeResult};
 use calamine::{Data, Range};
 use spreadsheet_ods::Value;
 
@@ -85,7 +85,9 @@ pub fn parse_ods_f64_cell(cell_value: &Value, row_num: usize, col_name: &str) ->
         Value::Empty => Ok(f64::NAN),
         // Convert boolean to f64: true -> 1.0, false -> 0.0
         Value::Boolean(b) => Ok(if *b { 1.0 } else { 0.0 }),
-        other => Err(format!("Invalid {col_name} cell type {other:?} at row {row_num}").into()),
+        other => Err(RSLifeError::Parse(format!(
+            "Invalid {col_name} cell type {other:?} at row {row_num}"
+        ))),
     }
 }
 
@@ -97,7 +99,7 @@ pub fn parse_excel_headers(
 ) -> RSLifeResult<Vec<String>> {
     // If the first cell in the header row is None, return error
     if range.get((start_row, 0)).is_none() {
-        return Err("Header row is empty".into());
+        return Err(RSLifeError::Parse("Header row is empty".into()));
     }
 
     // Initialize
@@ -182,9 +184,11 @@ fn parse_excel_f64_cell(
         }
         Some(Data::Bool(b)) => Ok(if *b { 1.0 } else { 0.0 }),
         Some(Data::Empty) => Ok(f64::NAN),
-        Some(other) => {
-            Err(format!("Invalid {col_name} cell type {other:?} at row {row_num}").into())
-        }
-        None => Err(format!("Missing {col_name} cell at row {row_num}").into()),
+        Some(other) => Err(RSLifeError::Parse(format!(
+            "Invalid {col_name} cell type {other:?} at row {row_num}"
+        ))),
+        None => Err(RSLifeError::Parse(format!(
+            "Missing {col_name} cell at row {row_num}"
+        ))),
     }
 }
@@ -1,9 +1,11 @@
+use crate::RSLifeResult;
 use crate::mt_config::MortTableConfig;
 use bon::Builder;
 use garde::Validate;
 
 use super::validation::{
-    ErrorVec, collect_age_bounds_errors, validate_age_boundaries, validate_entry_age,
+    ErrorVec, collect_age_bounds_errors, errors_to_result, validate_age_boundaries,
+    validate_entry_age,
 };
 
 #[derive(Debug, Clone, Validate, Builder)]
@@ -26,8 +28,8 @@ pub struct GetValueFunctionValidation {
 }
 
 impl GetValueFunctionValidation {
-    /// Validate with cross-field validation using Result<(), garde::Report>
-    pub fn validate_all(&self) -> Result<(), garde::Report> {
+    /// Validate with cross-field validation, age out of range reported as `RSLifeError::AgeOutOfRange`
+    pub fn validate_all(&self) -> RSLifeResult<()> {
         // First run garde's built-in validations
         self.validate()?;
 
@@ -36,8 +38,7 @@ impl GetValueFunctionValidation {
     }
 
     /// Custom cross-field validations that garde can't handle with attributes
-    fn validate_custom_constraints(&self) -> Result<(), garde::Report> {
-        let mut report = garde::Report::new();
+    fn validate_custom_constraints(&self) -> RSLifeResult<()> {
         let mut errors: ErrorVec = Vec::new();
 
         // Get age bounds and collect any errors
@@ -46,31 +47,17 @@ impl GetValueFunctionValidation {
         // If we can't get age bounds, return early
         let (min_age, max_age) = match age_bounds {
             Some(bounds) => bounds,
-            None => {
-                for (path, message) in errors {
-                    report.append(garde::Path::new(path), garde::Error::new(message));
-                }
-                return Err(report);
-            }
+            None => return errors_to_result(errors, None),
         };
 
         // Validate age boundaries
-        validate_age_boundaries(self.x, min_age, max_age, &mut errors);
+        let age_error = validate_age_boundaries("x", self.x, min_age, max_age, &mut errors);
 
         // Validate entry age constraints
         validate_entry_age(self.entry_age, self.x, &mut errors);
 
-        // Convert errors to report
-        for (path, message) in errors {
-            report.append(garde::Path::new(path), garde::Error::new(message));
-        }
-
-        // Return Ok if no errors, otherwise return the complete report
-        if report.is_empty() {
-            Ok(())
-        } else {
-            Err(report)
-        }
+        // Age out of range on its own, otherwise the complete report
+        errors_to_result(errors, age_error)
     }
 }
 
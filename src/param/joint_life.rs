@@ -0,0 +1,232 @@
+use crate::RSLifeResult;
+use crate::mt_config::MortTableConfig;
+use bon::Builder;
+use garde::Validate;
+
+use super::validation::{
+    ErrorVec, collect_age_bounds_errors, errors_to_result, validate_age_boundaries,
+    validate_entry_age,
+};
+
+#[derive(Debug, Clone, Validate, Builder)]
+#[garde(allow_unvalidated)]
+pub struct JointLifeParams {
+    // Mortality table configuration for life x
+    // Validate when ParamConfig is validated
+    #[garde(dive)]
+    pub mt_x: MortTableConfig,
+
+    // Mortality table configuration for life y
+    // Validate when ParamConfig is validated
+    #[garde(dive)]
+    pub mt_y: MortTableConfig,
+
+    // Interest rate for actuarial calculations
+    pub i: f64,
+
+    // Age of first life - Cannot exceed min and max from mt_x
+    #[garde(range(min = 0.0, max = 130.0))]
+    pub x: f64,
+
+    // Age of second life - Cannot exceed min and max from mt_y
+    #[garde(range(min = 0.0, max = 130.0))]
+    pub y: f64,
+
+    // Term - the status cannot outlive the longer of the two remaining lifetimes
+    // n can be 0 for some actuarial calculations
+    #[garde(range(min = 0.0, max = 130.0))]
+    pub n: f64,
+
+    // Deferral period
+    #[garde(range(min = 0.0, max = 130.0))]
+    pub t: f64,
+
+    // Payable m-thly
+    #[garde(range(min = 1))]
+    pub m: u32,
+
+    // Mathematical moment, 1 is the first moment (mean), 2 is the second moment (variance)
+    #[garde(range(min = 1))]
+    pub moment: u32,
+
+    // Entry age of first life for select-ultimate tables,
+    // Entry age cannot exceed age x
+    #[garde(range(min = 0, max = 130))]
+    pub entry_age_x: Option<u32>,
+
+    // Entry age of second life for select-ultimate tables,
+    // Entry age cannot exceed age y
+    #[garde(range(min = 0, max = 130))]
+    pub entry_age_y: Option<u32>,
+}
+
+impl JointLifeParams {
+    /// Validate with cross-field validation, age out of range reported as `RSLifeError::AgeOutOfRange`
+    pub fn validate_all(&self) -> RSLifeResult<()> {
+        // First run garde's built-in validations
+        self.validate()?;
+
+        // Then run our custom cross-field validations
+        self.validate_custom_constraints()
+    }
+
+    /// Custom cross-field validations that garde can't handle with attributes
+    fn validate_custom_constraints(&self) -> RSLifeResult<()> {
+        let mut errors: ErrorVec = Vec::new();
+
+        // Get age bounds of both tables and collect any errors
+        let age_bounds_x = collect_age_bounds_errors(&self.mt_x, &mut errors);
+        let age_bounds_y = collect_age_bounds_errors(&self.mt_y, &mut errors);
+
+        // If we can't get age bounds, return early
+        let ((min_age_x, max_age_x), (min_age_y, max_age_y)) = match (age_bounds_x, age_bounds_y) {
+            (Some(bounds_x), Some(bounds_y)) => (bounds_x, bounds_y),
+            _ => return errors_to_result(errors, None),
+        };
+
+        // Validate age boundaries
+        let y = self.y;
+        let age_error_x = validate_age_boundaries("x", self.x, min_age_x, max_age_x, &mut errors);
+        let age_error_y = validate_age_boundaries("y", y, min_age_y, max_age_y, &mut errors);
+        let age_error = age_error_x.or(age_error_y);
+
+        // Validate term constraints (custom for JointLifeParams)
+        // Beyond the longer remaining lifetime, every status has failed
+        let x = self.x;
+        let t = self.t;
+        let n = self.n;
+        let max_term = f64::max(max_age_x - x, max_age_y - y);
+        if age_error.is_none() && t + n > max_term {
+            errors.push(("", format!(
+                "deferral + term ({t} + {n}) cannot exceed the longer remaining lifetime {max_term} from mortality tables"
+            )));
+        }
+
+        // Validate entry age constraints
+        validate_entry_age(self.entry_age_x, x, &mut errors);
+        if let Some(entry_age_y) = self.entry_age_y
+            && entry_age_y as f64 > y
+        {
+            errors.push((
+                "entry_age_y",
+                format!("entry_age_y {entry_age_y} cannot exceed age {y}"),
+            ));
+        }
+
+        // Age out of range on its own, otherwise the complete report
+        errors_to_result(errors, age_error)
+    }
+}
+
+// =============================================================================
+// UNIT TEST
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+
+    fn configs() -> (MortTableConfig, MortTableConfig) {
+        let pma92c20 = MortData::from_builtin("PMA92C20").unwrap();
+        let pfa92c20 = MortData::from_builtin("PFA92C20").unwrap();
+        let mt_x = MortTableConfig::builder().data(pma92c20).build().unwrap();
+        let mt_y = MortTableConfig::builder().data(pfa92c20).build().unwrap();
+        (mt_x, mt_y)
+    }
+
+    // ------------------------------- Age -------------------------------------
+
+    #[test]
+    fn test_age_y_outside_table() {
+        let (mt_x, mt_y) = configs();
+        let min_age_y = mt_y.min_age().unwrap();
+
+        // Test with second age below the second table (should fail)
+        let params = JointLifeParams::builder()
+            .mt_x(mt_x.clone())
+            .mt_y(mt_y.clone())
+            .i(0.04)
+            .x(65.0)
+            .y(min_age_y as f64 - 1.0)
+            .n(10.0)
+            .t(0.0)
+            .m(1)
+            .moment(1)
+            .build();
+        assert!(params.validate_all().is_err());
+
+        // Test with both ages inside their tables (should pass)
+        let params = JointLifeParams::builder()
+            .mt_x(mt_x)
+            .mt_y(mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(10.0)
+            .t(0.0)
+            .m(1)
+            .moment(1)
+            .build();
+        assert!(params.validate_all().is_ok());
+    }
+
+    // ------------------------------- Term ------------------------------------
+
+    #[test]
+    fn test_term_exceeds_longer_lifetime() {
+        let (mt_x, mt_y) = configs();
+        let max_age_x = mt_x.max_age().unwrap() as f64;
+        let max_age_y = mt_y.max_age().unwrap() as f64;
+        let max_term = f64::max(max_age_x - 65.0, max_age_y - 62.0);
+
+        // Term up to the longer remaining lifetime is allowed (last survivor)
+        let params = JointLifeParams::builder()
+            .mt_x(mt_x.clone())
+            .mt_y(mt_y.clone())
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(max_term)
+            .t(0.0)
+            .m(1)
+            .moment(1)
+            .build();
+        assert!(params.validate_all().is_ok());
+
+        // Anything beyond it should fail
+        let params = JointLifeParams::builder()
+            .mt_x(mt_x)
+            .mt_y(mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(max_term)
+            .t(1.0)
+            .m(1)
+            .moment(1)
+            .build();
+        assert!(params.validate_all().is_err());
+    }
+
+    // ------------------------------- Entry age -------------------------------
+
+    #[test]
+    fn test_entry_age_y_exceeds_age_y() {
+        let (mt_x, mt_y) = configs();
+
+        let params = JointLifeParams::builder()
+            .mt_x(mt_x)
+            .mt_y(mt_y)
+            .i(0.04)
+            .x(65.0)
+            .y(62.0)
+            .n(10.0)
+            .t(0.0)
+            .m(1)
+            .moment(1)
+            .entry_age_y(63)
+            .build();
+        assert!(params.validate_all().is_err());
+    }
+}
@@ -1,8 +1,10 @@
 mod get_value;
+mod joint_life;
 mod single_life;
 mod survival;
 mod validation;
 
 pub use get_value::GetValueFunctionValidation;
+pub use joint_life::JointLifeParams;
 pub use single_life::SingleLifeParams;
 pub use survival::SurvivalFunctionParams;
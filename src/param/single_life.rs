@@ -1,9 +1,11 @@
+use crate::RSLifeResult;
 use crate::mt_config::MortTableConfig;
 use bon::Builder;
 use garde::Validate;
 
 use super::validation::{
-    ErrorVec, collect_age_bounds_errors, validate_age_boundaries, validate_entry_age,
+    ErrorVec, collect_age_bounds_errors, errors_to_result, validate_age_boundaries,
+    validate_entry_age,
 };
 
 #[derive(Debug, Clone, Validate, Builder)]
@@ -49,8 +51,8 @@ pub struct SingleLifeParams {
 }
 
 impl SingleLifeParams {
-    /// Validate with cross-field validation using Result<(), garde::Report>
-    pub fn validate_all(&self) -> Result<(), garde::Report> {
+    /// Validate with cross-field validation, age out of range reported as `RSLifeError::AgeOutOfRange`
+    pub fn validate_all(&self) -> RSLifeResult<()> {
         // First run garde's built-in validations
         self.validate()?;
 
@@ -59,8 +61,7 @@ impl SingleLifeParams {
     }
 
     /// Custom cross-field validations that garde can't handle with attributes
-    fn validate_custom_constraints(&self) -> Result<(), garde::Report> {
-        let mut report = garde::Report::new();
+    fn validate_custom_constraints(&self) -> RSLifeResult<()> {
         let mut errors: ErrorVec = Vec::new();
 
         // Get age bounds and collect any errors
@@ -69,22 +70,17 @@ impl SingleLifeParams {
         // If we can't get age bounds, return early
         let (min_age, max_age) = match age_bounds {
             Some(bounds) => bounds,
-            None => {
-                for (path, message) in errors {
-                    report.append(garde::Path::new(path), garde::Error::new(message));
-                }
-                return Err(report);
-            }
+            None => return errors_to_result(errors, None),
         };
 
         // Validate age boundaries
-        validate_age_boundaries(self.x, min_age, max_age, &mut errors);
+        let age_error = validate_age_boundaries("x", self.x, min_age, max_age, &mut errors);
 
         // Validate term constraints (custom for SingleLifeParams)
         let x = self.x;
         let t = self.t;
         let n = self.n;
-        if x + t + n > max_age {
+        if age_error.is_none() && x + t + n > max_age {
             errors.push(("", format!(
                 "age + deferral + term ({x} + {t} + {n}) cannot exceed max age {max_age} from mortality table"
             )));
@@ -93,17 +89,8 @@ impl SingleLifeParams {
         // Validate entry age constraints
         validate_entry_age(self.entry_age, self.x, &mut errors);
 
-        // Convert errors to report
-        for (path, message) in errors {
-            report.append(garde::Path::new(path), garde::Error::new(message));
-        }
-
-        // Return Ok if no errors, otherwise return the complete report
-        if report.is_empty() {
-            Ok(())
-        } else {
-            Err(report)
-        }
+        // Age out of range on its own, otherwise the complete report
+        errors_to_result(errors, age_error)
     }
 }
 
@@ -230,6 +217,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_age_out_of_range_merged_with_other_errors() {
+        let mort_data = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(mort_data).build().unwrap();
+        let above_max = mt.max_age().unwrap() as f64 + 1.0;
+
+        // Age out of range on its own
+        let params = SingleLifeParams::builder()
+            .mt(mt.clone())
+            .i(0.04)
+            .x(above_max)
+            .n(0.0)
+            .t(0.0)
+            .m(1)
+            .moment(1)
+            .build();
+        assert!(matches!(
+            params.validate_all(),
+            Err(crate::RSLifeError::AgeOutOfRange { .. })
+        ));
+
+        // Together with an entry age above x, both are reported
+        let params = SingleLifeParams::builder()
+            .mt(mt.clone())
+            .i(0.04)
+            .x(above_max)
+            .n(0.0)
+            .t(0.0)
+            .entry_age(above_max as u32 + 1)
+            .m(1)
+            .moment(1)
+            .build();
+        let Err(crate::RSLifeError::InvalidParameters(report)) = params.validate_all() else {
+            panic!("expected InvalidParameters");
+        };
+        let paths: Vec<String> = report.iter().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(paths, vec!["x", "entry_age"]);
+    }
+
     // ------------------------------- Term (n) --------------------------------
 
     #[test]
@@ -1,4 +1,5 @@
 use crate::mt_config::MortTableConfig;
+use crate::{RSLifeError, RSLifeResult};
 
 pub type ErrorVec = Vec<(&'static str, String)>;
 
@@ -22,12 +23,43 @@ pub fn collect_age_bounds_errors(
     }
 }
 
-pub fn validate_age_boundaries(x: f64, min_age: f64, max_age: f64, errors: &mut ErrorVec) {
-    if x < min_age || x > max_age {
+/// Collect an age outside `[min_age, max_age]` in `errors` under `path`.
+///
+/// Returns the matching `AgeOutOfRange` error, reported on its own by `errors_to_result`
+/// when no other error was collected.
+pub fn validate_age_boundaries(
+    path: &'static str,
+    age: f64,
+    min_age: f64,
+    max_age: f64,
+    errors: &mut ErrorVec,
+) -> Option<RSLifeError> {
+    if age < min_age || age > max_age {
         errors.push((
-            "x",
-            format!("age {x} must be between {min_age} and {max_age} from mortality table"),
+            path,
+            format!("age {age} must be between {min_age} and {max_age} from mortality table"),
         ));
+        return Some(RSLifeError::AgeOutOfRange {
+            age,
+            min_age,
+            max_age,
+        });
+    }
+    None
+}
+
+/// `Ok` without errors, `age_error` when the age is the only error, otherwise one report with all `errors`.
+pub fn errors_to_result(errors: ErrorVec, age_error: Option<RSLifeError>) -> RSLifeResult<()> {
+    match age_error {
+        _ if errors.is_empty() => Ok(()),
+        Some(age_error) if errors.len() == 1 => Err(age_error),
+        _ => {
+            let mut report = garde::Report::new();
+            for (path, message) in errors {
+                report.append(garde::Path::new(path), garde::Error::new(message));
+            }
+            Err(report.into())
+        }
     }
 }
 
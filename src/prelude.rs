@@ -14,28 +14,57 @@
 pub use crate::mddf;
 
 // Core mortality table types and configuration
-pub use crate::mt_config::mt_data::MortData;
+pub use crate::mt_config::improvement::{ImprovementScale, MortalityProjection};
+pub use crate::mt_config::layout::ColumnMapping;
+pub use crate::mt_config::md_table::MultiDecrementTable;
+pub use crate::mt_config::metadata::{TableMetadata, TableSourceEnum};
+pub use crate::mt_config::mt_data::{MortData, MortalityLawEnum};
 pub use crate::mt_config::{AssumptionEnum, MortTableConfig};
 
+// Graduation of experience data
+pub use crate::graduation::analysis::{
+    ActualVsExpected, ExposureEnum, GraduationTests, TestStatistic, actual_vs_expected,
+    graduation_tests,
+};
+pub use crate::graduation::fitting::{FitMethodEnum, LawFamilyEnum, LawFit, fit_law};
+pub use crate::graduation::{
+    Experience, FormulaEnum, Graduation, GraduationMethodEnum, LinkEnum, graduate,
+};
+
 // All actuarial calculation functions (implementation functions from whole.rs)
 pub use crate::int_rate_convert::*;
 
+pub use crate::yield_curve::{InterestEnum, InterpolationEnum, YieldCurve};
+
 pub use crate::annuities_certain::{Daan, Dan, Iaan, Ian, aan, an};
 
-pub use crate::single_life::survivals::{dx, lx, tpx, tqx};
+pub use crate::single_life::survivals::{dx, ebarx, ebarxn, ex, exn, lx, mux, tpx, tqx};
 
 pub use crate::single_life::commutations::{Cx, Dx, Mx, Nx, Rx, Sx};
 
 pub use crate::single_life::benefits::{
-    Ax, Ax1n, Axn, Axn1, DAx1n, DAxn, Exn, IAx, IAx1n, IAxn, gAx, gAx1n, gAxn,
+    Abarx, Abarx1n, Ax, Ax1n, Axn, Axn1, ContinuousMethodEnum, DAx1n, DAxn, Exn, IAx, IAx1n, IAxn,
+    gAx, gAx1n, gAxn,
 };
 
 pub use crate::single_life::annuities::{
-    Daaxn, Daxn, Iaax, Iaaxn, Iax, Iaxn, aax, aaxn, ax, axn, gaax, gaaxn, gax, gaxn,
+    Daaxn, Daxn, Iaax, Iaaxn, Iax, Iaxn, aax, aaxn, abarx, abarxn, ax, axn, gaax, gaaxn, gax, gaxn,
 };
 
+pub use crate::single_life::batch::{BatchFunctionEnum, batch, batch_df};
+
+pub use crate::single_life::premiums::{ContractEnum, Px, Px1n, Pxn, gross_premium};
+
+pub use crate::single_life::policy_values::{ADS, DSAR, EDS, ReserveMethodEnum, tVx};
+
+pub use crate::joint_life::survivals::{tpxy, tpxybar, tqxy, tqxybar};
+
+pub use crate::joint_life::benefits::{A1xy, A2xy, Axy, Axy1n, Axybar};
+
+pub use crate::joint_life::annuities::{aaxy, aaxybar, aaxyn, aayx};
+
 // Most commonly used Polars types for working with mortality tables
 pub use polars::prelude::{DataFrame, LazyFrame, PolarsError, PolarsResult, Series};
 
-// Package Result type for RSLife functions
-pub use crate::RSLifeResult;
+// Package Result and Error types for RSLife functions
+pub use crate::{RSLifeError, RSLifeResult};
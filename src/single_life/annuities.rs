@@ -1,11 +1,15 @@
 #![allow(non_snake_case)]
 #![allow(clippy::too_many_arguments)]
 
-use super::helpers::get_new_config_with_selected_table;
+use super::benefits::{Abarx1n, ContinuousMethodEnum, Exn};
+use super::helpers::{
+    ContinuousIntegrand, annuity_summation, continuous_integral, get_new_config_with_selected_table,
+};
 use super::survivals::tpx;
 use crate::RSLifeResult;
 use crate::mt_config::MortTableConfig;
 use crate::param::SingleLifeParams;
+use crate::yield_curve::InterestEnum;
 use bon::builder;
 
 // ==================In arrears==================
@@ -42,7 +46,7 @@ use bon::builder;
 #[builder]
 pub fn axn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -53,7 +57,7 @@ pub fn axn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -98,7 +102,7 @@ pub fn axn(
 #[builder]
 pub fn ax(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -110,7 +114,7 @@ pub fn ax(
     let n = max_age - x - t;
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -158,7 +162,7 @@ pub fn ax(
 #[builder]
 pub fn Iaxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -169,7 +173,7 @@ pub fn Iaxn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -214,7 +218,7 @@ pub fn Iaxn(
 #[builder]
 pub fn Iax(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -226,7 +230,7 @@ pub fn Iax(
     let n = max_age - x - t;
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -274,7 +278,7 @@ pub fn Iax(
 #[builder]
 pub fn Daxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -285,7 +289,7 @@ pub fn Daxn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -446,7 +450,7 @@ pub fn gax(
 #[builder]
 pub fn aaxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -457,7 +461,7 @@ pub fn aaxn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -501,7 +505,7 @@ pub fn aaxn(
 #[builder]
 pub fn aax(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -513,7 +517,7 @@ pub fn aax(
     let n = max_age - x - t;
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -561,7 +565,7 @@ pub fn aax(
 #[builder]
 pub fn Iaaxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -572,7 +576,7 @@ pub fn Iaaxn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -617,7 +621,7 @@ pub fn Iaaxn(
 #[builder]
 pub fn Iaax(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -629,7 +633,7 @@ pub fn Iaax(
     let n = max_age - x - t;
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -677,7 +681,7 @@ pub fn Iaax(
 #[builder]
 pub fn Daaxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -688,7 +692,7 @@ pub fn Daaxn(
 ) -> RSLifeResult<f64> {
     annuity_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -813,10 +817,169 @@ pub fn gaaxn(
     }
 }
 
+// ==================Continuous==================
+
+/// Continuous temporary annuity:
+///
+/// Present value of a continuous payment at rate 1 per year for up to n years after time t, while the insured is alive.
+///
+/// # Formula
+/// ```text
+/// ₜ|āₓ:ₙ̅ = ∫ₜᵗ⁺ⁿ vˢ · ₛpₓ ds                                  (Integration)
+/// ₜ|āₓ:ₙ̅ ≈ (ₜEₓ - ₜ|Ā¹ₓ:ₙ̅ - ₜ₊ₙEₓ) / δ,  Ā¹ ≈ (1+i)^½ · A¹     (ClaimsAcceleration)
+/// ```
+/// where:
+/// - `v = 1/(1+i)` is the discount factor and `δ = ln(1+i)` the force of interest
+/// - `ₛpₓ` is the survival probability under the table's fractional-age assumption (UDD, CFM or HPB)
+/// - `n` is the term of the annuity
+/// - `t` is the deferral period (default 0)
+/// - `moment` is the moment to calculate (default 1, i.e., mean)
+/// - `method` selects exact integration (default) or the claims acceleration approximation
+/// - `entry_age` is the age at which the insured enters the policy (default None, uses ultimate table)
+///
+/// On a yield curve `vˢ` is replaced by `P(s)`, and claims acceleration is applied year by year
+/// with `δ` the force of interest over each year.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let cont_annuity = abarxn().mt(&config).i(0.04).x(40.0).n(20.0).call()?;
+/// println!("Continuous temporary annuity: {:.6}", cont_annuity);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn abarxn(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    x: f64,
+    n: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] moment: u32,
+    #[builder(default)] method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    continuous_annuity_procedure(mt, &i, x, n, t, moment, method, entry_age, validate)
+}
+
+/// Continuous whole life annuity:
+///
+/// Present value of a continuous payment at rate 1 per year after time t, for as long as the insured is alive.
+///
+/// # Formula
+/// ```text
+/// ₜ|āₓ = ∫ₜ^∞ vˢ · ₛpₓ ds
+/// ```
+/// See `abarxn` for the parameters and the claims acceleration alternative.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let cont_annuity = abarx().mt(&config).i(0.04).x(65.0).call()?;
+/// let annuity_due = aax().mt(&config).i(0.04).x(65.0).call()?;
+///
+/// // āₓ ≈ äₓ - ½
+/// assert!((cont_annuity - (annuity_due - 0.5)).abs() < 0.01);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn abarx(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    x: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] moment: u32,
+    #[builder(default)] method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let max_age = mt.max_age()? as f64;
+    let n = max_age - x - t;
+    continuous_annuity_procedure(mt, &i, x, n, t, moment, method, entry_age, validate)
+}
+
 // ================================================
 // PRIVATE FUNCTIONS
 // ================================================
 
+fn continuous_annuity_procedure(
+    mt: &MortTableConfig,
+    i: &InterestEnum,
+    x: f64,
+    n: f64,
+    t: f64,
+    moment: u32,
+    method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    if validate {
+        // Validate the parameters
+        let params = SingleLifeParams {
+            mt: mt.clone(),
+            i: i.rate(),
+            x,
+            n,
+            t,
+            m: 1,
+            moment,
+            entry_age,
+        };
+
+        params.validate_all()?;
+    }
+
+    // Decide if selected table is used
+    let mt = get_new_config_with_selected_table(mt, entry_age)?;
+
+    match method {
+        ContinuousMethodEnum::Integration => {
+            continuous_integral(&mt, i, moment, x, t, t + n, ContinuousIntegrand::Survival)
+        }
+        ContinuousMethodEnum::ClaimsAcceleration => {
+            // Year by year from the deferral, with δ the force of interest over (s₀, s₁) at the
+            // requested moment: āₓ over (s₀, s₁) = (ₛ₀Eₓ - ₛ₀|Ā¹ₓ:₍ₛ₁₋ₛ₀₎ - ₛ₁Eₓ) / δ
+            // For a flat rate δ is constant and the sum is ₜ|āₓ:ₙ̅ = (ₜEₓ - ₜ|Ā¹ₓ:ₙ̅ - ₜ₊ₙEₓ) / δ
+            const EPS: f64 = 1e-12;
+            let exn = |n: f64| {
+                Exn()
+                    .mt(&mt)
+                    .i(i.clone())
+                    .x(x)
+                    .n(n)
+                    .moment(moment)
+                    .validate(false)
+                    .call()
+            };
+            let mut summation = 0.0;
+            let mut s_a = t;
+            while s_a < t + n - EPS {
+                let s_b = f64::min(s_a + 1.0, t + n);
+                let delta = f64::from(moment)
+                    * (i.discount_factor(s_a) / i.discount_factor(s_b)).ln()
+                    / (s_b - s_a);
+                let term = Abarx1n()
+                    .mt(&mt)
+                    .i(i.clone())
+                    .x(x)
+                    .n(s_b - s_a)
+                    .t(s_a)
+                    .moment(moment)
+                    .method(ContinuousMethodEnum::ClaimsAcceleration)
+                    .validate(false)
+                    .call()?;
+                summation += (exn(s_a)? - term - exn(s_b)?) / delta;
+                s_a = s_b;
+            }
+            Ok(summation)
+        }
+    }
+}
+
 #[derive(PartialEq)]
 enum CashFlowStructure {
     Flat,
@@ -832,7 +995,7 @@ enum CashFlowTiming {
 
 fn annuity_procedure(
     mt: &MortTableConfig,
-    i: f64,
+    i: &InterestEnum,
     x: f64,
     n: f64,
     t: f64,
@@ -847,7 +1010,7 @@ fn annuity_procedure(
         // Validate the parameters
         let params = SingleLifeParams {
             mt: mt.clone(),
-            i,
+            i: i.rate(),
             x,
             n,
             t,
@@ -856,63 +1019,51 @@ fn annuity_procedure(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // Decide if selected table is used
     let mt = get_new_config_with_selected_table(mt, entry_age)?;
 
-    // Initialize k array: time points [0, 1/m, 2/m, ..., last_multiple < n, n]
+    // Initialize k array of payment times
+    // - In advance: [0, 1/m, 2/m, ...] strictly before n
+    // - In arrears: [1/m, 2/m, ...] up to and including n
     let m_f = m as f64;
     let full_steps = (n * m_f).floor() as u32;
+    let advance_steps = (n * m_f).ceil() as u32;
     let k_arr: Vec<f64> = match timing {
-        CashFlowTiming::InAdvance => (0..=full_steps).map(|k| k as f64 / m_f).collect(),
+        CashFlowTiming::InAdvance => (0..advance_steps).map(|k| k as f64 / m_f).collect(),
         CashFlowTiming::InArrears => (1..=full_steps).map(|k| k as f64 / m_f).collect(),
     };
 
-    // Convert parameters to f64 for calculations
-    let v = 1.0 / (1.0 + i);
-    let moment_f = moment as f64;
-
-    // ----------Discount factor----------
-    let discount_factors: Vec<f64> = k_arr.iter().map(|&k| v.powf(moment_f * k)).collect();
-
-    // ----------Probabilities vectors----------
-    let probabilities: Vec<f64> = k_arr
-        .iter()
-        .map(|&k| {
-            tpx()
-                .mt(&mt)
-                .x(x + t)
-                .t(k)
-                .validate(false)
-                .call()
-                .unwrap_or(0.0)
-        })
-        .collect();
-
-    // ----------Benefit vector----------
-    let mut amounts: Vec<f64> = match structure {
-        CashFlowStructure::Flat => vec![1.0; k_arr.len()],
-        CashFlowStructure::Increasing => k_arr.iter().map(|&k| k.floor() + 1.0).collect(),
-        CashFlowStructure::Decreasing => k_arr.iter().map(|&k| n - k.floor()).collect(),
+    // ----------Probability----------
+    // ₖpₓ₊ₜ
+    let probability = |k: f64| {
+        Ok(tpx()
+            .mt(&mt)
+            .x(x + t)
+            .t(k)
+            .validate(false)
+            .call()
+            .unwrap_or(0.0))
     };
 
+    // ----------Amount----------
     // The amount is very much alike with benfits except that we divide by m - m-payable
-    amounts.iter_mut().for_each(|x| *x /= m_f);
+    let amount = |k: f64| {
+        let amount = match structure {
+            CashFlowStructure::Flat => 1.0,
+            CashFlowStructure::Increasing => k.floor() + 1.0,
+            CashFlowStructure::Decreasing => n - k.floor(),
+        };
+        amount / m_f
+    };
 
     // Calculate the summation
-    let summation: f64 = discount_factors
-        .iter()
-        .zip(probabilities.iter())
-        .zip(amounts.iter())
-        .map(|((df, prob), amount)| df * prob * amount)
-        .sum();
+    let summation = annuity_summation(i, t, moment, &k_arr, probability, amount)?;
 
-    // Deferred factor: ₜEₓ = vᵗ · ₜpₓ
-    let deferred_factor = v.powf(t) * tpx().mt(&mt).x(x).t(t).validate(false).call()?;
+    // Deferred survival: ₜpₓ (discounting to time 0 is already in the discount factors)
+    let deferred_factor = tpx().mt(&mt).x(x).t(t).validate(false).call()?;
 
     // Final result
     Ok(summation * deferred_factor)
@@ -925,10 +1076,125 @@ fn annuity_procedure(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mt_config::MortTableConfig;
     use crate::mt_config::mt_data::MortData;
+    use crate::mt_config::{AssumptionEnum, MortTableConfig};
+    use crate::yield_curve::YieldCurve;
     use approx::assert_abs_diff_eq;
 
+    #[test]
+    fn test_fn_abarxn_curve() {
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let methods = [
+            ContinuousMethodEnum::Integration,
+            ContinuousMethodEnum::ClaimsAcceleration,
+        ];
+
+        // A flat curve reproduces the flat-rate result for both methods
+        let flat = YieldCurve::flat(0.04);
+        for method in methods {
+            let rate = abarxn()
+                .mt(&mt)
+                .i(0.04)
+                .x(50.0)
+                .n(20.0)
+                .t(2.0)
+                .method(method)
+                .call()
+                .unwrap();
+            let curve = abarxn()
+                .mt(&mt)
+                .i(&flat)
+                .x(50.0)
+                .n(20.0)
+                .t(2.0)
+                .method(method)
+                .call()
+                .unwrap();
+            assert_abs_diff_eq!(rate, curve, epsilon = 1e-10);
+        }
+
+        // On an upward sloping curve with a constant force over each year, both methods stay close
+        let curve = YieldCurve::from_annual_rates()
+            .rates((0..25).map(|k| 0.02 + 0.001 * k as f64).collect())
+            .call()
+            .unwrap();
+        let [exact, approx] = methods.map(|method| {
+            abarxn()
+                .mt(&mt)
+                .i(&curve)
+                .x(50.0)
+                .n(20.0)
+                .method(method)
+                .call()
+                .unwrap()
+        });
+        assert_abs_diff_eq!(exact, approx, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_fn_abarxn_identity() {
+        // āₓ:ₙ̅ = (1 - Āₓ:ₙ̅) / δ holds exactly, including from a fractional age
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        for assumption in [AssumptionEnum::UDD, AssumptionEnum::CFM] {
+            let mt = MortTableConfig::builder()
+                .data(am92.clone())
+                .assumption(assumption)
+                .build()
+                .unwrap();
+            let i = 0.05;
+            let (x, n) = (45.5, 20.0);
+            let ans = abarxn().mt(&mt).i(i).x(x).n(n).call().unwrap();
+            let term = Abarx1n().mt(&mt).i(i).x(x).n(n).call().unwrap();
+            let endow = Exn().mt(&mt).i(i).x(x).n(n).call().unwrap();
+            let expected = (1.0 - term - endow) / (1.0_f64 + i).ln();
+            assert_abs_diff_eq!(ans, expected, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_fn_aaxn_deferred_second_moment() {
+        // ₜ|²äₓ:ₙ̅ = v²ᵗ · ₜpₓ · ²äₓ₊ₜ:ₙ̅, the deferral discounted at the second moment too
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let deferred = aaxn()
+            .mt(&mt)
+            .i(0.04)
+            .x(50.0)
+            .n(10.0)
+            .t(5.0)
+            .moment(2)
+            .call()
+            .unwrap();
+        let immediate = aaxn()
+            .mt(&mt)
+            .i(0.04)
+            .x(55.0)
+            .n(10.0)
+            .moment(2)
+            .call()
+            .unwrap();
+        let p = tpx().mt(&mt).x(50.0).t(5.0).call().unwrap();
+        let expected = 1.04f64.powf(-10.0) * p * immediate;
+        assert_abs_diff_eq!(deferred, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fn_aaxn_annual_rates() {
+        // äₓ:₃̅ = 1 + P(1)·₁pₓ + P(2)·₂pₓ on a vector of annual rates
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let curve = YieldCurve::from_annual_rates()
+            .rates(vec![0.02, 0.03, 0.05])
+            .call()
+            .unwrap();
+        let ans = aaxn().mt(&mt).i(&curve).x(60.0).n(3.0).call().unwrap();
+        let p1 = tpx().mt(&mt).x(60.0).t(1.0).call().unwrap();
+        let p2 = tpx().mt(&mt).x(60.0).t(2.0).call().unwrap();
+        let expected = 1.0 + p1 / 1.02 + p2 / (1.02 * 1.03);
+        assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+    }
+
     #[test]
     fn test_fn_aax_01() {
         // CM1 2019 Chapter 17
@@ -976,27 +1242,53 @@ mod tests {
         assert_abs_diff_eq!(advance - arrear, 1.0, epsilon = 1e-4);
     }
 
-    // #[test]
-    // fn test_fn_aax_05() {
-    //     // Testing relationship between in arrear and in advance
-    //     // Create MortTableConfig with AM92
-    //     let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
-    //     let mt = MortTableConfig::builder().data(am92).build().unwrap();
-    //     let advance = aaxn().mt(&mt).i(0.04).x(50.0).n(20.0).m(12).call().unwrap();
-    //     let arrear = axn().mt(&mt).i(0.04).x(50.0).n(20.0).m(12).call().unwrap();
-    //     // ₜ|äₓ:ₙ̅⁽ᵐ⁾ = vᵗₜpₓ[äₓ₊ₜ:ₙ̅⁽ᵐ⁾ + 1/m(1-vⁿₙpₓ)] = ₜ|aₓ:ₙ̅⁽ᵐ⁾ + vᵗₜpₓ(1-vⁿₙpₓ)/m
-    //     // For t = 0, m=12:  the diffrence = (1-vⁿₙpₓ)/12
-    //     let vn = (1.0f64 / (1.0f64 + 0.04f64)).powf(20.0f64);
-    //     let pxn = tpx()
-    //         .mt(&mt)
-    //         .x(50.0)
-    //         .t(20.0)
-    //         .validate(false)
-    //         .call()
-    //         .unwrap();
-    //     let expected_diff = (1.0 - vn * pxn) / 12.0;
-    //     assert_abs_diff_eq!(advance - arrear, expected_diff, epsilon = 1e-4);
-    // }
+    #[test]
+    fn test_fn_aax_05() {
+        // Testing relationship between in arrear and in advance
+        // Create MortTableConfig with AM92
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let advance = aaxn().mt(&mt).i(0.04).x(50.0).n(20.0).m(12).call().unwrap();
+        let arrear = axn().mt(&mt).i(0.04).x(50.0).n(20.0).m(12).call().unwrap();
+        // ₜ|äₓ:ₙ̅⁽ᵐ⁾ = vᵗₜpₓ[äₓ₊ₜ:ₙ̅⁽ᵐ⁾ + 1/m(1-vⁿₙpₓ)] = ₜ|aₓ:ₙ̅⁽ᵐ⁾ + vᵗₜpₓ(1-vⁿₙpₓ)/m
+        // For t = 0, m=12:  the diffrence = (1-vⁿₙpₓ)/12
+        let vn = (1.0f64 / (1.0f64 + 0.04f64)).powf(20.0f64);
+        let pxn = tpx()
+            .mt(&mt)
+            .x(50.0)
+            .t(20.0)
+            .validate(false)
+            .call()
+            .unwrap();
+        let expected_diff = (1.0 - vn * pxn) / 12.0;
+        assert_abs_diff_eq!(advance - arrear, expected_diff, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_fn_aax_pct_loading() {
+        // Heavier mortality shortens the expected payment period of a life annuity
+        let pma92c20 = MortData::from_builtin("PMA92C20").expect("Failed to load PMA92C20 table");
+        let standard = MortTableConfig::builder()
+            .data(pma92c20.clone())
+            .build()
+            .unwrap();
+        let heavy = MortTableConfig::builder()
+            .data(pma92c20.clone())
+            .pct(1.5)
+            .build()
+            .unwrap();
+        let light = MortTableConfig::builder()
+            .data(pma92c20)
+            .pct(0.5)
+            .build()
+            .unwrap();
+
+        let ans_standard = aax().mt(&standard).i(0.04).x(75.0).call().unwrap();
+        let ans_heavy = aax().mt(&heavy).i(0.04).x(75.0).call().unwrap();
+        let ans_light = aax().mt(&light).i(0.04).x(75.0).call().unwrap();
+        assert!(ans_heavy < ans_standard);
+        assert!(ans_light > ans_standard);
+    }
 
     #[test]
     fn test_fn_Iaax_annuities() {
@@ -0,0 +1,455 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use super::annuities::{aax, aaxn};
+use super::benefits::{Ax, Ax1n, Axn, Exn};
+use super::helpers::get_new_config_with_selected_table;
+use super::survivals::{tpx, tqx};
+use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+use crate::mt_config::MortTableConfig;
+use crate::yield_curve::InterestEnum;
+use bon::builder;
+use polars::prelude::*;
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+
+// =======================================
+// PUBLIC TYPES
+// =======================================
+
+/// Single life function evaluated for every policy in a batch.
+///
+/// Term-based functions read the term `n` (or `t` for survival probabilities) from the policy record.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchFunctionEnum {
+    /// Survival probability over the term: ₙpₓ
+    Survival,
+
+    /// Mortality probability over the term: ₙqₓ
+    Mortality,
+
+    /// Whole life assurance: Aₓ
+    WholeLife,
+
+    /// Term assurance: Aₓ:ₙ̅¹
+    Term,
+
+    /// Endowment assurance: Aₓ:ₙ̅
+    Endowment,
+
+    /// Pure endowment: ₙEₓ
+    PureEndowment,
+
+    /// Whole life annuity-due: äₓ
+    WholeLifeAnnuity,
+
+    /// Temporary annuity-due: äₓ:ₙ̅
+    TemporaryAnnuity,
+}
+
+impl BatchFunctionEnum {
+    /// Actuarial name of the function, used as the name of the result column.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BatchFunctionEnum::Survival => "tpx",
+            BatchFunctionEnum::Mortality => "tqx",
+            BatchFunctionEnum::WholeLife => "Ax",
+            BatchFunctionEnum::Term => "Ax1n",
+            BatchFunctionEnum::Endowment => "Axn",
+            BatchFunctionEnum::PureEndowment => "Exn",
+            BatchFunctionEnum::WholeLifeAnnuity => "aax",
+            BatchFunctionEnum::TemporaryAnnuity => "aaxn",
+        }
+    }
+
+    /// Whether the function requires a term for each policy.
+    pub fn requires_term(&self) -> bool {
+        !matches!(
+            self,
+            BatchFunctionEnum::WholeLife | BatchFunctionEnum::WholeLifeAnnuity
+        )
+    }
+}
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+/// Evaluate a single life function for many policies at once.
+///
+/// Each row `k` is valued with age `x[k]`, term `n[k]`, entry age `entry_age[k]` and multiplied by
+/// `sum_assured[k]`. Select tables are built once per distinct entry age and shared across all rows,
+/// so a portfolio costs one table preparation per entry age instead of one per policy.
+///
+/// With the `rayon` feature enabled, rows are evaluated in parallel.
+///
+/// # Parameters
+/// - `func`: function to evaluate, see [`BatchFunctionEnum`]
+/// - `x`: ages of the policyholders
+/// - `n`: terms, required unless `func` is whole life
+/// - `entry_age`: entry ages for select tables (`None` rows use the ultimate table)
+/// - `sum_assured`: amounts multiplying each result (default 1)
+/// - `i`, `m`, `moment`: as for the underlying scalar function (`i` is ignored for survival functions)
+///
+/// # Errors
+/// - Slices of different lengths
+/// - Missing term for a term-based function
+/// - Any row failing the validation of the underlying scalar function
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// let values = batch()
+///     .mt(&mt)
+///     .i(0.04)
+///     .func(BatchFunctionEnum::Endowment)
+///     .x(&[30.0, 40.0, 50.0])
+///     .n(&[30.0, 20.0, 10.0])
+///     .sum_assured(&[100_000.0, 50_000.0, 25_000.0])
+///     .call()?;
+/// assert_eq!(values.len(), 3);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn batch(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    func: BatchFunctionEnum,
+    x: &[f64],
+    n: Option<&[f64]>,
+    entry_age: Option<&[Option<u32>]>,
+    sum_assured: Option<&[f64]>,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+) -> RSLifeResult<Series> {
+    let size = x.len();
+    let check_len = |name: &str, len: usize| -> RSLifeResult<()> {
+        if len != size {
+            return Err(invalid_parameter(
+                name,
+                format!("{name} has {len} values but x has {size}"),
+            ));
+        }
+        Ok(())
+    };
+
+    let n = match n {
+        Some(n) => {
+            check_len("n", n.len())?;
+            n.to_vec()
+        }
+        None if func.requires_term() => {
+            return Err(invalid_parameter(
+                "n",
+                format!("n is required for {}", func.name()),
+            ));
+        }
+        None => vec![0.0; size],
+    };
+    let entry_age = match entry_age {
+        Some(entry_age) => {
+            check_len("entry_age", entry_age.len())?;
+            entry_age.to_vec()
+        }
+        None => vec![None; size],
+    };
+    let sum_assured = match sum_assured {
+        Some(sum_assured) => {
+            check_len("sum_assured", sum_assured.len())?;
+            sum_assured.to_vec()
+        }
+        None => vec![1.0; size],
+    };
+
+    // One prepared table per distinct entry age
+    let mut tables: FxHashMap<Option<u32>, MortTableConfig> = FxHashMap::default();
+    for &age in &entry_age {
+        if let Entry::Vacant(entry) = tables.entry(age) {
+            entry.insert(get_new_config_with_selected_table(mt, age)?);
+        }
+    }
+
+    let evaluator = BatchEvaluator {
+        tables: &tables,
+        i: &i,
+        func,
+        m,
+        moment,
+    };
+    let values = evaluate_rows(&evaluator, x, &n, &entry_age, &sum_assured)?;
+
+    Ok(Series::new(func.name().into(), values))
+}
+
+/// Evaluate a single life function for every policy record of a DataFrame.
+///
+/// The DataFrame is read by column name:
+/// - `age` (required): age of the policyholder
+/// - `term`: term in years, required unless `func` is whole life
+/// - `entry_age`: entry age for select tables, nulls use the ultimate table
+/// - `sum_assured`: amount multiplying each result (default 1)
+///
+/// Numeric columns of any type are accepted. Refer to [`batch`] for the other parameters.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # use polars::prelude::df;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// let policies = df! {
+///     "age" => [35.0, 45.0],
+///     "term" => [25.0, 15.0],
+///     "entry_age" => [Some(35_u32), None],
+///     "sum_assured" => [200_000.0, 80_000.0],
+/// }?;
+/// let values = batch_df()
+///     .mt(&mt)
+///     .i(0.04)
+///     .func(BatchFunctionEnum::Term)
+///     .policies(&policies)
+///     .call()?;
+/// println!("Term assurance values: {values}");
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn batch_df(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    func: BatchFunctionEnum,
+    policies: &DataFrame,
+    #[builder(default = 1)] m: u32,
+    #[builder(default = 1)] moment: u32,
+) -> RSLifeResult<Series> {
+    let x = f64_column(policies, "age")?
+        .ok_or_else(|| PolarsError::ColumnNotFound("age column not found".into()))?;
+    let n = f64_column(policies, "term")?;
+    let sum_assured = f64_column(policies, "sum_assured")?;
+    let entry_age: Option<Vec<Option<u32>>> = if has_column(policies, "entry_age") {
+        let column = policies.column("entry_age")?.cast(&DataType::UInt32)?;
+        Some(column.u32()?.iter().collect())
+    } else {
+        None
+    };
+
+    batch()
+        .mt(mt)
+        .i(i)
+        .func(func)
+        .x(&x)
+        .maybe_n(n.as_deref())
+        .maybe_entry_age(entry_age.as_deref())
+        .maybe_sum_assured(sum_assured.as_deref())
+        .m(m)
+        .moment(moment)
+        .call()
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+/// Shared state for evaluating the rows of a batch.
+struct BatchEvaluator<'a> {
+    tables: &'a FxHashMap<Option<u32>, MortTableConfig>,
+    i: &'a InterestEnum,
+    func: BatchFunctionEnum,
+    m: u32,
+    moment: u32,
+}
+
+impl BatchEvaluator<'_> {
+    fn value(&self, x: f64, n: f64, entry_age: Option<u32>) -> RSLifeResult<f64> {
+        if let Some(age) = entry_age
+            && (age as f64) > x
+        {
+            return Err(invalid_parameter(
+                "entry_age",
+                format!("entry_age {age} cannot exceed age {x}"),
+            ));
+        }
+
+        // Selected table is already prepared, so the scalar functions run on the ultimate path
+        let mt = &self.tables[&entry_age];
+        let i = self.i.clone();
+        let (m, moment) = (self.m, self.moment);
+        match self.func {
+            BatchFunctionEnum::Survival => tpx().mt(mt).x(x).t(n).call(),
+            BatchFunctionEnum::Mortality => tqx().mt(mt).x(x).t(n).call(),
+            BatchFunctionEnum::WholeLife => Ax().mt(mt).i(i).x(x).m(m).moment(moment).call(),
+            BatchFunctionEnum::Term => Ax1n().mt(mt).i(i).x(x).n(n).m(m).moment(moment).call(),
+            BatchFunctionEnum::Endowment => Axn().mt(mt).i(i).x(x).n(n).m(m).moment(moment).call(),
+            BatchFunctionEnum::PureEndowment => Exn().mt(mt).i(i).x(x).n(n).moment(moment).call(),
+            BatchFunctionEnum::WholeLifeAnnuity => {
+                aax().mt(mt).i(i).x(x).m(m).moment(moment).call()
+            }
+            BatchFunctionEnum::TemporaryAnnuity => {
+                aaxn().mt(mt).i(i).x(x).n(n).m(m).moment(moment).call()
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+fn evaluate_rows(
+    evaluator: &BatchEvaluator,
+    x: &[f64],
+    n: &[f64],
+    entry_age: &[Option<u32>],
+    sum_assured: &[f64],
+) -> RSLifeResult<Vec<f64>> {
+    (0..x.len())
+        .map(|k| Ok(sum_assured[k] * evaluator.value(x[k], n[k], entry_age[k])?))
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+fn evaluate_rows(
+    evaluator: &BatchEvaluator,
+    x: &[f64],
+    n: &[f64],
+    entry_age: &[Option<u32>],
+    sum_assured: &[f64],
+) -> RSLifeResult<Vec<f64>> {
+    use rayon::prelude::*;
+
+    (0..x.len())
+        .into_par_iter()
+        .map(|k| Ok(sum_assured[k] * evaluator.value(x[k], n[k], entry_age[k])?))
+        .collect()
+}
+
+fn has_column(df: &DataFrame, name: &str) -> bool {
+    df.get_column_names().contains(&&name.into())
+}
+
+/// Read an optional numeric column as f64 values (nulls become NaN).
+fn f64_column(df: &DataFrame, name: &str) -> PolarsResult<Option<Vec<f64>>> {
+    if !has_column(df, name) {
+        return Ok(None);
+    }
+    let column = df.column(name)?.cast(&DataType::Float64)?;
+    let values = column
+        .f64()?
+        .iter()
+        .map(|value| value.unwrap_or(f64::NAN))
+        .collect();
+    Ok(Some(values))
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RSLifeError;
+    use crate::mt_config::mt_data::MortData;
+    use approx::assert_abs_diff_eq;
+
+    fn am92() -> MortTableConfig {
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        MortTableConfig::builder().data(am92).build().unwrap()
+    }
+
+    #[test]
+    fn test_fn_batch_matches_scalar() {
+        let mt = am92();
+        let x = [30.0, 42.0, 55.0];
+        let n = [20.0, 15.0, 10.0];
+        let entry_age = [Some(30), Some(40), None];
+        let sum_assured = [1_000.0, 2_000.0, 3_000.0];
+
+        let values = batch()
+            .mt(&mt)
+            .i(0.04)
+            .func(BatchFunctionEnum::Endowment)
+            .x(&x)
+            .n(&n)
+            .entry_age(&entry_age)
+            .sum_assured(&sum_assured)
+            .call()
+            .unwrap();
+        let values = values.f64().unwrap();
+
+        for k in 0..x.len() {
+            let built = Axn().mt(&mt).i(0.04).x(x[k]).n(n[k]);
+            let expected = match entry_age[k] {
+                Some(age) => built.entry_age(age).call().unwrap(),
+                None => built.call().unwrap(),
+            };
+            assert_abs_diff_eq!(
+                values.get(k).unwrap(),
+                sum_assured[k] * expected,
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_fn_batch_df_matches_batch() {
+        let mt = am92();
+        let policies = df! {
+            "age" => [40_u32, 60],
+            "entry_age" => [Some(39_u32), None],
+        }
+        .unwrap();
+
+        let from_df = batch_df()
+            .mt(&mt)
+            .i(0.05)
+            .func(BatchFunctionEnum::WholeLifeAnnuity)
+            .policies(&policies)
+            .call()
+            .unwrap();
+        let from_slices = batch()
+            .mt(&mt)
+            .i(0.05)
+            .func(BatchFunctionEnum::WholeLifeAnnuity)
+            .x(&[40.0, 60.0])
+            .entry_age(&[Some(39), None])
+            .call()
+            .unwrap();
+
+        assert_eq!(from_df.name().as_str(), "aax");
+        assert!(from_df.equals(&from_slices));
+    }
+
+    #[test]
+    fn test_fn_batch_invalid_input() {
+        let mt = am92();
+        // Missing term
+        let result = batch()
+            .mt(&mt)
+            .i(0.04)
+            .func(BatchFunctionEnum::Survival)
+            .x(&[40.0])
+            .call();
+        assert!(matches!(result, Err(RSLifeError::InvalidParameters(_))));
+
+        // Mismatched lengths
+        let result = batch()
+            .mt(&mt)
+            .i(0.04)
+            .func(BatchFunctionEnum::Survival)
+            .x(&[40.0, 50.0])
+            .n(&[10.0])
+            .call();
+        assert!(matches!(result, Err(RSLifeError::InvalidParameters(_))));
+
+        // Entry age above age
+        let result = batch()
+            .mt(&mt)
+            .i(0.04)
+            .func(BatchFunctionEnum::Survival)
+            .x(&[40.0])
+            .n(&[10.0])
+            .entry_age(&[Some(45)])
+            .call();
+        assert!(matches!(result, Err(RSLifeError::InvalidParameters(_))));
+    }
+}
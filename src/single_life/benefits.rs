@@ -1,11 +1,14 @@
 #![allow(non_snake_case)]
 #![allow(clippy::too_many_arguments)]
 
-use super::helpers::get_new_config_with_selected_table;
+use super::helpers::{
+    ContinuousIntegrand, benefit_summation, continuous_integral, get_new_config_with_selected_table,
+};
 use super::survivals::{tpx, tqx};
 use crate::RSLifeResult;
 use crate::mt_config::MortTableConfig;
 use crate::param::SingleLifeParams;
+use crate::yield_curve::InterestEnum;
 use bon::builder;
 
 // =======================================
@@ -94,7 +97,7 @@ use bon::builder;
 #[builder]
 pub fn Exn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -106,7 +109,7 @@ pub fn Exn(
     if validate {
         let params = SingleLifeParams {
             mt: mt.clone(),
-            i,
+            i: i.rate(),
             x,
             n,
             t,
@@ -115,18 +118,15 @@ pub fn Exn(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // As provided - no default
-    let v = 1.0 / (1.0 + i);
     let moment = moment as f64;
 
     // Decide if selected table is used
     let mt = get_new_config_with_selected_table(mt, entry_age)?;
-    let discount_factor = v.powf(moment * (t + n)); // vⁿ⁺ᵗ
+    let discount_factor = i.discount_factor(t + n).powf(moment); // vⁿ⁺ᵗ
     let prob = tpx().mt(&mt).x(x).t(t + n).call()?; // ₜ+ₙpₓ
     let result = discount_factor * prob;
     Ok(result)
@@ -138,7 +138,7 @@ pub fn Exn(
 #[builder]
 pub fn Axn1(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -256,7 +256,7 @@ pub fn Axn1(
 #[builder]
 pub fn Ax1n(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -267,7 +267,7 @@ pub fn Ax1n(
 ) -> RSLifeResult<f64> {
     benefit_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -363,7 +363,7 @@ pub fn Ax1n(
 #[builder]
 pub fn Ax(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -375,7 +375,7 @@ pub fn Ax(
     let n = max_age - x - t;
     benefit_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -466,7 +466,7 @@ pub fn Ax(
 #[builder]
 pub fn Axn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -482,7 +482,7 @@ pub fn Axn(
     // ₜ|Aₓ:ₙ̅⁽ᵐ⁾ = ₜ|A¹ₓ:ₙ̅⁽ᵐ⁾ + ₜ|ₙEₓ
     let term = Ax1n()
         .mt(&mt)
-        .i(i)
+        .i(i.clone())
         .x(x)
         .n(n)
         .t(t)
@@ -544,7 +544,7 @@ pub fn Axn(
 #[builder]
 pub fn IAx1n(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -555,7 +555,7 @@ pub fn IAx1n(
 ) -> RSLifeResult<f64> {
     benefit_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -599,7 +599,7 @@ pub fn IAx1n(
 #[builder]
 pub fn IAx(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     #[builder(default = 0.0)] t: f64,
     #[builder(default = 1)] m: u32,
@@ -611,7 +611,7 @@ pub fn IAx(
     let n = max_age - x - t;
     benefit_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -655,7 +655,7 @@ pub fn IAx(
 #[builder]
 pub fn IAxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -670,7 +670,7 @@ pub fn IAxn(
 
     let term = IAx1n()
         .mt(&mt)
-        .i(i)
+        .i(i.clone())
         .x(x)
         .n(n)
         .t(t)
@@ -728,7 +728,7 @@ pub fn IAxn(
 #[builder]
 pub fn DAx1n(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -739,7 +739,7 @@ pub fn DAx1n(
 ) -> RSLifeResult<f64> {
     benefit_procedure(
         mt,
-        i,
+        &i,
         x,
         n,
         t,
@@ -783,7 +783,7 @@ pub fn DAx1n(
 #[builder]
 pub fn DAxn(
     mt: &MortTableConfig,
-    i: f64,
+    #[builder(into)] i: InterestEnum,
     x: f64,
     n: f64,
     #[builder(default = 0.0)] t: f64,
@@ -798,7 +798,7 @@ pub fn DAxn(
 
     let term = DAx1n()
         .mt(&mt)
-        .i(i)
+        .i(i.clone())
         .x(x)
         .n(n)
         .t(t)
@@ -988,10 +988,181 @@ pub fn gAxn(
     }
 }
 
+//-----------------Continuous------------------
+
+/// Method used to value continuous (moment-of-death) benefits and annuities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContinuousMethodEnum {
+    /// Numerical integration of `vˢ · ₛpₓ · μₓ₊ₛ` (or `vˢ · ₛpₓ`) under the table's fractional-age assumption.
+    #[default]
+    Integration,
+
+    /// Claims acceleration approximation: `Ā ≈ (1+i)^½ · A`, with annuities from `ā = (1 - Ā)/δ`.
+    ClaimsAcceleration,
+}
+
+/// Continuous term insurance
+///
+/// Present value of $1 paid at the moment of death, provided death occurs within n years after time t.
+///
+/// # Formula
+/// ```text
+/// ₜ|Ā¹ₓ:ₙ̅ = ∫ₜᵗ⁺ⁿ vˢ · ₛpₓ · μₓ₊ₛ ds                 (Integration)
+/// ₜ|Ā¹ₓ:ₙ̅ ≈ (1+i)^½ · ₜ|A¹ₓ:ₙ̅                       (ClaimsAcceleration)
+/// ```
+/// where:
+/// - `v = 1/(1+i)` is the discount factor
+/// - `ₛpₓ · μₓ₊ₛ` is the density of the future lifetime, taken from the table's fractional-age assumption (UDD, CFM or HPB)
+/// - `t` is the deferral period (default 0)
+/// - `n` is the term of the insurance
+/// - `moment` is the moment to calculate (default 1, i.e., mean); for moment j the discount is `vʲˢ`
+/// - `method` selects exact integration (default) or the claims acceleration approximation
+/// - `entry_age` is the age at which the insured enters the policy (default None, uses ultimate table)
+///
+/// Under UDD and integer ages the integration method reproduces `Ā¹ₓ:ₙ̅ = (i/δ) · A¹ₓ:ₙ̅`.
+/// On a yield curve `vˢ` is replaced by `P(s)`, and claims acceleration pays each year's claim at mid-year.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let exact = Abarx1n().mt(&config).i(0.04).x(50.0).n(20.0).call()?;
+///
+/// let approx = Abarx1n()
+///     .mt(&config)
+///     .i(0.04)
+///     .x(50.0)
+///     .n(20.0)
+///     .method(ContinuousMethodEnum::ClaimsAcceleration)
+///     .call()?;
+///
+/// assert!((exact - approx).abs() < 1e-4);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Abarx1n(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    x: f64,
+    n: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] moment: u32,
+    #[builder(default)] method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    continuous_benefit_procedure(mt, &i, x, n, t, moment, method, entry_age, validate)
+}
+
+/// Continuous whole life insurance
+///
+/// Present value of $1 paid at the moment of death, provided death occurs after time t.
+///
+/// # Formula
+/// ```text
+/// ₜ|Āₓ = ∫ₜ^∞ vˢ · ₛpₓ · μₓ₊ₛ ds                     (Integration)
+/// ₜ|Āₓ ≈ (1+i)^½ · ₜ|Aₓ                             (ClaimsAcceleration)
+/// ```
+/// where:
+/// - `v = 1/(1+i)` is the discount factor
+/// - `ₛpₓ · μₓ₊ₛ` is the density of the future lifetime, taken from the table's fractional-age assumption (UDD, CFM or HPB)
+/// - `t` is the deferral period (default 0)
+/// - `moment` is the moment to calculate (default 1, i.e., mean)
+/// - `method` selects exact integration (default) or the claims acceleration approximation
+/// - `entry_age` is the age at which the insured enters the policy (default None, uses ultimate table)
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder()
+/// #     .data(mort_data)
+/// #     .assumption(AssumptionEnum::CFM)
+/// #     .build()?;
+/// let whole_life = Abarx().mt(&config).i(0.04).x(60.0).call()?;
+/// println!("Continuous whole life: {:.6}", whole_life);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Abarx(
+    mt: &MortTableConfig,
+    #[builder(into)] i: InterestEnum,
+    x: f64,
+    #[builder(default = 0.0)] t: f64,
+    #[builder(default = 1)] moment: u32,
+    #[builder(default)] method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let max_age = mt.max_age()? as f64;
+    let n = max_age - x - t;
+    continuous_benefit_procedure(mt, &i, x, n, t, moment, method, entry_age, validate)
+}
+
 // ================================================
 // PRIVATE FUNCTIONS
 // ================================================
 
+fn continuous_benefit_procedure(
+    mt: &MortTableConfig,
+    i: &InterestEnum,
+    x: f64,
+    n: f64,
+    t: f64,
+    moment: u32,
+    method: ContinuousMethodEnum,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    if validate {
+        // Validate the parameters
+        let params = SingleLifeParams {
+            mt: mt.clone(),
+            i: i.rate(),
+            x,
+            n,
+            t,
+            m: 1,
+            moment,
+            entry_age,
+        };
+
+        params.validate_all()?;
+    }
+
+    // Decide if selected table is used
+    let mt = get_new_config_with_selected_table(mt, entry_age)?;
+
+    match method {
+        ContinuousMethodEnum::Integration => {
+            continuous_integral(&mt, i, moment, x, t, t + n, ContinuousIntegrand::Density)
+        }
+        ContinuousMethodEnum::ClaimsAcceleration => {
+            // Deaths occur on average half way through the year: the claim for the year
+            // (k, k + 1) is paid at t + k + ½, i.e. (1+i)^½ · A¹ for a flat rate
+            let periods: Vec<(f64, f64)> = (0..n as u32)
+                .map(|k| (f64::from(k), f64::from(k + 1)))
+                .collect();
+            let probability = |start: f64, end: f64| {
+                Ok(tqx()
+                    .mt(&mt)
+                    .x(x + t)
+                    .t(end - start)
+                    .k(start)
+                    .validate(false)
+                    .call()
+                    .unwrap_or(0.0))
+            };
+            let summation = benefit_summation(i, t - 0.5, moment, &periods, probability, |_| 1.0)?;
+
+            // Deferred survival: ₜpₓ (discounting to time 0 is already in the summation)
+            let deferred_factor = tpx().mt(&mt).x(x).t(t).validate(false).call()?;
+            Ok(summation * deferred_factor)
+        }
+    }
+}
+
 #[derive(PartialEq)]
 enum CashFlowStructure {
     Flat,
@@ -1001,7 +1172,7 @@ enum CashFlowStructure {
 
 fn benefit_procedure(
     mt: &MortTableConfig,
-    i: f64,
+    i: &InterestEnum,
     x: f64,
     n: f64,
     t: f64,
@@ -1015,7 +1186,7 @@ fn benefit_procedure(
         // Validate the parameters
         let params = SingleLifeParams {
             mt: mt.clone(),
-            i,
+            i: i.rate(),
             x,
             n,
             t,
@@ -1024,62 +1195,44 @@ fn benefit_procedure(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // Decide if selected table is used
     let mt = get_new_config_with_selected_table(mt, entry_age)?;
 
-    // Initialize k array
-    let total_periods = (n * m as f64) as u32;
-    let k_arr: Vec<f64> = (0..total_periods).map(|k| k as f64).collect();
-
-    // Convert parameters to f64 for calculations
-    let v = 1.0 / (1.0 + i);
+    // Initialize the periods (k/m, (k+1)/m)
     let m = f64::from(m);
-    let moment = f64::from(moment);
-
-    // ----------Discount factor----------
-    let discount_factors: Vec<f64> = k_arr
-        .iter()
-        .map(|&k| v.powf(moment * ((k + 1.0) / m)))
+    let total_periods = (n * m) as u32;
+    let periods: Vec<(f64, f64)> = (0..total_periods)
+        .map(|k| (f64::from(k) / m, f64::from(k + 1) / m))
         .collect();
 
-    // ----------Probabilities vectors----------
+    // ----------Probability----------
     //ₖ/ₘ|₁/ₘqₓ₊ₜ
-    let probabilities: Vec<f64> = k_arr
-        .iter()
-        .map(|&k| {
-            tqx()
-                .mt(&mt)
-                .x(x + t)
-                .t(1.0 / m)
-                .k(k / m)
-                .validate(false)
-                .call()
-                .unwrap_or(0.0)
-        })
-        .collect();
+    let probability = |start: f64, end: f64| {
+        Ok(tqx()
+            .mt(&mt)
+            .x(x + t)
+            .t(end - start)
+            .k(start)
+            .validate(false)
+            .call()
+            .unwrap_or(0.0))
+    };
 
-    // ----------Benefit vector----------
-    let amounts: Vec<f64> = match structure {
-        CashFlowStructure::Flat => vec![1.0; (n * m) as usize],
-        CashFlowStructure::Increasing => k_arr.iter().map(|&k| (k / m).floor() + 1.0).collect(),
-        CashFlowStructure::Decreasing => k_arr.iter().map(|&k| n - (k / m).floor()).collect(),
+    // ----------Amount----------
+    let amount = |start: f64| match structure {
+        CashFlowStructure::Flat => 1.0,
+        CashFlowStructure::Increasing => start.floor() + 1.0,
+        CashFlowStructure::Decreasing => n - start.floor(),
     };
 
     // Calculate the summation
-    let summation: f64 = discount_factors
-        .iter()
-        .zip(probabilities.iter())
-        .zip(amounts.iter())
-        .map(|((df, prob), amount)| df * prob * amount)
-        .sum();
+    let summation = benefit_summation(i, t, moment, &periods, probability, amount)?;
 
-    // Deferred factor: ₜEₓ = vᵗ · ₜpₓ
-    let deferred_factor = v.powf(t) * tpx().mt(&mt).x(x).t(t).validate(false).call()?;
+    // Deferred survival: ₜpₓ (discounting to time 0 is already in the discount factors)
+    let deferred_factor = tpx().mt(&mt).x(x).t(t).validate(false).call()?;
 
     // Final result
     Ok(summation * deferred_factor)
@@ -1092,27 +1245,165 @@ fn benefit_procedure(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mt_config::MortTableConfig;
     use crate::mt_config::mt_data::MortData;
+    use crate::mt_config::{AssumptionEnum, MortTableConfig};
+    use crate::yield_curve::YieldCurve;
     use approx::assert_abs_diff_eq;
 
-    // #[test]
-    // fn test_fn_A1xn_cont_theory() {
-    //     let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
-    //     let mt = MortTableConfig::builder().data(am92).build().unwrap();
-    //     let regular = Ax1n().mt(&mt).i(0.05).x(70).n(20).call().unwrap();
-    //     let cont = Ax1n()
-    //         .mt(&mt)
-    //         .i(0.05)
-    //         .x(70)
-    //         .n(20)
-    //         .t(1)
-    //         .m(1000)
-    //         .call()
-    //         .unwrap();
-    //     let expected = 1.05_f64.powf(0.5);
-    //     assert_abs_diff_eq!(cont / regular, expected, epsilon = 1e-3);
-    // }
+    #[test]
+    fn test_fn_A1xn_cont_theory() {
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let regular = Ax1n().mt(&mt).i(0.05).x(70.0).n(20.0).call().unwrap();
+        let cont = Abarx1n().mt(&mt).i(0.05).x(70.0).n(20.0).call().unwrap();
+        let expected = 1.05_f64.powf(0.5);
+        assert_abs_diff_eq!(cont / regular, expected, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_fn_Abarx1n_udd_exact() {
+        // Under UDD: Ā¹ₓ:ₙ̅ = (i/δ) · A¹ₓ:ₙ̅
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let i = 0.04;
+        let regular = Ax1n().mt(&mt).i(i).x(50.0).n(15.0).t(2.0).call().unwrap();
+        let cont = Abarx1n()
+            .mt(&mt)
+            .i(i)
+            .x(50.0)
+            .n(15.0)
+            .t(2.0)
+            .call()
+            .unwrap();
+        let expected = i / (1.0_f64 + i).ln() * regular;
+        assert_abs_diff_eq!(cont, expected, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_fn_Abarx1n_curve() {
+        // Under UDD each year's deaths are uniform: ∫ P(s) ds over the year times ₖ|qₓ
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let curve = YieldCurve::from_annual_rates()
+            .rates(vec![0.02, 0.03, 0.05])
+            .call()
+            .unwrap();
+        let ans = Abarx1n().mt(&mt).i(&curve).x(60.0).n(3.0).call().unwrap();
+        let expected: f64 = (0..3)
+            .map(|k| {
+                // Flat forward f within year k: ∫ₖᵏ⁺¹ P(s) ds = P(k) · (1 - 1/(1+f)) / ln(1+f)
+                let f = [0.02, 0.03, 0.05][k];
+                let year_integral =
+                    curve.discount_factor(k as f64) * (1.0 - 1.0 / (1.0 + f)) / (1.0_f64 + f).ln();
+                let q = tqx().mt(&mt).x(60.0).t(1.0).k(k as f64).call().unwrap();
+                year_integral * q
+            })
+            .sum();
+        assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fn_Abarx1n_hpb_closed_form() {
+        // At i = 0, Ā¹ₓ:ₙ̅ = ₙqₓ. Under HPB from x = 60.5 to 62.25:
+        // ₙpₓ = ½p₆₀.₅ · p₆₁ · ¼p₆₂ = (1 - ½q₆₀) · (1 - q₆₁) · (1 - q₆₂) / (1 - ¾q₆₂)
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder()
+            .data(am92)
+            .assumption(AssumptionEnum::HPB)
+            .build()
+            .unwrap();
+        let q = |age: f64| tqx().mt(&mt).x(age).call().unwrap();
+        let ans = Abarx1n().mt(&mt).i(0.0).x(60.5).n(1.75).call().unwrap();
+        let survival =
+            (1.0 - 0.5 * q(60.0)) * (1.0 - q(61.0)) * (1.0 - q(62.0)) / (1.0 - 0.75 * q(62.0));
+        assert_abs_diff_eq!(ans, 1.0 - survival, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fn_A1xn_spot_curve() {
+        // ₜ|A¹ₓ:ₙ̅ = Σₖ P(t + k + 1) · ₜpₓ · ₖ|qₓ₊ₜ on an upward sloping spot curve
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let curve = YieldCurve::from_spot_rates()
+            .terms(vec![1.0, 5.0])
+            .rates(vec![0.02, 0.04])
+            .call()
+            .unwrap();
+        let ans = Ax1n()
+            .mt(&mt)
+            .i(&curve)
+            .x(60.0)
+            .n(3.0)
+            .t(2.0)
+            .call()
+            .unwrap();
+        let p = tpx().mt(&mt).x(60.0).t(2.0).call().unwrap();
+        let expected: f64 = (0..3)
+            .map(|k| {
+                let q = tqx().mt(&mt).x(62.0).t(1.0).k(k as f64).call().unwrap();
+                curve.discount_factor(3.0 + k as f64) * p * q
+            })
+            .sum();
+        assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+
+        // The curve differs from its one-year rate used flat
+        let flat = Ax1n().mt(&mt).i(0.02).x(60.0).n(3.0).t(2.0).call().unwrap();
+        assert!(ans < flat);
+    }
+
+    #[test]
+    fn test_fn_A1xn_deferred_second_moment() {
+        // ₜ|²A¹ₓ:ₙ̅ = v²ᵗ · ₜpₓ · ²A¹ₓ₊ₜ:ₙ̅, the deferral discounted at the second moment too
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let deferred = Ax1n()
+            .mt(&mt)
+            .i(0.04)
+            .x(50.0)
+            .n(10.0)
+            .t(5.0)
+            .moment(2)
+            .call()
+            .unwrap();
+        let immediate = Ax1n()
+            .mt(&mt)
+            .i(0.04)
+            .x(55.0)
+            .n(10.0)
+            .moment(2)
+            .call()
+            .unwrap();
+        let p = tpx().mt(&mt).x(50.0).t(5.0).call().unwrap();
+        let expected = 1.04f64.powf(-10.0) * p * immediate;
+        assert_abs_diff_eq!(deferred, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fn_Ax_flat_curve() {
+        // A flat yield curve reproduces the flat-rate result
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let curve = YieldCurve::flat(0.04);
+        let flat = Axn()
+            .mt(&mt)
+            .i(0.04)
+            .x(45.0)
+            .n(20.0)
+            .t(3.0)
+            .m(4)
+            .call()
+            .unwrap();
+        let ans = Axn()
+            .mt(&mt)
+            .i(&curve)
+            .x(45.0)
+            .n(20.0)
+            .t(3.0)
+            .m(4)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(ans, flat, epsilon = 1e-12);
+    }
 
     #[test]
     fn test_fn_A1xn_n_is_0() {
@@ -1256,6 +1547,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fn_Ax_pct_loading() {
+        // Heavier mortality brings the death benefit forward, lighter mortality defers it
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let standard = MortTableConfig::builder()
+            .data(am92.clone())
+            .build()
+            .unwrap();
+        let heavy = MortTableConfig::builder()
+            .data(am92.clone())
+            .pct(1.5)
+            .build()
+            .unwrap();
+        let light = MortTableConfig::builder()
+            .data(am92)
+            .pct(0.75)
+            .build()
+            .unwrap();
+
+        let ans_standard = Ax().mt(&standard).i(0.04).x(50.0).call().unwrap();
+        let ans_heavy = Ax().mt(&heavy).i(0.04).x(50.0).call().unwrap();
+        let ans_light = Ax().mt(&light).i(0.04).x(50.0).call().unwrap();
+        assert!(ans_heavy > ans_standard);
+        assert!(ans_light < ans_standard);
+
+        // First year of a term assurance: A¹ₓ:₁̅ = v · 1.5 · qₓ
+        let qx = tqx().mt(&standard).x(50.0).call().unwrap();
+        let ans = Ax1n().mt(&heavy).i(0.04).x(50.0).n(1.0).call().unwrap();
+        assert_abs_diff_eq!(ans, 1.5 * qx / 1.04, epsilon = 1e-10);
+    }
+
     #[test]
     fn test_fn_IAx_benefit_01() {
         // From Formulae and Tables for Actuarial Examinations
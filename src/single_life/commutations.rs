@@ -50,9 +50,7 @@ pub fn Cx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // As provided - no default
@@ -109,9 +107,7 @@ pub fn Dx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // As provided - no default
@@ -168,9 +164,7 @@ pub fn Mx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // Decide if we need to use entry_age or not - Prebuilt to reduce procedure inside loops
@@ -237,9 +231,7 @@ pub fn Nx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // Decide if we need to use entry_age or not - Prebuilt to reduce procedure inside loops
@@ -306,9 +298,7 @@ pub fn Rx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // Decide if we need to use entry_age or not - Prebuilt to reduce procedure inside loops
@@ -375,9 +365,7 @@ pub fn Sx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     };
 
     // Decide if we need to use entry_age or not - Prebuilt to reduce procedure inside loops
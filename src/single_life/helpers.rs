@@ -3,99 +3,57 @@
 //! Provides DataFrame lookup utilities and mortality table configuration
 //! helpers used by `survivals`, `commutations`, `annuities`, and `benefits`.
 
+use super::survivals::tpx;
 use crate::RSLifeResult;
-use crate::mt_config::MortTableConfig;
+use crate::mt_config::{AssumptionEnum, MortTableConfig};
+use crate::quadrature::gauss_legendre;
+use crate::yield_curve::InterestEnum;
 use polars::prelude::*;
 
 // ================================================
 // PUBLIC (within single_life) FUNCTIONS
 // ================================================
 
-/// Filter a column value from the mortality table DataFrame based on age and optional duration.
+/// Look up `qx` or `lx` from the precomputed table arrays by age and optional duration.
 ///
-/// - If the DataFrame has no `duration` column, filters by `age` only.
-/// - If it has a `duration` column and `duration` is `Some(d)`, filters by both `age` and `duration`.
-/// - If it has a `duration` column and `duration` is `None`, filters by `age` and the max duration value.
+/// - For 1D tables, `duration` is ignored.
+/// - For select tables, `duration` of `None` reads the ultimate (max duration) value.
 pub(super) fn get_value(
     mt: &MortTableConfig,
     age: u32,
     duration: Option<u32>,
     col_name: &str,
 ) -> RSLifeResult<f64> {
-    let df = &mt.data.dataframe;
-    let has_duration = df.get_column_names().contains(&&"duration".into());
-
-    let lazy = df.clone().lazy().filter(col("age").eq(lit(age)));
-
-    let lazy = if has_duration {
-        let dur = match duration {
-            Some(d) => d,
-            None => mt.max_duration()?,
-        };
-        lazy.filter(col("duration").eq(lit(dur)))
-    } else {
-        lazy
+    let lookup = mt.lookup()?;
+    let value = match col_name {
+        "qx" => lookup.qx(age, duration),
+        "lx" => lookup.lx(age, duration),
+        _ => None,
     };
-
-    let value = lazy
-        .select([col(col_name)])
-        .collect()?
-        .column(col_name)?
-        .f64()?
-        .get(0)
-        .ok_or_else(|| {
-            PolarsError::ComputeError(format!("{} not found for age {}", col_name, age).into())
-        })?;
+    let value = value.ok_or_else(|| {
+        PolarsError::ComputeError(format!("{} not found for age {}", col_name, age).into())
+    })?;
     Ok(value)
 }
 
-/// Single-pass lookup of `(lx, lx_next, qx)` at age x.
+/// Lookup of `(lx, lx_next, qx)` at age x.
 ///
-/// Filters the mortality table DataFrame once by `age ∈ {x, x+1}` and returns:
+/// Returns:
 /// - `lx`   at age `x`
 /// - `lx_next` at age `x + 1` (returns `0.0` when `x + 1` is beyond the table)
 /// - `qx`   at age `x`
 ///
-/// Duration handling matches `get_value` (uses `mt.max_duration()` when present).
+/// Duration handling matches `get_value` (uses the max duration when present).
 pub(super) fn get_lx_and_qx(mt: &MortTableConfig, x: u32) -> RSLifeResult<(f64, f64, f64)> {
-    let df = &mt.data.dataframe;
-    let has_duration = df.get_column_names().contains(&&"duration".into());
-
-    let mut lazy = df
-        .clone()
-        .lazy()
-        .filter(col("age").gt_eq(lit(x)).and(col("age").lt_eq(lit(x + 1))));
-
-    if has_duration {
-        lazy = lazy.filter(col("duration").eq(lit(mt.max_duration()?)));
-    }
-
-    let filtered = lazy.select([col("age"), col("lx"), col("qx")]).collect()?;
-    let age_ca = filtered.column("age")?.u32()?;
-    let lx_ca = filtered.column("lx")?.f64()?;
-    let qx_ca = filtered.column("qx")?.f64()?;
-
-    let mut lx_x: Option<f64> = None;
-    let mut qx_x: Option<f64> = None;
-    let mut lx_next: f64 = 0.0;
-
-    for row in 0..filtered.height() {
-        match age_ca.get(row) {
-            Some(a) if a == x => {
-                lx_x = lx_ca.get(row);
-                qx_x = qx_ca.get(row);
-            }
-            Some(a) if a == x + 1 => {
-                lx_next = lx_ca.get(row).unwrap_or(0.0);
-            }
-            _ => {}
-        }
-    }
+    let lookup = mt.lookup()?;
 
-    let lx_x = lx_x
+    let lx_x = lookup
+        .lx(x, None)
         .ok_or_else(|| PolarsError::ComputeError(format!("lx not found for age {}", x).into()))?;
-    let qx_x = qx_x
+    let qx_x = lookup
+        .qx(x, None)
         .ok_or_else(|| PolarsError::ComputeError(format!("qx not found for age {}", x).into()))?;
+    let lx_next = lookup.lx(x + 1, None).unwrap_or(0.0);
 
     Ok((lx_x, lx_next, qx_x))
 }
@@ -105,99 +63,189 @@ pub(super) fn get_lx_and_qx(mt: &MortTableConfig, x: u32) -> RSLifeResult<(f64,
 /// - If the table has no `duration` column, returns the config as-is.
 /// - If `entry_age` is `Some`, builds a selected table from that entry age.
 /// - If `entry_age` is `None`, uses the ultimate table (max duration row).
-pub(super) fn get_new_config_with_selected_table(
+pub(crate) fn get_new_config_with_selected_table(
     mt: &MortTableConfig,
     entry_age: Option<u32>,
 ) -> PolarsResult<MortTableConfig> {
-    let df = &mt.data.dataframe;
-
-    if !df.get_column_names().contains(&&"duration".into()) {
+    let lookup = mt.lookup()?;
+    let Some((_, max_dur)) = lookup.durations() else {
         return Ok(mt.clone());
-    }
+    };
 
-    let selected_df = if let Some(age) = entry_age {
-        get_selected_mortality_table(mt, age)?
-    } else {
-        get_ultimate_mortality_table(mt)?
+    // Selected table: duration runs from the entry age up to the ultimate duration
+    // Ultimate table: max duration everywhere
+    let min_age = lookup.min_age();
+    let max_age = lookup.max_age();
+    let start_age = match entry_age {
+        // Entry age below this has NO meaning in calculation and interpretation
+        Some(age) => u32::max(age, min_age.saturating_sub(max_dur)),
+        None => min_age,
+    };
+    let duration_at = |age: u32| match entry_age {
+        Some(entry) => u32::min(age - entry, max_dur),
+        None => max_dur,
     };
 
-    let mut new_mt = mt.clone();
-    new_mt.data.dataframe = selected_df;
+    let age_vec: Vec<u32> = (start_age..=max_age).collect();
+    // Returns 0.0 if data not found (e.g., age below table's min_age)
+    let qx_vec: Vec<f64> = age_vec
+        .iter()
+        .map(|&age| lookup.qx(age, Some(duration_at(age))).unwrap_or(0.0))
+        .collect();
+    let lx_vec: Vec<f64> = age_vec
+        .iter()
+        .map(|&age| lookup.lx(age, Some(duration_at(age))).unwrap_or(0.0))
+        .collect();
+
+    let height = age_vec.len();
+    let columns = vec![
+        Series::new("age".into(), age_vec).into_column(),
+        Series::new("qx".into(), qx_vec).into_column(),
+        Series::new("lx".into(), lx_vec).into_column(),
+    ];
+    let selected_df = DataFrame::new(height, columns)?;
 
-    Ok(new_mt)
+    mt.with_dataframe(selected_df)
 }
 
-// ================================================
-// PRIVATE FUNCTIONS
-// ================================================
+/// Σₖ P(t + k)^moment · probability(k) · amount(k) over the payment times `k_arr` after a deferral of t.
+///
+/// Summation loop of the annuity procedures (single and joint life). `probability(k)` is the
+/// probability that the payment at time `k` after the deferral is made.
+pub(crate) fn annuity_summation(
+    i: &InterestEnum,
+    t: f64,
+    moment: u32,
+    k_arr: &[f64],
+    probability: impl Fn(f64) -> RSLifeResult<f64>,
+    amount: impl Fn(f64) -> f64,
+) -> RSLifeResult<f64> {
+    let moment = f64::from(moment);
+    let mut summation = 0.0;
+    for &k in k_arr {
+        // Discounting from time 0 at the requested moment: P(t + k)^moment
+        let discount_factor = i.discount_factor(t + k).powf(moment);
+        summation += discount_factor * probability(k)? * amount(k);
+    }
+    Ok(summation)
+}
 
-fn get_ultimate_mortality_table(mt: &MortTableConfig) -> PolarsResult<DataFrame> {
-    let df = &mt.data.dataframe;
-    let max_duration = mt.max_duration()?;
+/// Σ P(t + end)^moment · probability(start, end) · amount(start) over the `periods` after a deferral of t.
+///
+/// Summation loop of the benefit procedures (single and joint life): each claim is paid at the
+/// end of its period `(start, end)`, and `probability(start, end)` is the probability of a claim
+/// in that period.
+pub(crate) fn benefit_summation(
+    i: &InterestEnum,
+    t: f64,
+    moment: u32,
+    periods: &[(f64, f64)],
+    probability: impl Fn(f64, f64) -> RSLifeResult<f64>,
+    amount: impl Fn(f64) -> f64,
+) -> RSLifeResult<f64> {
+    let moment = f64::from(moment);
+    let mut summation = 0.0;
+    for &(start, end) in periods {
+        // Discounting from time 0 at the requested moment: P(t + end)^moment
+        let discount_factor = i.discount_factor(t + end).powf(moment);
+        summation += discount_factor * probability(start, end)? * amount(start);
+    }
+    Ok(summation)
+}
 
-    df.clone()
-        .lazy()
-        .filter(col("duration").eq(lit(max_duration)))
-        .select([col("age"), col("qx"), col("lx")])
-        .collect()
+/// Integrand used by `continuous_integral`.
+pub(super) enum ContinuousIntegrand {
+    /// Density of the future lifetime: `ₛpₓ · μₓ₊ₛ` (continuous benefits).
+    Density,
+    /// Survival probability: `ₛpₓ` (continuous annuities).
+    Survival,
 }
 
-fn get_selected_mortality_table(mt: &MortTableConfig, entry_age: u32) -> PolarsResult<DataFrame> {
-    let df = &mt.data.dataframe;
+/// Integrate `P(s)^moment · f(s)` over `s ∈ [start, end]` for a life aged `x`.
+///
+/// `P(s)` is the discount factor of `i` (`vˢ` for a flat rate). `f` is either the density `ₛpₓ · μₓ₊ₛ` or the survival probability `ₛpₓ`.
+/// The range is split at every integer age so the fractional-age assumption of `mt`
+/// gives a smooth integrand on each piece, which is then integrated by 8-point
+/// Gauss-Legendre quadrature. Within the year of age `k` (with `r = x + s - k`):
+///
+/// ```text
+/// UDD: ᵣpₖ = 1 - r·qₖ                   μₖ₊ᵣ = qₖ / (1 - r·qₖ)
+/// CFM: ᵣpₖ = (1 - qₖ)ʳ                  μₖ₊ᵣ = -ln(1 - qₖ)
+/// HPB: ᵣpₖ = (1 - qₖ) / (1 - (1-r)·qₖ)  μₖ₊ᵣ = qₖ / (1 - (1-r)·qₖ)
+/// ```
+///
+/// `mt` must already be the selected/ultimate table (see `get_new_config_with_selected_table`).
+pub(super) fn continuous_integral(
+    mt: &MortTableConfig,
+    i: &InterestEnum,
+    moment: u32,
+    x: f64,
+    start: f64,
+    end: f64,
+    integrand: ContinuousIntegrand,
+) -> RSLifeResult<f64> {
+    const EPS: f64 = 1e-12;
+    let moment = f64::from(moment);
+    let discount = |s: f64| i.discount_factor(s).powf(moment);
+    let mut total = 0.0;
+    let mut s_a = start;
+
+    while s_a < end - EPS {
+        // Split at the next integer age
+        let age_a = x + s_a;
+        let k = (age_a + EPS).floor();
+        let s_b = f64::min(end, k + 1.0 - x);
+        let r_a = (age_a - k).max(0.0);
+        let r_b = (x + s_b - k).min(1.0);
+
+        let base = tpx().mt(mt).x(x).t(s_a).validate(false).call()?;
+        if base <= 0.0 {
+            break;
+        }
 
-    let min_age = mt.min_age()?;
-    let max_age = mt.max_age()?;
-    let max_dur = mt.max_duration()?;
+        let qk = get_value(mt, k as u32, None, "qx")?.clamp(0.0, 1.0);
 
-    // Entry age below this has NO meaning in calculation and interpretation
-    let start_age = u32::max(entry_age, min_age.saturating_sub(max_dur));
-    let age_vec: Vec<u32> = (start_age..=max_age).collect();
-    let mut qx_vec: Vec<f64> = vec![0.0; age_vec.len()];
-    let mut lx_vec: Vec<f64> = vec![0.0; age_vec.len()];
-
-    for (i, &age) in age_vec.iter().enumerate() {
-        let duration = u32::min(age - entry_age, max_dur); // age runs from start
-        let qx = get_value_2d(df, age, duration, "qx")?;
-        let lx = get_value_2d(df, age, duration, "lx")?;
-        qx_vec[i] = qx;
-        lx_vec[i] = lx;
-    }
+        // Everyone alive dies at once under CFM/HPB when q = 1
+        if qk >= 1.0 && mt.assumption != AssumptionEnum::UDD {
+            if let ContinuousIntegrand::Density = integrand {
+                total += discount(s_a) * base;
+            }
+            break;
+        }
 
-    let height = age_vec.len();
-    let columns = vec![
-        Series::new("age".into(), age_vec).into_column(),
-        Series::new("qx".into(), qx_vec).into_column(),
-        Series::new("lx".into(), lx_vec).into_column(),
-    ];
-    let result = DataFrame::new(height, columns)?;
+        let surv = |r: f64| match mt.assumption {
+            AssumptionEnum::UDD => 1.0 - r * qk,
+            AssumptionEnum::CFM => (1.0 - qk).powf(r),
+            AssumptionEnum::HPB => (1.0 - qk) / (1.0 - (1.0 - r) * qk),
+        };
+        let dens = |r: f64| match mt.assumption {
+            AssumptionEnum::UDD => qk,
+            AssumptionEnum::CFM => -(1.0 - qk).ln() * (1.0 - qk).powf(r),
+            AssumptionEnum::HPB => qk * (1.0 - qk) / (1.0 - (1.0 - r) * qk).powi(2),
+        };
 
-    Ok(result)
-}
+        let surv_a = surv(r_a);
+        if surv_a <= 0.0 {
+            break;
+        }
 
-/// Filter a column value from a 2D DataFrame by age and duration.
-/// Returns 0.0 if data not found (e.g., age below table's min_age).
-fn get_value_2d(df: &DataFrame, age: u32, duration: u32, col_name: &str) -> PolarsResult<f64> {
-    let result = df
-        .clone()
-        .lazy()
-        .filter(col("age").eq(lit(age)))
-        .filter(col("duration").eq(lit(duration)))
-        .select([col(col_name)])
-        .collect()?;
-
-    if result.height() == 0 {
-        return Ok(0.0);
+        // Gauss-Legendre on [r_a, r_b]
+        let piece: f64 = gauss_legendre(r_a, r_b)
+            .map(|(r, weight)| {
+                let s = k + r - x;
+                let f = match integrand {
+                    ContinuousIntegrand::Density => dens(r),
+                    ContinuousIntegrand::Survival => surv(r),
+                };
+                weight * discount(s) * f
+            })
+            .sum();
+
+        total += base / surv_a * piece;
+        s_a = s_b;
     }
 
-    result.column(col_name)?.f64()?.get(0).ok_or_else(|| {
-        PolarsError::ComputeError(
-            format!(
-                "{} not found for age {} duration {}",
-                col_name, age, duration
-            )
-            .into(),
-        )
-    })
+    Ok(total)
 }
 
 // ================================================
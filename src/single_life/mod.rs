@@ -9,7 +9,10 @@
 //!
 //! ## Submodules
 //! - [`annuities`] — Present value and expected value of life annuities (temporary, whole, deferred, etc.)
+//! - [`batch`] — Batch evaluation over many policies from slices or a DataFrame
 //! - [`benefits`] — Present value and expected value of life insurance benefits (whole life, term, pure endowment, etc.)
+//! - [`premiums`] — Net and gross level premiums by the equivalence principle
+//! - [`policy_values`] — Prospective, retrospective and recursive policy values, and death strains
 //! - [`commutations`] — Commutation functions (Dx, Nx, Sx, Cx, Mx, Rx) for efficient actuarial calculations
 //! - [`survivals`] — Survival probabilities (tpx, tqx, etc.) and related functions
 //!
@@ -46,7 +49,10 @@
 
 // Module structure
 pub mod annuities;
+pub mod batch;
 pub mod benefits;
 pub mod commutations;
-mod helpers;
+pub(crate) mod helpers;
+pub mod policy_values;
+pub mod premiums;
 pub mod survivals;
@@ -0,0 +1,510 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use super::commutations::{Dx, Mx, Nx};
+use super::premiums::{
+    ContractEnum, annuity_due, assurance_factor, get_premium_term, gross_premium,
+};
+use super::survivals::tqx;
+use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+use crate::mt_config::MortTableConfig;
+use bon::builder;
+
+// =======================================
+// PUBLIC TYPES
+// =======================================
+
+/// Method used to compute a policy value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReserveMethodEnum {
+    /// EPV of future benefits and expenses less EPV of future premiums.
+    Prospective,
+
+    /// Accumulated past premiums less expenses and claims, via commutation functions.
+    Retrospective,
+
+    /// Backward annual recursion from the end of the contract over the mortality table.
+    Recursive,
+}
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+/// Policy value at duration t: ₜVₓ
+///
+/// Net or gross premium policy value at policy anniversary `t` for a contract issued at age `x`,
+/// with level annual premiums payable in advance and the death benefit paid at the end of the year of death.
+///
+/// Without expenses and premium this is the net premium policy value. Supplying expenses gives the
+/// gross premium policy value. When `premium` is not given, the equivalence principle premium on the
+/// same basis is used (see `gross_premium`), so ₀Vₓ = 0.
+///
+/// # Formula
+/// ```text
+/// Prospective:   ₜV = (S + E)·A + EPV(future expenses) - P·äₓ₊ₜ:ₕ₋ₜ̅
+/// Retrospective: ₜV = [P(Nₓ - Nₓ₊ₖ) - (I + f₁P)Dₓ - (e + fP)(Nₓ₊₁ - Nₓ₊ₖ) - (S + E)(Mₓ - Mₓ₊ₜ)] / Dₓ₊ₜ
+/// Recursive:     ₖV = v·[qₓ₊ₖ(S + E) + pₓ₊ₖ·ₖ₊₁V] - Pₖ + eₖ
+/// ```
+/// where:
+/// - `S` is the sum assured (default 1) and `E` the claim expense (default 0)
+/// - `A` is the assurance factor of the remaining contract at age x+t
+/// - `P` is the annual premium, payable for `premium_term` h years (default contract term)
+/// - `I`, `f₁` are the initial expense and first-year percentage of premium
+/// - `e`, `f` are the renewal per-policy expense and percentage of premium from year 2
+/// - `k = min(t, h)` is the number of premiums paid by time t
+/// - the recursion starts from 0 at the end of a term or whole life contract and from `S + E` at
+///   the maturity of an endowment
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// // Net premium policy value after 10 years of a 20-year endowment
+/// let reserve = tVx()
+///     .mt(&mt)
+///     .i(0.04)
+///     .x(40.0)
+///     .t(10)
+///     .contract(ContractEnum::Endowment(20.0))
+///     .sum_assured(100_000.0)
+///     .call()?;
+///
+/// // The same value by retrospective accumulation
+/// let retro = tVx()
+///     .mt(&mt)
+///     .i(0.04)
+///     .x(40.0)
+///     .t(10)
+///     .contract(ContractEnum::Endowment(20.0))
+///     .sum_assured(100_000.0)
+///     .method(ReserveMethodEnum::Retrospective)
+///     .call()?;
+/// println!("Policy value: {:.2} / {:.2}", reserve, retro);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn tVx(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    t: u32,
+    contract: ContractEnum,
+    #[builder(default = 1.0)] sum_assured: f64,
+    premium: Option<f64>,
+    premium_term: Option<f64>,
+    #[builder(default = 0.0)] initial_expense: f64,
+    #[builder(default = 0.0)] initial_pct: f64,
+    #[builder(default = 0.0)] renewal_pct: f64,
+    #[builder(default = 0.0)] renewal_expense: f64,
+    #[builder(default = 0.0)] claim_expense: f64,
+    #[builder(default = ReserveMethodEnum::Prospective)] method: ReserveMethodEnum,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let h = get_premium_term(mt, x, contract, premium_term)?;
+    let contract_term = get_premium_term(mt, x, contract, None)?;
+    let t_f = t as f64;
+    if t_f > contract_term {
+        return Err(invalid_parameter(
+            "t",
+            format!("duration {t} cannot exceed the contract term {contract_term}"),
+        ));
+    }
+
+    // Equivalence principle premium on the same basis unless supplied
+    let premium = match premium {
+        Some(p) => p,
+        None => gross_premium()
+            .mt(mt)
+            .i(i)
+            .x(x)
+            .contract(contract)
+            .sum_assured(sum_assured)
+            .initial_expense(initial_expense)
+            .initial_pct(initial_pct)
+            .renewal_pct(renewal_pct)
+            .renewal_expense(renewal_expense)
+            .claim_expense(claim_expense)
+            .premium_term(h)
+            .validate(validate)
+            .maybe_entry_age(entry_age)
+            .call()?,
+    };
+
+    let basis = ReserveBasis {
+        mt,
+        i,
+        x,
+        contract,
+        contract_term,
+        premium_term: h,
+        benefit: sum_assured + claim_expense,
+        premium,
+        first_year_expense: initial_expense + initial_pct * premium,
+        renewal_expense: renewal_expense + renewal_pct * premium,
+        entry_age,
+        validate,
+    };
+
+    match method {
+        ReserveMethodEnum::Prospective => basis.prospective(t_f),
+        ReserveMethodEnum::Retrospective => basis.retrospective(t_f),
+        ReserveMethodEnum::Recursive => basis.recursive(t),
+    }
+}
+
+/// Death strain at risk for a policy year: DSAR
+///
+/// # Formula
+/// ```text
+/// DSAR = S + E - ₜ₊₁V
+/// ```
+/// where `S` is the sum assured, `E` the claim expense (default 0) and `ₜ₊₁V` the policy value at the end of the year.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// let dsar = DSAR().sum_assured(100_000.0).reserve(12_500.0).call();
+/// assert_eq!(dsar, 87_500.0);
+/// ```
+#[builder]
+pub fn DSAR(sum_assured: f64, reserve: f64, #[builder(default = 0.0)] claim_expense: f64) -> f64 {
+    sum_assured + claim_expense - reserve
+}
+
+/// Expected death strain for a policy year: EDS
+///
+/// # Formula
+/// ```text
+/// EDS = N · qₓ₊ₜ · DSAR
+/// ```
+/// where `N` is the number of policies in force at the start of the year (default 1) and `x` is the age at the start of the year.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// let dsar = DSAR().sum_assured(50_000.0).reserve(8_000.0).call();
+/// let eds = EDS().mt(&mt).x(60.0).dsar(dsar).policies(500.0).call()?;
+/// let ads = ADS().dsar(dsar).deaths(6.0).call()?;
+/// println!("Mortality profit: {:.2}", eds - ads);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn EDS(
+    mt: &MortTableConfig,
+    x: f64,
+    dsar: f64,
+    #[builder(default = 1.0)] policies: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let qx = tqx()
+        .mt(mt)
+        .x(x)
+        .validate(validate)
+        .maybe_entry_age(entry_age)
+        .call()?;
+    Ok(policies * qx * dsar)
+}
+
+/// Actual death strain for a policy year: ADS
+///
+/// # Formula
+/// ```text
+/// ADS = deaths · DSAR
+/// ```
+///
+/// Mortality profit is `EDS - ADS`; a negative value is a loss.
+#[builder]
+pub fn ADS(dsar: f64, deaths: f64) -> RSLifeResult<f64> {
+    if deaths < 0.0 {
+        return Err(invalid_parameter(
+            "deaths",
+            format!("deaths {deaths} cannot be negative"),
+        ));
+    }
+    Ok(deaths * dsar)
+}
+
+// =======================================
+// PRIVATE FUNCTIONS
+// =======================================
+
+/// Cash flows of a contract shared by the three reserve methods.
+struct ReserveBasis<'a> {
+    mt: &'a MortTableConfig,
+    i: f64,
+    x: f64,
+    contract: ContractEnum,
+    contract_term: f64,
+    premium_term: f64,
+    // Sum assured plus claim expense
+    benefit: f64,
+    premium: f64,
+    // Expense at time 0 (initial expense and first-year premium percentage)
+    first_year_expense: f64,
+    // Expense at the start of each later premium-paying year
+    renewal_expense: f64,
+    entry_age: Option<u32>,
+    validate: bool,
+}
+
+impl ReserveBasis<'_> {
+    /// Value at the end of the contract, before any maturity payment.
+    fn terminal_value(&self) -> f64 {
+        match self.contract {
+            ContractEnum::Endowment(_) => self.benefit,
+            ContractEnum::WholeLife | ContractEnum::Term(_) => 0.0,
+        }
+    }
+
+    fn prospective(&self, t: f64) -> RSLifeResult<f64> {
+        let remaining = self.contract_term - t;
+        if remaining <= 0.0 {
+            return Ok(self.terminal_value());
+        }
+
+        let age = self.x + t;
+        let remaining_contract = match self.contract {
+            ContractEnum::WholeLife => ContractEnum::WholeLife,
+            ContractEnum::Term(_) => ContractEnum::Term(remaining),
+            ContractEnum::Endowment(_) => ContractEnum::Endowment(remaining),
+        };
+        let benefits = self.benefit
+            * assurance_factor(
+                self.mt,
+                self.i,
+                age,
+                remaining_contract,
+                self.entry_age,
+                self.validate,
+            )?;
+
+        // Future premiums and premium-related expenses
+        let premium_years = self.premium_term - t;
+        let annuity = if premium_years > 0.0 {
+            annuity_due(
+                self.mt,
+                self.i,
+                age,
+                premium_years,
+                1,
+                self.entry_age,
+                self.validate,
+            )?
+        } else {
+            0.0
+        };
+
+        let expenses = if t == 0.0 && premium_years > 0.0 {
+            self.first_year_expense + self.renewal_expense * (annuity - 1.0)
+        } else {
+            self.renewal_expense * annuity
+        };
+
+        Ok(benefits + expenses - self.premium * annuity)
+    }
+
+    fn retrospective(&self, t: f64) -> RSLifeResult<f64> {
+        if t == 0.0 {
+            return Ok(0.0);
+        }
+
+        let k = t.min(self.premium_term);
+        let d_x = self.d(self.x)?;
+        let d_xt = self.d(self.x + t)?;
+
+        // Accumulated premiums and expenses over the premium-paying years
+        let (premiums, expenses) = if k > 0.0 {
+            let n_x = self.n(self.x)?;
+            let n_xk = self.n(self.x + k)?;
+            let n_x1 = self.n(self.x + 1.0)?;
+            (
+                self.premium * (n_x - n_xk),
+                self.first_year_expense * d_x + self.renewal_expense * (n_x1 - n_xk),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        // Accumulated death claims
+        let m_x = self.m(self.x)?;
+        let m_xt = self.m(self.x + t)?;
+        let claims = self.benefit * (m_x - m_xt);
+
+        Ok((premiums - expenses - claims) / d_xt)
+    }
+
+    fn recursive(&self, t: u32) -> RSLifeResult<f64> {
+        let v = 1.0 / (1.0 + self.i);
+        let last = self.contract_term.floor() as u32;
+
+        // ₖV = v·[qₓ₊ₖ·(S + E) + pₓ₊ₖ·ₖ₊₁V] - Pₖ + eₖ, from the end of the contract back to t
+        let mut value = self.terminal_value();
+        for k in (t..last).rev() {
+            let q = tqx()
+                .mt(self.mt)
+                .x(self.x + k as f64)
+                .validate(false)
+                .maybe_entry_age(self.entry_age)
+                .call()?;
+
+            let (premium, expense) = match k {
+                _ if k as f64 >= self.premium_term => (0.0, 0.0),
+                0 => (self.premium, self.first_year_expense),
+                _ => (self.premium, self.renewal_expense),
+            };
+
+            value = v * (q * self.benefit + (1.0 - q) * value) - premium + expense;
+        }
+
+        Ok(value)
+    }
+
+    fn d(&self, age: f64) -> RSLifeResult<f64> {
+        Dx().mt(self.mt)
+            .i(self.i)
+            .x(age)
+            .validate(self.validate)
+            .maybe_entry_age(self.entry_age)
+            .call()
+    }
+
+    fn n(&self, age: f64) -> RSLifeResult<f64> {
+        Nx().mt(self.mt)
+            .i(self.i)
+            .x(age)
+            .validate(self.validate)
+            .maybe_entry_age(self.entry_age)
+            .call()
+    }
+
+    fn m(&self, age: f64) -> RSLifeResult<f64> {
+        Mx().mt(self.mt)
+            .i(self.i)
+            .x(age)
+            .validate(self.validate)
+            .maybe_entry_age(self.entry_age)
+            .call()
+    }
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+    use crate::single_life::annuities::aaxn;
+    use approx::assert_abs_diff_eq;
+
+    fn am92() -> MortTableConfig {
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        MortTableConfig::builder().data(am92).build().unwrap()
+    }
+
+    #[test]
+    fn test_fn_tVx_endowment_annuity_identity() {
+        // ₜVₓ:ₙ̅ = 1 - äₓ₊ₜ:ₙ₋ₜ̅ / äₓ:ₙ̅
+        let mt = am92();
+        let ans = tVx()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .t(10)
+            .contract(ContractEnum::Endowment(20.0))
+            .call()
+            .unwrap();
+        let aa_50 = aaxn().mt(&mt).i(0.04).x(50.0).n(10.0).call().unwrap();
+        let aa_40 = aaxn().mt(&mt).i(0.04).x(40.0).n(20.0).call().unwrap();
+        assert_abs_diff_eq!(ans, 1.0 - aa_50 / aa_40, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_fn_tVx_methods_agree() {
+        // Gross premium policy values with expenses and a limited premium term
+        let mt = am92();
+        for contract in [
+            ContractEnum::WholeLife,
+            ContractEnum::Term(25.0),
+            ContractEnum::Endowment(25.0),
+        ] {
+            for t in [0, 1, 7, 15, 25] {
+                let value = |method| {
+                    tVx()
+                        .mt(&mt)
+                        .i(0.06)
+                        .x(40.0)
+                        .t(t)
+                        .contract(contract)
+                        .sum_assured(100_000.0)
+                        .premium_term(15.0)
+                        .initial_expense(250.0)
+                        .initial_pct(0.4)
+                        .renewal_pct(0.05)
+                        .renewal_expense(60.0)
+                        .claim_expense(150.0)
+                        .method(method)
+                        .call()
+                        .unwrap()
+                };
+                let prospective = value(ReserveMethodEnum::Prospective);
+                let retrospective = value(ReserveMethodEnum::Retrospective);
+                let recursive = value(ReserveMethodEnum::Recursive);
+                assert_abs_diff_eq!(prospective, retrospective, epsilon = 1e-6);
+                assert_abs_diff_eq!(prospective, recursive, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fn_tVx_invalid_duration() {
+        let mt = am92();
+        let result = tVx()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .t(21)
+            .contract(ContractEnum::Term(20.0))
+            .call();
+        assert!(matches!(
+            result,
+            Err(crate::RSLifeError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_death_strain() {
+        let mt = am92();
+        let dsar = DSAR()
+            .sum_assured(50_000.0)
+            .reserve(8_000.0)
+            .claim_expense(100.0)
+            .call();
+        assert_abs_diff_eq!(dsar, 42_100.0, epsilon = 1e-9);
+
+        let q60 = tqx().mt(&mt).x(60.0).call().unwrap();
+        let eds = EDS()
+            .mt(&mt)
+            .x(60.0)
+            .dsar(dsar)
+            .policies(500.0)
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(eds, 500.0 * q60 * dsar, epsilon = 1e-9);
+
+        let ads = ADS().dsar(dsar).deaths(6.0).call().unwrap();
+        assert_abs_diff_eq!(ads, 6.0 * dsar, epsilon = 1e-9);
+        assert!(matches!(
+            ADS().dsar(dsar).deaths(-1.0).call(),
+            Err(crate::RSLifeError::InvalidParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,467 @@
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use super::annuities::aaxn;
+use super::benefits::{Ax, Ax1n, Axn};
+use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+use crate::mt_config::MortTableConfig;
+use bon::builder;
+
+// =======================================
+// PUBLIC TYPES
+// =======================================
+
+/// Contract type for premium calculations.
+///
+/// The death benefit is paid at the end of the year of death. The term `n` is in years.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContractEnum {
+    /// Whole life assurance: Aₓ
+    WholeLife,
+
+    /// Term assurance for n years: Aₓ:ₙ̅¹
+    Term(f64),
+
+    /// Endowment assurance for n years: Aₓ:ₙ̅
+    Endowment(f64),
+}
+
+// =======================================
+// PUBLIC FUNCTIONS
+// =======================================
+
+//-----------------Net premiums------------------
+
+/// Net annual premium for whole life assurance: Pₓ
+///
+/// Annual amount of level net premium, payable m times per year in advance, for a benefit of $1
+/// paid at the end of the year of death. Premiums are payable for life unless `premium_term` is given.
+///
+/// # Formula
+/// ```text
+/// Pₓ⁽ᵐ⁾ = Aₓ / äₓ⁽ᵐ⁾
+/// ₕPₓ⁽ᵐ⁾ = Aₓ / äₓ:ₕ̅⁽ᵐ⁾      (limited premium term h)
+/// ```
+/// where:
+/// - `Aₓ` is the whole life assurance factor
+/// - `äₓ⁽ᵐ⁾` is the life annuity-due payable m times per year
+/// - `m` is the premium frequency (default 1)
+/// - `entry_age` is the age at which the insured enters the policy (default None, uses ultimate table)
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// // Monthly premiums for a whole life assurance of 100,000 at age 40
+/// let premium = 100_000.0 * Px().mt(&mt).i(0.04).x(40.0).m(12).call()?;
+/// println!("Annual premium: {:.2}", premium);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Px(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    #[builder(default = 1)] m: u32,
+    premium_term: Option<f64>,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    net_premium_procedure(
+        mt,
+        i,
+        x,
+        m,
+        premium_term,
+        entry_age,
+        validate,
+        ContractEnum::WholeLife,
+    )
+}
+
+/// Net annual premium for term assurance: Pₓ:ₙ̅¹
+///
+/// # Formula
+/// ```text
+/// Pₓ:ₙ̅¹⁽ᵐ⁾ = Aₓ:ₙ̅¹ / äₓ:ₕ̅⁽ᵐ⁾      (h = n unless premium_term is given)
+/// ```
+///
+/// Refer to `Px` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// let premium = Px1n().mt(&mt).i(0.04).x(40.0).n(20.0).call()?;
+/// println!("Net premium rate for 20-year term: {:.6}", premium);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Px1n(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    n: f64,
+    #[builder(default = 1)] m: u32,
+    premium_term: Option<f64>,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    net_premium_procedure(
+        mt,
+        i,
+        x,
+        m,
+        premium_term,
+        entry_age,
+        validate,
+        ContractEnum::Term(n),
+    )
+}
+
+/// Net annual premium for endowment assurance: Pₓ:ₙ̅
+///
+/// # Formula
+/// ```text
+/// Pₓ:ₙ̅⁽ᵐ⁾ = Aₓ:ₙ̅ / äₓ:ₕ̅⁽ᵐ⁾      (h = n unless premium_term is given)
+/// ```
+///
+/// Refer to `Px` for parameter details.
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// let premium = Pxn().mt(&mt).i(0.04).x(40.0).n(20.0).m(12).call()?;
+/// println!("Net premium rate for 20-year endowment: {:.6}", premium);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn Pxn(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    n: f64,
+    #[builder(default = 1)] m: u32,
+    premium_term: Option<f64>,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    net_premium_procedure(
+        mt,
+        i,
+        x,
+        m,
+        premium_term,
+        entry_age,
+        validate,
+        ContractEnum::Endowment(n),
+    )
+}
+
+//-----------------Gross premium------------------
+
+/// Gross annual premium by the equivalence principle.
+///
+/// Annual amount of level gross premium, payable m times per year in advance over the premium term,
+/// such that the EPV of premiums equals the EPV of benefits plus expenses.
+///
+/// # Formula
+/// ```text
+/// G · äₓ:ₕ̅⁽ᵐ⁾ = (S + E_claim) · A + I + e · (äₓ:ₕ̅ - 1)
+///              + f_init · G · äₓ:₁̅⁽ᵐ⁾ + f_ren · G · (äₓ:ₕ̅⁽ᵐ⁾ - äₓ:₁̅⁽ᵐ⁾)
+/// ```
+/// where:
+/// - `S` is the sum assured (`sum_assured`)
+/// - `A` is the assurance factor of the `contract` (whole life, term or endowment)
+/// - `E_claim` is the expense per claim (`claim_expense`, default 0)
+/// - `I` is the initial expense at outset (`initial_expense`, default 0)
+/// - `e` is the annual per-policy renewal expense from year 2 over the premium term (`renewal_expense`, default 0)
+/// - `f_init` is the percentage of first-year premiums (`initial_pct`, default 0)
+/// - `f_ren` is the percentage of premiums from year 2 (`renewal_pct`, default 0)
+/// - `h` is the premium term (`premium_term`, defaults to the contract term)
+/// - `m` is the premium frequency (default 1)
+///
+/// # Errors
+/// - Premium term exceeds the contract term
+/// - Premium percentages leave no premium to fund the benefits
+///
+/// # Examples
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let mt = MortTableConfig::builder().data(mort_data).build()?;
+/// // 25-year endowment of 250,000 with monthly premiums
+/// let premium = gross_premium()
+///     .mt(&mt)
+///     .i(0.06)
+///     .x(40.0)
+///     .contract(ContractEnum::Endowment(25.0))
+///     .sum_assured(250_000.0)
+///     .initial_expense(500.0)
+///     .initial_pct(0.5)
+///     .renewal_pct(0.025)
+///     .renewal_expense(80.0)
+///     .claim_expense(200.0)
+///     .m(12)
+///     .call()?;
+/// println!("Gross annual premium: {:.2}", premium);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn gross_premium(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    contract: ContractEnum,
+    sum_assured: f64,
+    #[builder(default = 0.0)] initial_expense: f64,
+    #[builder(default = 0.0)] initial_pct: f64,
+    #[builder(default = 0.0)] renewal_pct: f64,
+    #[builder(default = 0.0)] renewal_expense: f64,
+    #[builder(default = 0.0)] claim_expense: f64,
+    premium_term: Option<f64>,
+    #[builder(default = 1)] m: u32,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let h = get_premium_term(mt, x, contract, premium_term)?;
+
+    // EPV of benefits and claim expenses
+    let benefit =
+        (sum_assured + claim_expense) * assurance_factor(mt, i, x, contract, entry_age, validate)?;
+
+    // EPV of premium-related annuities
+    let aa_h_m = annuity_due(mt, i, x, h, m, entry_age, validate)?;
+    let aa_1_m = annuity_due(mt, i, x, h.min(1.0), m, entry_age, validate)?;
+
+    // EPV of per-policy renewal expenses from year 2: e · (äₓ:ₕ̅ - 1)
+    let renewal = if h > 1.0 {
+        renewal_expense * (annuity_due(mt, i, x, h, 1, entry_age, validate)? - 1.0)
+    } else {
+        0.0
+    };
+
+    let premium_factor = aa_h_m - initial_pct * aa_1_m - renewal_pct * (aa_h_m - aa_1_m);
+    if premium_factor <= 0.0 {
+        return Err(invalid_parameter(
+            "renewal_pct",
+            "Premium percentages leave no premium to fund the benefits",
+        ));
+    }
+
+    Ok((benefit + initial_expense + renewal) / premium_factor)
+}
+
+// =======================================
+// PRIVATE FUNCTIONS
+// =======================================
+
+fn net_premium_procedure(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    m: u32,
+    premium_term: Option<f64>,
+    entry_age: Option<u32>,
+    validate: bool,
+    contract: ContractEnum,
+) -> RSLifeResult<f64> {
+    let h = get_premium_term(mt, x, contract, premium_term)?;
+    let benefit = assurance_factor(mt, i, x, contract, entry_age, validate)?;
+    let premium_factor = annuity_due(mt, i, x, h, m, entry_age, validate)?;
+    Ok(benefit / premium_factor)
+}
+
+/// Premium term, defaulting to and bounded by the contract term.
+pub(super) fn get_premium_term(
+    mt: &MortTableConfig,
+    x: f64,
+    contract: ContractEnum,
+    premium_term: Option<f64>,
+) -> RSLifeResult<f64> {
+    let contract_term = match contract {
+        ContractEnum::WholeLife => mt.max_age()? as f64 - x,
+        ContractEnum::Term(n) | ContractEnum::Endowment(n) => n,
+    };
+
+    match premium_term {
+        Some(h) if h <= 0.0 => Err(invalid_parameter(
+            "premium_term",
+            format!("premium term {h} must be positive"),
+        )),
+        Some(h) if h > contract_term => Err(invalid_parameter(
+            "premium_term",
+            format!("premium term {h} cannot exceed the contract term {contract_term}"),
+        )),
+        Some(h) => Ok(h),
+        None => Ok(contract_term),
+    }
+}
+
+pub(super) fn assurance_factor(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    contract: ContractEnum,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    match contract {
+        ContractEnum::WholeLife => Ax()
+            .mt(mt)
+            .i(i)
+            .x(x)
+            .validate(validate)
+            .maybe_entry_age(entry_age)
+            .call(),
+        ContractEnum::Term(n) => Ax1n()
+            .mt(mt)
+            .i(i)
+            .x(x)
+            .n(n)
+            .validate(validate)
+            .maybe_entry_age(entry_age)
+            .call(),
+        ContractEnum::Endowment(n) => Axn()
+            .mt(mt)
+            .i(i)
+            .x(x)
+            .n(n)
+            .validate(validate)
+            .maybe_entry_age(entry_age)
+            .call(),
+    }
+}
+
+pub(super) fn annuity_due(
+    mt: &MortTableConfig,
+    i: f64,
+    x: f64,
+    n: f64,
+    m: u32,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    aaxn()
+        .mt(mt)
+        .i(i)
+        .x(x)
+        .n(n)
+        .m(m)
+        .validate(validate)
+        .maybe_entry_age(entry_age)
+        .call()
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mt_config::mt_data::MortData;
+    use crate::single_life::annuities::aax;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_fn_Px_01() {
+        // AM92 at 4%: Pₓ = Aₓ / äₓ = 0.23056 / 20.005
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let ans = Px().mt(&mt).i(0.04).x(40.0).call().unwrap();
+        assert_abs_diff_eq!(ans, 0.23056 / 20.005, epsilon = 1e-5);
+
+        // Premiums limited to 20 years
+        let limited = Px()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .premium_term(20.0)
+            .call()
+            .unwrap();
+        let expected = Ax().mt(&mt).i(0.04).x(40.0).call().unwrap()
+            / aaxn().mt(&mt).i(0.04).x(40.0).n(20.0).call().unwrap();
+        assert_abs_diff_eq!(limited, expected, epsilon = 1e-12);
+
+        // Premium term longer than the contract is rejected
+        assert!(matches!(
+            Px1n()
+                .mt(&mt)
+                .i(0.04)
+                .x(40.0)
+                .n(10.0)
+                .premium_term(15.0)
+                .call(),
+            Err(crate::RSLifeError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_fn_Pxn_identity() {
+        // Pₓ:ₙ̅ = 1/äₓ:ₙ̅ - d
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let i = 0.04;
+        let d = i / (1.0 + i);
+        let premium = Pxn().mt(&mt).i(i).x(40.0).n(20.0).call().unwrap();
+        let annuity = aaxn().mt(&mt).i(i).x(40.0).n(20.0).call().unwrap();
+        assert_abs_diff_eq!(premium, 1.0 / annuity - d, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_fn_gross_premium() {
+        let am92 = MortData::from_builtin("AM92").unwrap();
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+
+        // Without expenses the gross premium is the net premium
+        let gross = gross_premium()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .contract(ContractEnum::WholeLife)
+            .sum_assured(100_000.0)
+            .m(12)
+            .call()
+            .unwrap();
+        let net = 100_000.0 * Px().mt(&mt).i(0.04).x(40.0).m(12).call().unwrap();
+        assert_abs_diff_eq!(gross, net, epsilon = 1e-6);
+
+        // Expenses from the equivalence principle, annual premiums
+        let gross = gross_premium()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .contract(ContractEnum::Term(20.0))
+            .sum_assured(100_000.0)
+            .initial_expense(300.0)
+            .initial_pct(0.5)
+            .renewal_pct(0.05)
+            .renewal_expense(40.0)
+            .claim_expense(100.0)
+            .call()
+            .unwrap();
+        let a = Ax1n().mt(&mt).i(0.04).x(40.0).n(20.0).call().unwrap();
+        let aa = aaxn().mt(&mt).i(0.04).x(40.0).n(20.0).call().unwrap();
+        let expected = (100_100.0 * a + 300.0 + 40.0 * (aa - 1.0)) / (aa - 0.5 - 0.05 * (aa - 1.0));
+        assert_abs_diff_eq!(gross, expected, epsilon = 1e-6);
+
+        // Whole life premiums payable for life match a life annuity
+        let aa_life = aax().mt(&mt).i(0.04).x(40.0).call().unwrap();
+        let gross = gross_premium()
+            .mt(&mt)
+            .i(0.04)
+            .x(40.0)
+            .contract(ContractEnum::WholeLife)
+            .sum_assured(1.0)
+            .call()
+            .unwrap();
+        let a_life = Ax().mt(&mt).i(0.04).x(40.0).call().unwrap();
+        assert_abs_diff_eq!(gross, a_life / aa_life, epsilon = 1e-9);
+    }
+}
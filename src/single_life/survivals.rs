@@ -1,7 +1,11 @@
-use super::helpers::{get_lx_and_qx, get_new_config_with_selected_table, get_value};
+use super::helpers::{
+    ContinuousIntegrand, continuous_integral, get_lx_and_qx, get_new_config_with_selected_table,
+    get_value,
+};
 use crate::RSLifeResult;
 use crate::mt_config::{AssumptionEnum, MortTableConfig};
 use crate::param::SurvivalFunctionParams;
+use crate::yield_curve::InterestEnum;
 use bon::builder;
 
 // =======================================
@@ -36,10 +40,10 @@ use bon::builder;
 /// ₜpₓ₊ₛ = (1 - qₓ)ᵗ
 /// ```
 ///
-/// - HPB (Hyperbolic):
+/// - HPB (Hyperbolic, Balmer):
 /// ```text
-/// ₜqₓ₊ₛ = t · qₓ / (1 + s · qₓ)
-/// ₜpₓ₊ₛ = 1 - t · qₓ / (1 + s · qₓ)
+/// ₜqₓ₊ₛ = t · qₓ / (1 - (1 - s - t) · qₓ)
+/// ₜpₓ₊ₛ = (1 - (1 - s) · qₓ) / (1 - (1 - s - t) · qₓ)
 /// ```
 ///
 /// # Examples
@@ -96,9 +100,7 @@ pub fn tpx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // Decide if selected table is used
@@ -213,6 +215,202 @@ pub fn tqx(
     Ok(kpx - ktpx)
 }
 
+/// Force of mortality: μₓ (instantaneous rate of mortality at age x, fractional ages supported)
+///
+/// For tables generated from a parametric law (`MortData::from_Gompertz_law`, `from_Makeham_law`,
+/// `from_Weibull_law`) with `pct = 1.0`, the exact analytic μₓ is returned. Otherwise μₓ is derived
+/// from qₓ of the whole age below under the fractional age assumption:
+///
+/// # Formula
+/// ```text
+/// UDD: μₓ₊ₛ = qₓ / (1 - s·qₓ)
+/// CFM: μₓ₊ₛ = -ln(1 - qₓ)
+/// HPB: μₓ₊ₛ = qₓ / (1 - (1 - s)·qₓ)
+/// ```
+/// where `x` is a whole age and `0 ≤ s < 1`.
+///
+/// When `entry_age` is provided, uses the selected mortality table starting from that entry age.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rslife::prelude::*;
+/// let data = MortData::from_Gompertz_law().B(0.0003).C(1.07).call()?;
+/// let config = MortTableConfig::builder().data(data).build()?;
+///
+/// // Exact μ₅₀.₅ = B·C⁵⁰·⁵
+/// let mu = mux().mt(&config).x(50.5).call()?;
+/// assert!((mu - 0.0003 * 1.07_f64.powf(50.5)).abs() < 1e-12);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn mux(
+    mt: &MortTableConfig,
+    x: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    if validate {
+        let params = SurvivalFunctionParams {
+            mt: mt.clone(),
+            x,
+            t: 0.0,
+            k: 0.0,
+            entry_age,
+        };
+
+        params.validate_all()?;
+    }
+
+    // Exact force of mortality when the table comes from an unadjusted parametric law
+    if let Some(law) = mt.data.metadata.law
+        && mt.pct == 1.0
+    {
+        return Ok(law.mu(x));
+    }
+
+    // Decide if selected table is used
+    let mt = get_new_config_with_selected_table(mt, entry_age)?;
+    let x_whole = x.floor() as u32;
+    let s = x.fract();
+
+    let qx = get_value(&mt, x_whole, None, "qx")?;
+
+    let mu = match mt.assumption {
+        AssumptionEnum::UDD => qx / (1.0 - s * qx),
+        AssumptionEnum::CFM => -(1.0 - qx).ln(),
+        AssumptionEnum::HPB => qx / (1.0 - (1.0 - s) * qx),
+    };
+
+    Ok(mu)
+}
+
+/// Curtate expectation of life: eₓ (expected number of whole years lived after age x)
+///
+/// # Formula
+/// ```text
+/// eₓ = Σₖ₌₁^{ω-x} ₖpₓ
+/// ```
+/// where `ω` is the maximum age of the table.
+///
+/// When `entry_age` is provided, uses the selected mortality table starting from that entry age.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let e65 = ex().mt(&config).x(65.0).call()?;
+/// println!("Curtate expectation of life at 65: {:.4}", e65);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn ex(
+    mt: &MortTableConfig,
+    x: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    let n = mt.max_age()? as f64 - x;
+    curtate_expectation(mt, x, n, entry_age, validate)
+}
+
+/// Temporary curtate expectation of life: eₓ:ₙ̅ (expected number of whole years lived in the next n years)
+///
+/// # Formula
+/// ```text
+/// eₓ:ₙ̅ = Σₖ₌₁ⁿ ₖpₓ
+/// ```
+///
+/// When `entry_age` is provided, uses the selected mortality table starting from that entry age.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let e = exn().mt(&config).x(50.0).n(10.0).entry_age(48).call()?;
+/// println!("10-year temporary curtate expectation: {:.4}", e);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn exn(
+    mt: &MortTableConfig,
+    x: f64,
+    n: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    curtate_expectation(mt, x, n, entry_age, validate)
+}
+
+/// Complete expectation of life: e̊ₓ (expected future lifetime of a life aged x)
+///
+/// Integrates `ₜpₓ` under the configured fractional age assumption, up to the end of the
+/// final year of age in the table.
+///
+/// # Formula
+/// ```text
+/// e̊ₓ = ∫₀^{ω+1-x} ₜpₓ dt
+/// ```
+/// Under UDD, `e̊ₓ = eₓ + ½` when the table closes with `q_ω = 1`.
+///
+/// When `entry_age` is provided, uses the selected mortality table starting from that entry age.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let e65 = ebarx().mt(&config).x(65.0).call()?;
+/// println!("Complete expectation of life at 65: {:.4}", e65);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn ebarx(
+    mt: &MortTableConfig,
+    x: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    // Validate up to the last age in the table, then integrate through its final year
+    let n = mt.max_age()? as f64 - x;
+    complete_expectation(mt, x, n, n + 1.0, entry_age, validate)
+}
+
+/// Temporary complete expectation of life: e̊ₓ:ₙ̅ (expected time lived in the next n years)
+///
+/// # Formula
+/// ```text
+/// e̊ₓ:ₙ̅ = ∫₀ⁿ ₜpₓ dt
+/// ```
+///
+/// When `entry_age` is provided, uses the selected mortality table starting from that entry age.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rslife::prelude::*;
+/// # let mort_data = MortData::from_builtin("AM92")?;
+/// # let config = MortTableConfig::builder().data(mort_data).build()?;
+/// let e = ebarxn().mt(&config).x(40.5).n(25.0).call()?;
+/// println!("25-year temporary complete expectation: {:.4}", e);
+/// # RSLifeResult::Ok(())
+/// ```
+#[builder]
+pub fn ebarxn(
+    mt: &MortTableConfig,
+    x: f64,
+    n: f64,
+    entry_age: Option<u32>,
+    #[builder(default = true)] validate: bool,
+) -> RSLifeResult<f64> {
+    complete_expectation(mt, x, n, n, entry_age, validate)
+}
+
 /// Number of lives: lₓ (expected number of lives at age x from the mortality table)
 ///
 /// Computes lₓ for any age `x`, including fractional ages, using the survival probability from the
@@ -253,9 +451,7 @@ pub fn lx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // Decide if selected table is used
@@ -275,8 +471,8 @@ pub fn lx(
         // ₜpₓ = (1 - qₓ)ᵗ
         AssumptionEnum::CFM => (1.0 - qx).powf(x_frac) * lx,
 
-        // ₜpₓ = 1 - t · qₓ
-        _ => (1.0 - x_frac * qx) * lx,
+        // ₜpₓ = (1 - qₓ) / (1 - (1 - t) · qₓ)
+        _ => (1.0 - qx) / (1.0 - (1.0 - x_frac) * qx) * lx,
     };
     Ok(result)
 }
@@ -320,9 +516,7 @@ pub fn dx(
             entry_age,
         };
 
-        params
-            .validate_all()
-            .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)?;
+        params.validate_all()?;
     }
 
     // Decide if selected table is used
@@ -363,14 +557,82 @@ fn tpx_frac_t(mt: &MortTableConfig, x: f64, t: f64) -> RSLifeResult<f64> {
         // ₜpₓ₊ₛ = (1 - qₓ)ᵗ
         AssumptionEnum::CFM => (1.0 - qx).powf(t),
 
-        // ₜqₓ₊ₛ = t · qₓ / (1 + s · qₓ)
-        // ₜpₓ₊ₛ = 1 - t · qₓ / (1 + s · qₓ)
-        _ => 1.0 - t * qx / (1.0 + x_frac * qx),
+        // ₜqₓ₊ₛ = t · qₓ / (1 - (1 - s - t) · qₓ)
+        // ₜpₓ₊ₛ = (1 - (1 - s) · qₓ) / (1 - (1 - s - t) · qₓ)
+        _ => (1.0 - (1.0 - x_frac) * qx) / (1.0 - (1.0 - x_frac - t) * qx),
     };
 
     Ok(survival_rate)
 }
 
+fn curtate_expectation(
+    mt: &MortTableConfig,
+    x: f64,
+    n: f64,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    if validate {
+        let params = SurvivalFunctionParams {
+            mt: mt.clone(),
+            x,
+            t: n,
+            k: 0.0,
+            entry_age,
+        };
+
+        params.validate_all()?;
+    }
+
+    // Decide if selected table is used
+    let mt = get_new_config_with_selected_table(mt, entry_age)?;
+
+    // Σₖ₌₁ⁿ ₖpₓ
+    let total_years = n.floor() as u32;
+    let mut result = 0.0;
+    for k in 1..=total_years {
+        result += tpx().mt(&mt).x(x).t(k as f64).validate(false).call()?;
+    }
+
+    Ok(result)
+}
+
+fn complete_expectation(
+    mt: &MortTableConfig,
+    x: f64,
+    n_validate: f64,
+    n: f64,
+    entry_age: Option<u32>,
+    validate: bool,
+) -> RSLifeResult<f64> {
+    if validate {
+        let params = SurvivalFunctionParams {
+            mt: mt.clone(),
+            x,
+            t: n_validate,
+            k: 0.0,
+            entry_age,
+        };
+
+        params.validate_all()?;
+    }
+
+    // Decide if selected table is used
+    let mt = get_new_config_with_selected_table(mt, entry_age)?;
+
+    // ∫₀ⁿ ₜpₓ dt (no discounting)
+    let no_interest = InterestEnum::Flat(0.0);
+    continuous_integral(
+        &mt,
+        &no_interest,
+        1,
+        x,
+        0.0,
+        n,
+        ContinuousIntegrand::Survival,
+    )
+}
+
 // ================================================
 // UNIT TESTS
 // ================================================
@@ -381,6 +643,171 @@ mod tests {
     use crate::mt_config::mt_data::MortData;
     use crate::mt_config::{AssumptionEnum, MortTableConfig};
     use approx::assert_abs_diff_eq;
+    use polars::prelude::*;
+
+    #[test]
+    fn test_mux_from_table() {
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let q60 = tqx()
+            .mt(&MortTableConfig::builder()
+                .data(am92.clone())
+                .build()
+                .unwrap())
+            .x(60.0)
+            .call()
+            .unwrap();
+
+        let expectations = [
+            (AssumptionEnum::UDD, q60 / (1.0 - 0.25 * q60)),
+            (AssumptionEnum::CFM, -(1.0 - q60).ln()),
+            (AssumptionEnum::HPB, q60 / (1.0 - 0.75 * q60)),
+        ];
+        for (assumption, expected) in expectations {
+            let mt = MortTableConfig::builder()
+                .data(am92.clone())
+                .assumption(assumption)
+                .build()
+                .unwrap();
+            let ans = mux().mt(&mt).x(60.25).call().unwrap();
+            assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tpx_hpb_fractional() {
+        // Balmer: ₜpₓ₊ₛ = (1 - (1 - s)·qₓ) / (1 - (1 - s - t)·qₓ)
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder()
+            .data(am92)
+            .assumption(AssumptionEnum::HPB)
+            .build()
+            .unwrap();
+        let q60 = tqx().mt(&mt).x(60.0).call().unwrap();
+        let ans = tpx().mt(&mt).x(60.25).t(0.5).call().unwrap();
+        assert_abs_diff_eq!(
+            ans,
+            (1.0 - 0.75 * q60) / (1.0 - 0.25 * q60),
+            epsilon = 1e-12
+        );
+
+        // Consistent with lₓ₊ₛ = lₓ · (1 - qₓ) / (1 - (1 - s)·qₓ)
+        let l_a = lx().mt(&mt).x(60.25).call().unwrap();
+        let l_b = lx().mt(&mt).x(60.75).call().unwrap();
+        assert_abs_diff_eq!(ans, l_b / l_a, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_mux_from_law() {
+        // Makeham law keeps its parameters, so μₓ is exact
+        let data = MortData::from_Makeham_law()
+            .A(0.00022)
+            .B(2.7e-6)
+            .C(1.124)
+            .call()
+            .unwrap();
+        let mt = MortTableConfig::builder()
+            .data(data.clone())
+            .build()
+            .unwrap();
+        let ans = mux().mt(&mt).x(70.4).call().unwrap();
+        let expected = 0.00022 + 2.7e-6 * 1.124_f64.powf(70.4);
+        assert_abs_diff_eq!(ans, expected, epsilon = 1e-12);
+
+        // A pct adjustment falls back to the table
+        let mt = MortTableConfig::builder()
+            .data(data)
+            .pct(1.2)
+            .build()
+            .unwrap();
+        let q70 = tqx().mt(&mt).x(70.0).call().unwrap();
+        let ans = mux().mt(&mt).x(70.4).call().unwrap();
+        assert_abs_diff_eq!(ans, q70 / (1.0 - 0.4 * q70), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ex_and_ebarx() {
+        // Under UDD: e̊ₓ = eₓ + ½
+        let am92 = MortData::from_builtin("AM92").expect("Failed to load AM92 selected table");
+        let mt = MortTableConfig::builder().data(am92).build().unwrap();
+        let e = ex().mt(&mt).x(60.0).call().unwrap();
+        let ebar = ebarx().mt(&mt).x(60.0).call().unwrap();
+        assert_abs_diff_eq!(ebar, e + 0.5, epsilon = 1e-9);
+
+        // Temporary versions from the survival probabilities directly
+        let p: Vec<f64> = (0..=3)
+            .map(|k| {
+                tpx()
+                    .mt(&mt)
+                    .x(60.0)
+                    .t(k as f64)
+                    .entry_age(58)
+                    .call()
+                    .unwrap()
+            })
+            .collect();
+        let e3 = exn().mt(&mt).x(60.0).n(3.0).entry_age(58).call().unwrap();
+        assert_abs_diff_eq!(e3, p[1] + p[2] + p[3], epsilon = 1e-12);
+        let ebar3 = ebarxn()
+            .mt(&mt)
+            .x(60.0)
+            .n(3.0)
+            .entry_age(58)
+            .call()
+            .unwrap();
+        let expected = 0.5 * p[0] + p[1] + p[2] + 0.5 * p[3];
+        assert_abs_diff_eq!(ebar3, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_expectations_constant_force() {
+        // Constant force μ under CFM: ₜpₓ = e^(-μt) at all ages up to ω = 110
+        let mu: f64 = 0.05;
+        let ages: Vec<u32> = (0..=110).collect();
+        let qx = vec![1.0 - (-mu).exp(); ages.len()];
+        let data = MortData::from_df(df! { "age" => ages, "qx" => qx }.unwrap()).unwrap();
+        let mt = MortTableConfig::builder()
+            .data(data)
+            .assumption(AssumptionEnum::CFM)
+            .build()
+            .unwrap();
+        let p = (-mu).exp();
+
+        // eₓ = Σₖ₌₁ᴺ pᵏ = p·(1 - pᴺ)/(1 - p), N = ω - x
+        let e = ex().mt(&mt).x(60.0).call().unwrap();
+        assert_abs_diff_eq!(e, p * (1.0 - p.powi(50)) / (1.0 - p), epsilon = 1e-10);
+        let e10 = exn().mt(&mt).x(60.0).n(10.0).call().unwrap();
+        assert_abs_diff_eq!(e10, p * (1.0 - p.powi(10)) / (1.0 - p), epsilon = 1e-10);
+
+        // e̊ₓ:ₙ̅ = (1 - e^(-μn))/μ, through the final year of age for e̊ₓ
+        let ebar = ebarx().mt(&mt).x(60.0).call().unwrap();
+        assert_abs_diff_eq!(ebar, (1.0 - (-mu * 51.0).exp()) / mu, epsilon = 1e-10);
+        let ebar10 = ebarxn().mt(&mt).x(40.5).n(10.0).call().unwrap();
+        assert_abs_diff_eq!(ebar10, (1.0 - (-mu * 10.0).exp()) / mu, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_expectations_de_moivre() {
+        // De Moivre with ω = 100 under UDD: qₓ = 1/(ω - x), ₜpₓ = 1 - t/(ω - x)
+        let omega = 100u32;
+        let ages: Vec<u32> = (0..omega).collect();
+        let qx: Vec<f64> = ages.iter().map(|&x| 1.0 / (omega - x) as f64).collect();
+        let data = MortData::from_df(df! { "age" => ages, "qx" => qx }.unwrap()).unwrap();
+        let mt = MortTableConfig::builder().data(data).build().unwrap();
+
+        // eₓ = (ω - x - 1)/2 and e̊ₓ = (ω - x)/2
+        assert_abs_diff_eq!(ex().mt(&mt).x(60.0).call().unwrap(), 19.5, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            ebarx().mt(&mt).x(60.0).call().unwrap(),
+            20.0,
+            epsilon = 1e-10
+        );
+
+        // eₓ:ₙ̅ = n - n(n+1)/(2(ω - x)) and e̊ₓ:ₙ̅ = n - n²/(2(ω - x))
+        let e10 = exn().mt(&mt).x(60.0).n(10.0).call().unwrap();
+        assert_abs_diff_eq!(e10, 10.0 - 110.0 / 80.0, epsilon = 1e-10);
+        let ebar10 = ebarxn().mt(&mt).x(60.0).n(10.0).call().unwrap();
+        assert_abs_diff_eq!(ebar10, 10.0 - 100.0 / 80.0, epsilon = 1e-10);
+    }
 
     #[test]
     fn test_tpx_01() {
@@ -0,0 +1,429 @@
+//! # Yield Curves
+//!
+//! Term-structure discounting as an alternative to a flat effective rate `i`.
+//!
+//! A [`YieldCurve`] is built from annual effective spot rates, forward rates or a plain vector of
+//! annual rates, and interpolated with one of [`InterpolationEnum`]:
+//! - **Linear**: linear interpolation on spot rates, flat beyond the last term
+//! - **FlatForward**: piecewise constant forward rates (linear on log discount factors)
+//! - **SmithWilson**: exact fit to the input prices with extrapolation towards an ultimate forward rate
+//!
+//! The procedure-backed life functions (`Ax`, `Ax1n`, `Axn`, `Exn`, `IAx`, `DAx1n`, `aax`, `aaxn`,
+//! `ax`, `axn`, ...) and the continuous `Abarx`, `Abarx1n`, `abarx`, `abarxn` accept either an `f64` or a curve for `.i()` through [`InterestEnum`].
+//!
+//! ```rust
+//! # use rslife::prelude::*;
+//! # let mort_data = MortData::from_builtin("AM92")?;
+//! # let config = MortTableConfig::builder().data(mort_data).build()?;
+//! let curve = YieldCurve::from_spot_rates()
+//!     .terms(vec![1.0, 5.0, 10.0, 20.0])
+//!     .rates(vec![0.030, 0.034, 0.037, 0.040])
+//!     .call()?;
+//!
+//! let annuity = aaxn().mt(&config).i(&curve).x(50.0).n(20.0).call()?;
+//! println!("Annuity on a spot curve: {:.6}", annuity);
+//! # RSLifeResult::Ok(())
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::RSLifeResult;
+use crate::error::invalid_parameter;
+use crate::linalg::solve;
+use bon::bon;
+
+/// Interpolation and extrapolation method of a [`YieldCurve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationEnum {
+    /// Linear interpolation on annual effective spot rates, flat extrapolation.
+    Linear,
+
+    /// Constant forward rate between terms, last forward rate carried beyond the last term.
+    FlatForward,
+
+    /// Smith-Wilson with ultimate forward rate `ufr` (annual effective) and convergence speed `alpha`.
+    SmithWilson { ufr: f64, alpha: f64 },
+}
+
+/// Annual effective spot-rate curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YieldCurve {
+    terms: Vec<f64>,
+    spot_rates: Vec<f64>,
+    interpolation: InterpolationEnum,
+    // Nodes of -ln P(t) at the terms, used by FlatForward
+    log_df: Vec<f64>,
+    // Smith-Wilson weights ζ, empty for other methods
+    zeta: Vec<f64>,
+}
+
+#[bon]
+impl YieldCurve {
+    /// Flat curve at effective annual rate `i`.
+    pub fn flat(i: f64) -> Self {
+        Self {
+            terms: vec![1.0],
+            spot_rates: vec![i],
+            interpolation: InterpolationEnum::FlatForward,
+            log_df: vec![(1.0 + i).ln()],
+            zeta: Vec::new(),
+        }
+    }
+
+    /// Build a curve from annual effective spot rates at the given terms (in years).
+    ///
+    /// # Errors
+    /// - `terms` and `rates` are empty or of different lengths
+    /// - `terms` are not positive and strictly increasing
+    /// - a rate is not greater than -1
+    #[builder]
+    pub fn from_spot_rates(
+        terms: Vec<f64>,
+        rates: Vec<f64>,
+        #[builder(default = InterpolationEnum::Linear)] interpolation: InterpolationEnum,
+    ) -> RSLifeResult<Self> {
+        validate_nodes(&terms, &rates)?;
+
+        let zeta = match interpolation {
+            InterpolationEnum::SmithWilson { ufr, alpha } => {
+                if alpha <= 0.0 || ufr <= -1.0 {
+                    return Err(invalid_parameter(
+                        "interpolation",
+                        "Smith-Wilson requires alpha > 0 and ufr > -1",
+                    ));
+                }
+                smith_wilson_zeta(&terms, &rates, ufr, alpha)?
+            }
+            _ => Vec::new(),
+        };
+
+        let log_df = terms
+            .iter()
+            .zip(rates.iter())
+            .map(|(&u, &s)| u * (1.0 + s).ln())
+            .collect();
+
+        Ok(Self {
+            terms,
+            spot_rates: rates,
+            interpolation,
+            log_df,
+            zeta,
+        })
+    }
+
+    /// Build a curve from annual effective forward rates.
+    ///
+    /// `rates[k]` applies between `terms[k-1]` (or 0) and `terms[k]`:
+    /// ```text
+    /// (1 + sₙ)^tₙ = ∏ₖ (1 + fₖ)^(tₖ - tₖ₋₁)
+    /// ```
+    #[builder]
+    pub fn from_forward_rates(
+        terms: Vec<f64>,
+        rates: Vec<f64>,
+        #[builder(default = InterpolationEnum::FlatForward)] interpolation: InterpolationEnum,
+    ) -> RSLifeResult<Self> {
+        validate_nodes(&terms, &rates)?;
+
+        let mut log_accumulation = 0.0;
+        let mut prev_term = 0.0;
+        let spot_rates: Vec<f64> = terms
+            .iter()
+            .zip(rates.iter())
+            .map(|(&term, &f)| {
+                log_accumulation += (term - prev_term) * (1.0 + f).ln();
+                prev_term = term;
+                (log_accumulation / term).exp() - 1.0
+            })
+            .collect();
+
+        Self::from_spot_rates()
+            .terms(terms)
+            .rates(spot_rates)
+            .interpolation(interpolation)
+            .call()
+    }
+
+    /// Build a curve from a vector of annual rates, `rates[k]` applying in year k+1.
+    #[builder]
+    pub fn from_annual_rates(
+        rates: Vec<f64>,
+        #[builder(default = InterpolationEnum::FlatForward)] interpolation: InterpolationEnum,
+    ) -> RSLifeResult<Self> {
+        let terms: Vec<f64> = (1..=rates.len()).map(|k| k as f64).collect();
+        Self::from_forward_rates()
+            .terms(terms)
+            .rates(rates)
+            .interpolation(interpolation)
+            .call()
+    }
+
+    /// Terms (in years) of the input nodes.
+    pub fn terms(&self) -> &[f64] {
+        &self.terms
+    }
+
+    /// Annual effective spot rates at the input nodes.
+    pub fn spot_rates(&self) -> &[f64] {
+        &self.spot_rates
+    }
+
+    /// Discount factor P(t) for a payment at time t.
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 1.0;
+        }
+
+        match self.interpolation {
+            InterpolationEnum::Linear => {
+                let s = interpolate(&self.terms, &self.spot_rates, t);
+                (1.0 + s).powf(-t)
+            }
+            InterpolationEnum::FlatForward => {
+                // Nodes of -ln P(t), starting from (0, 0)
+                let log_df = &self.log_df;
+                let (mut t0, mut y0) = (0.0, 0.0);
+                for (&t1, &y1) in self.terms.iter().zip(log_df.iter()) {
+                    if t <= t1 {
+                        return (-(y0 + (y1 - y0) * (t - t0) / (t1 - t0))).exp();
+                    }
+                    (t0, y0) = (t1, y1);
+                }
+                // Beyond the last term: carry the last forward rate
+                let n = self.terms.len();
+                let last_forward = if n > 1 {
+                    (log_df[n - 1] - log_df[n - 2]) / (self.terms[n - 1] - self.terms[n - 2])
+                } else {
+                    log_df[0] / self.terms[0]
+                };
+                (-(y0 + last_forward * (t - t0))).exp()
+            }
+            InterpolationEnum::SmithWilson { ufr, alpha } => {
+                let omega = (1.0 + ufr).ln();
+                let kernel: f64 = self
+                    .terms
+                    .iter()
+                    .zip(self.zeta.iter())
+                    .map(|(&u, &z)| z * wilson(t, u, omega, alpha))
+                    .sum();
+                (-omega * t).exp() + kernel
+            }
+        }
+    }
+
+    /// Annual effective spot rate s(t) such that P(t) = (1 + s)^(-t).
+    pub fn spot_rate(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return self.spot_rates[0];
+        }
+        self.discount_factor(t).powf(-1.0 / t) - 1.0
+    }
+
+    /// Annual effective forward rate between times t1 < t2.
+    pub fn forward_rate(&self, t1: f64, t2: f64) -> f64 {
+        (self.discount_factor(t1) / self.discount_factor(t2)).powf(1.0 / (t2 - t1)) - 1.0
+    }
+}
+
+/// Interest basis accepted by the procedure-backed life functions through `.i()`.
+///
+/// Builders take `impl Into<InterestEnum>`, so `.i(0.04)`, `.i(curve)` and `.i(&curve)` all work.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterestEnum {
+    /// Flat effective annual rate.
+    Flat(f64),
+
+    /// Term structure of interest rates.
+    Curve(YieldCurve),
+}
+
+impl InterestEnum {
+    /// Discount factor for a payment at time t: vᵗ or P(t).
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        match self {
+            InterestEnum::Flat(i) => (1.0 + i).powf(-t),
+            InterestEnum::Curve(curve) => curve.discount_factor(t),
+        }
+    }
+
+    /// Representative annual rate: the flat rate, or the one-year spot rate of a curve.
+    pub fn rate(&self) -> f64 {
+        match self {
+            InterestEnum::Flat(i) => *i,
+            InterestEnum::Curve(curve) => curve.spot_rate(1.0),
+        }
+    }
+}
+
+impl From<f64> for InterestEnum {
+    fn from(i: f64) -> Self {
+        InterestEnum::Flat(i)
+    }
+}
+
+impl From<YieldCurve> for InterestEnum {
+    fn from(curve: YieldCurve) -> Self {
+        InterestEnum::Curve(curve)
+    }
+}
+
+impl From<&YieldCurve> for InterestEnum {
+    fn from(curve: &YieldCurve) -> Self {
+        InterestEnum::Curve(curve.clone())
+    }
+}
+
+// ================================================
+// PRIVATE FUNCTIONS
+// ================================================
+
+fn validate_nodes(terms: &[f64], rates: &[f64]) -> RSLifeResult<()> {
+    if terms.is_empty() || terms.len() != rates.len() {
+        return Err(invalid_parameter(
+            "rates",
+            "terms and rates must be non-empty and of the same length",
+        ));
+    }
+    if terms[0] <= 0.0 || terms.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(invalid_parameter(
+            "terms",
+            "terms must be positive and strictly increasing",
+        ));
+    }
+    if rates.iter().any(|&r| r <= -1.0) {
+        return Err(invalid_parameter("rates", "rates must be greater than -1"));
+    }
+    Ok(())
+}
+
+// Linear interpolation with flat extrapolation at both ends
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    if x <= xs[0] {
+        return ys[0];
+    }
+    for k in 1..xs.len() {
+        if x <= xs[k] {
+            let w = (x - xs[k - 1]) / (xs[k] - xs[k - 1]);
+            return ys[k - 1] + w * (ys[k] - ys[k - 1]);
+        }
+    }
+    ys[ys.len() - 1]
+}
+
+// Wilson kernel:
+// W(t, u) = e^(-ω(t+u)) · [α·min(t,u) - ½·e^(-α·max(t,u))·(e^(α·min(t,u)) - e^(-α·min(t,u)))]
+fn wilson(t: f64, u: f64, omega: f64, alpha: f64) -> f64 {
+    let (lo, hi) = (t.min(u), t.max(u));
+    (-omega * (t + u)).exp()
+        * (alpha * lo - 0.5 * (-alpha * hi).exp() * ((alpha * lo).exp() - (-alpha * lo).exp()))
+}
+
+// Solve W·ζ = P - μ for the Smith-Wilson weights
+fn smith_wilson_zeta(terms: &[f64], rates: &[f64], ufr: f64, alpha: f64) -> RSLifeResult<Vec<f64>> {
+    let omega = (1.0 + ufr).ln();
+
+    let a: Vec<Vec<f64>> = terms
+        .iter()
+        .map(|&t| terms.iter().map(|&u| wilson(t, u, omega, alpha)).collect())
+        .collect();
+    let b: Vec<f64> = terms
+        .iter()
+        .zip(rates.iter())
+        .map(|(&u, &s)| (1.0 + s).powf(-u) - (-omega * u).exp())
+        .collect();
+
+    solve(a, b).ok_or_else(|| "Smith-Wilson system is singular".into())
+}
+
+// ================================================
+// UNIT TESTS
+// ================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RSLifeError;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_linear_spot_interpolation() {
+        let curve = YieldCurve::from_spot_rates()
+            .terms(vec![1.0, 3.0])
+            .rates(vec![0.02, 0.04])
+            .call()
+            .unwrap();
+        assert_abs_diff_eq!(curve.spot_rate(2.0), 0.03, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            curve.discount_factor(2.0),
+            1.03_f64.powi(-2),
+            epsilon = 1e-12
+        );
+        // Flat extrapolation
+        assert_abs_diff_eq!(curve.spot_rate(10.0), 0.04, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.spot_rate(0.5), 0.02, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_annual_rates_flat_forward() {
+        let curve = YieldCurve::from_annual_rates()
+            .rates(vec![0.02, 0.03, 0.05])
+            .call()
+            .unwrap();
+        let expected = 1.0 / (1.02 * 1.03 * 1.05);
+        assert_abs_diff_eq!(curve.discount_factor(3.0), expected, epsilon = 1e-12);
+        // Constant forward within a year and beyond the last term
+        assert_abs_diff_eq!(curve.forward_rate(1.25, 1.75), 0.03, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.forward_rate(4.0, 6.0), 0.05, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_smith_wilson() {
+        let terms = vec![1.0, 2.0, 5.0, 10.0, 20.0];
+        let rates = vec![0.010, 0.012, 0.016, 0.020, 0.023];
+        let ufr = 0.036;
+        let curve = YieldCurve::from_spot_rates()
+            .terms(terms.clone())
+            .rates(rates.clone())
+            .interpolation(InterpolationEnum::SmithWilson { ufr, alpha: 0.1 })
+            .call()
+            .unwrap();
+
+        // Exact fit at the nodes
+        for (&u, &s) in terms.iter().zip(rates.iter()) {
+            assert_abs_diff_eq!(curve.spot_rate(u), s, epsilon = 1e-10);
+        }
+        // Forward rate converges to the UFR
+        assert_abs_diff_eq!(curve.forward_rate(150.0, 151.0), ufr, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_invalid_curve_parameters() {
+        let results = [
+            // Lengths differ
+            YieldCurve::from_spot_rates()
+                .terms(vec![1.0, 2.0])
+                .rates(vec![0.02])
+                .call(),
+            // Terms not increasing
+            YieldCurve::from_spot_rates()
+                .terms(vec![2.0, 1.0])
+                .rates(vec![0.02, 0.03])
+                .call(),
+            // Rate not above -1
+            YieldCurve::from_annual_rates().rates(vec![-1.0]).call(),
+            // Smith-Wilson without a positive alpha
+            YieldCurve::from_spot_rates()
+                .terms(vec![1.0])
+                .rates(vec![0.02])
+                .interpolation(InterpolationEnum::SmithWilson {
+                    ufr: 0.036,
+                    alpha: 0.0,
+                })
+                .call(),
+        ];
+        for result in results {
+            assert!(matches!(result, Err(RSLifeError::InvalidParameters(_))));
+        }
+    }
+}